@@ -0,0 +1,14 @@
+use aoc_utils::runner::Year;
+
+const YEARS: &[Year] = &[
+    #[cfg(feature = "year_2023")]
+    year_2023::YEAR,
+    #[cfg(feature = "year_2024")]
+    year_2024::YEAR,
+    #[cfg(feature = "year_2025")]
+    year_2025::YEAR,
+];
+
+fn main() {
+    aoc_utils::runner::run(YEARS);
+}
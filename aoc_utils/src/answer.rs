@@ -0,0 +1,179 @@
+use std::fmt::{Display, Formatter};
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    /// The part has no solution (yet), e.g. the free star of the last day.
+    Unsolved,
+}
+
+impl Answer {
+    const UNSOLVED: &'static str = "_";
+
+    /// Read back an answer written with its `Display` implementation.
+    pub fn parse(text: &str) -> Answer {
+        match text {
+            Answer::UNSOLVED => Answer::Unsolved,
+            _ => match text.parse() {
+                Ok(number) => Answer::Number(number),
+                Err(_) => Answer::Text(text.to_string()),
+            },
+        }
+    }
+
+    pub fn is_solved(&self) -> bool {
+        *self != Answer::Unsolved
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Unsolved => write!(f, "{}", Answer::UNSOLVED),
+        }
+    }
+}
+
+macro_rules! answer_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Answer {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Answer {
+        Answer::Number(value)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Answer {
+        Answer::Number(i128::try_from(value).expect("answer out of range"))
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Answer {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Answer {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl PartialEq<i128> for Answer {
+    fn eq(&self, other: &i128) -> bool {
+        matches!(self, Answer::Number(number) if number == other)
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        matches!(self, Answer::Text(text) if text == other)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn from_text(text: &str) -> Result<Part, String> {
+        match text {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("Invalid part `{}`, expected 1 or 2", text)),
+        }
+    }
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+/// The answers to both parts of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayResult {
+    pub part1: Answer,
+    pub part2: Answer,
+}
+
+impl DayResult {
+    pub fn new<A: Into<Answer>, B: Into<Answer>>(part1: A, part2: B) -> DayResult {
+        DayResult {
+            part1: part1.into(),
+            part2: part2.into(),
+        }
+    }
+
+    pub fn part(&self, part: Part) -> &Answer {
+        match part {
+            Part::One => &self.part1,
+            Part::Two => &self.part2,
+        }
+    }
+}
+
+impl Display for DayResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.part1, self.part2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let result = DayResult::new(296u64, "28,44");
+        assert_eq!(result.to_string(), "296 28,44");
+        assert_eq!(result.part(Part::One), &Answer::Number(296));
+        assert_eq!(result.part(Part::Two), &Answer::Text("28,44".to_string()));
+
+        let result = DayResult::new(-531, Answer::Unsolved);
+        assert_eq!(result.to_string(), "-531 _");
+    }
+
+    #[test]
+    fn test_compare() {
+        let result = DayResult::new(54927usize, "hjf,kdh");
+        assert_eq!(result.part1, 54927);
+        assert_ne!(result.part1, "54927");
+        assert_eq!(result.part2, "hjf,kdh");
+        assert!(result.part2.is_solved());
+        assert!(!Answer::Unsolved.is_solved());
+    }
+
+    #[test]
+    fn test_parse() {
+        for answer in [
+            Answer::Number(1672318386674),
+            Answer::Number(-3),
+            Answer::Text("4,3,7".to_string()),
+            Answer::Unsolved,
+        ] {
+            assert_eq!(Answer::parse(&answer.to_string()), answer);
+        }
+    }
+}
@@ -0,0 +1,245 @@
+use crate::answer::{Answer, Part};
+use crate::{Error, Result};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+/// Name of the file holding the expected answers of a year, next to its inputs.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// The expected answers of a year, read from a small subset of TOML:
+///
+/// ```toml
+/// [day17]
+/// part1 = "4,3,7,1,5,3,0,5,4"
+/// part2 = 190384615275535
+/// ```
+///
+/// The answers of the examples of a day use `[exampleN]` sections instead, see
+/// [`Answers::from_examples_text`].
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    expected: HashMap<(u8, Part), String>,
+}
+
+/// The outcome of comparing one answer to the expected one.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Check {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl Answers {
+    pub fn read<P: AsRef<Path>>(path: P) -> Result<Answers> {
+        let path = path.as_ref();
+        let text = crate::read_line(path)?;
+        Answers::from_text(&text).map_err(|error| error.in_file(path))
+    }
+
+    pub fn from_text(text: &str) -> Result<Answers> {
+        Answers::from_sections(text, "day")
+    }
+
+    /// Read the answers of the examples of a day, as written by
+    /// [`examples::write`](crate::examples::write).
+    pub fn read_examples<P: AsRef<Path>>(path: P) -> Result<Answers> {
+        let path = path.as_ref();
+        let text = crate::read_line(path)?;
+        Answers::from_examples_text(&text).map_err(|error| error.in_file(path))
+    }
+
+    /// The answers of the examples of a day, in `[exampleN]` sections, whose
+    /// `N` then takes the place of the day in [`Answers::expected`].
+    pub fn from_examples_text(text: &str) -> Result<Answers> {
+        Answers::from_sections(text, "example")
+    }
+
+    fn from_sections(text: &str, section_prefix: &str) -> Result<Answers> {
+        let mut answers = Answers::default();
+        let mut day = None;
+
+        for (i, line) in text.lines().enumerate() {
+            let at_line = |error: Error| error.at_line(i + 1, line);
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(section) = line.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+                day = Some(parse_key(section.trim(), section_prefix).map_err(at_line)?);
+                continue;
+            }
+
+            let (key, value) = crate::parse::split_once_or_err(line, "=").map_err(at_line)?;
+            let key = key.trim();
+            let part = match parse_key(key, "part").map_err(at_line)? {
+                1 => Part::One,
+                2 => Part::Two,
+                _ => return Err(at_line(Error::parse(key, "expected `part1` or `part2`"))),
+            };
+            let Some(day) = day else {
+                let message = format!("answer outside of a `[{}N]` section", section_prefix);
+                return Err(at_line(Error::parse(line, message)));
+            };
+            let value = parse_value(value.trim()).map_err(at_line)?;
+            answers.expected.insert((day, part), value);
+        }
+
+        Ok(answers)
+    }
+
+    pub fn expected(&self, day: u8, part: Part) -> Option<&str> {
+        self.expected.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Compare an answer to the expected one, using their text representation.
+    pub fn check(&self, day: u8, part: Part, answer: &Answer) -> Check {
+        let actual = answer.to_string();
+        match self.expected(day, part) {
+            None => Check::Missing,
+            Some(expected) if actual == expected => Check::Pass,
+            Some(expected) => Check::Fail {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Check::Pass => write!(f, "ok"),
+            Check::Fail { expected } => write!(f, "FAIL (expected {})", expected),
+            Check::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+fn parse_key(key: &str, prefix: &str) -> Result<u8> {
+    match key.strip_prefix(prefix) {
+        Some(number) => crate::parse::value(number),
+        None => Err(Error::parse(key, format!("expected `{}N`", prefix))),
+    }
+}
+
+/// Write `text` as a string value of the answers file, escaping its quotes,
+/// backslashes and line breaks.
+pub fn quote(text: &str) -> String {
+    let mut quoted = String::from('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn parse_value(value: &str) -> Result<String> {
+    let Some(text) = value.strip_prefix('"') else {
+        return crate::parse::value::<i128>(value).map(|number| number.to_string());
+    };
+
+    let mut unquoted = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if chars.as_str().is_empty() => return Ok(unquoted),
+            '"' => return Err(Error::parse(value, "unexpected text after the string")),
+            '\\' => unquoted.push(match chars.next() {
+                Some('"') => '"',
+                Some('\\') => '\\',
+                Some('n') => '\n',
+                Some('t') => '\t',
+                _ => return Err(Error::parse(value, "invalid escape sequence")),
+            }),
+            c => unquoted.push(c),
+        }
+    }
+    Err(Error::parse(value, "unterminated string"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+# My answers
+[day17]
+part1 = \"4,3,7,1,5,3,0,5,4\"
+part2 = 190384615275535
+
+[day18]
+part1 = 296
+";
+
+    #[test]
+    fn test_from_text() {
+        let answers = Answers::from_text(EXAMPLE).unwrap();
+        assert_eq!(answers.expected(17, Part::One), Some("4,3,7,1,5,3,0,5,4"));
+        assert_eq!(answers.expected(17, Part::Two), Some("190384615275535"));
+        assert_eq!(answers.expected(18, Part::One), Some("296"));
+        assert_eq!(answers.expected(18, Part::Two), None);
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::from_text(EXAMPLE).unwrap();
+        let program = Answer::from("4,3,7,1,5,3,0,5,4");
+        assert_eq!(answers.check(17, Part::One, &program), Check::Pass);
+        assert_eq!(
+            answers.check(18, Part::One, &Answer::from(297)),
+            Check::Fail {
+                expected: "296".to_string()
+            }
+        );
+        assert_eq!(
+            answers.check(18, Part::Two, &Answer::from("28,44")),
+            Check::Missing
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            Answers::from_text("[day1]\npart3 = 1")
+                .unwrap_err()
+                .to_string(),
+            "2: expected `part1` or `part2`: `part3` in `part3 = 1`"
+        );
+        assert!(Answers::from_text("part1 = 1").is_err());
+        assert!(Answers::from_text("[dayX]").is_err());
+        assert!(Answers::from_text("[day1]\npart1 = \"1").is_err());
+        assert!(Answers::from_text("[day1]\npart1 = 1.5").is_err());
+        assert!(Answers::from_text("[day1]\npart1").is_err());
+        assert!(Answers::from_text("[day1]\npart1 = \"1\"2\"").is_err());
+        assert!(Answers::from_text("[day1]\npart1 = \"1\\x\"").is_err());
+    }
+
+    #[test]
+    fn test_from_examples_text() {
+        let answers = Answers::from_examples_text("[example2]\npart1 = \"42\"").unwrap();
+        assert_eq!(answers.expected(2, Part::One), Some("42"));
+        assert_eq!(
+            Answers::from_examples_text("[day1]")
+                .unwrap_err()
+                .to_string(),
+            "1: expected `exampleN`: `day1` in `[day1]`"
+        );
+        assert!(Answers::from_text("[example1]").is_err());
+    }
+
+    #[test]
+    fn test_quote() {
+        for text in ["abc", "a \"b\" c", "C:\\dir\\", "two\nlines\t!", ""] {
+            let answers = Answers::from_text(&format!("[day1]\npart1 = {}", quote(text))).unwrap();
+            assert_eq!(answers.expected(1, Part::One), Some(text));
+        }
+        assert_eq!(quote("say \"hi\""), "\"say \\\"hi\\\"\"");
+    }
+}
@@ -0,0 +1,337 @@
+//! Download the puzzle inputs from the Advent of Code website and submit the
+//! answers, on behalf of the user whose session cookie is configured.
+
+use crate::answer::{Answer, Part};
+use crate::submission::Verdict;
+use crate::{Error, Result};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Environment variable holding the value of the `session` cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable replacing the address of the website, e.g. for a mirror.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies the tool to the website maintainers, as they ask of automated clients.
+pub const USER_AGENT: &str = concat!(
+    "github.com/gchazot/aoc aoc_utils/",
+    env!("CARGO_PKG_VERSION")
+);
+
+/// The shortest time between two requests of a client.
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(3);
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Where the session cookie is read from when `$AOC_SESSION` is not set.
+///
+/// This is `aoc/session` in `$XDG_CONFIG_HOME`, or else in `$HOME/.config`.
+pub fn session_file() -> Option<PathBuf> {
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    Some(config_dir.join("aoc").join("session"))
+}
+
+/// The session cookie from `$AOC_SESSION` or the [`session_file`], if any.
+pub fn session_from_env() -> Option<String> {
+    let session = match env::var(SESSION_VAR) {
+        Ok(session) => session,
+        Err(_) => fs::read_to_string(session_file()?).ok()?,
+    };
+    let session = session.trim();
+    (!session.is_empty()).then(|| session.to_string())
+}
+
+/// A client of the website, sending its requests no closer than a minimum interval.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Client {
+    pub fn new(session: &str) -> Client {
+        Client {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(TIMEOUT)
+                .build(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            session: session.to_string(),
+            min_interval: DEFAULT_MIN_INTERVAL,
+            last_request: None,
+        }
+    }
+
+    /// A client for the session of [`session_from_env`] and the website at
+    /// `$AOC_BASE_URL`, if set.
+    pub fn from_env() -> Result<Client> {
+        let session = session_from_env().ok_or_else(|| Error::Http {
+            url: DEFAULT_BASE_URL.to_string(),
+            message: match session_file() {
+                Some(path) => format!(
+                    "no session cookie, set ${} or write it to {}",
+                    SESSION_VAR,
+                    path.display()
+                ),
+                None => format!("no session cookie, set ${}", SESSION_VAR),
+            },
+        })?;
+        let mut client = Client::new(&session);
+        if let Ok(base_url) = env::var(BASE_URL_VAR) {
+            client.set_base_url(&base_url);
+        }
+        Ok(client)
+    }
+
+    pub fn set_base_url(&mut self, base_url: &str) {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+    }
+
+    pub fn set_min_interval(&mut self, min_interval: Duration) {
+        self.min_interval = min_interval;
+    }
+
+    pub fn input_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+
+    /// Download the input of a day.
+    pub fn fetch_input(&mut self, year: u16, day: u8) -> Result<String> {
+        let url = self.input_url(year, day);
+        self.get(&url)
+    }
+
+    /// The path of the input of a day in `dir`, downloading it there first
+    /// unless it is already present.
+    pub fn cached_input<P: AsRef<Path>>(&mut self, year: u16, day: u8, dir: P) -> Result<PathBuf> {
+        let path = dir.as_ref().join(format!("day{}.txt", day));
+        if crate::input::is_available(&path) {
+            return Ok(path);
+        }
+
+        let input = self.fetch_input(year, day)?;
+        fs::create_dir_all(dir.as_ref())
+            .and_then(|_| fs::write(&path, input))
+            .map_err(|source| Error::Io {
+                path: path.clone(),
+                source,
+            })?;
+        Ok(path)
+    }
+
+    pub fn answer_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}/answer", self.base_url, year, day)
+    }
+
+    /// Submit the answer to a part of a day, returning how the website judged it.
+    pub fn submit_answer(
+        &mut self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &Answer,
+    ) -> Result<Verdict> {
+        let url = self.answer_url(year, day);
+        self.wait_turn();
+        let request = self.agent.post(&url).set("Cookie", &self.cookie());
+        let level = part.number().to_string();
+        let answer = answer.to_string();
+        let form = [("level", level.as_str()), ("answer", answer.as_str())];
+        let response = read_response(&url, request.send_form(&form))?;
+        Verdict::from_response(&response).ok_or_else(|| Error::Http {
+            url,
+            message: String::from("the response could not be understood"),
+        })
+    }
+
+    fn get(&mut self, url: &str) -> Result<String> {
+        self.wait_turn();
+        let request = self.agent.get(url).set("Cookie", &self.cookie());
+        read_response(url, request.call())
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// Sleep until the minimum interval since the previous request has elapsed.
+    fn wait_turn(&mut self) {
+        if let Some(last_request) = self.last_request {
+            let elapsed = last_request.elapsed();
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+        self.last_request = Some(Instant::now());
+    }
+}
+
+fn read_response(
+    url: &str,
+    response: std::result::Result<ureq::Response, ureq::Error>,
+) -> Result<String> {
+    let error = |message: String| Error::Http {
+        url: url.to_string(),
+        message,
+    };
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|source| error(source.to_string())),
+        Err(ureq::Error::Status(status, _)) => Err(error(match status {
+            400 | 401 | 403 => format!("the session cookie was refused ({})", status),
+            404 => String::from("the puzzle is not available yet"),
+            _ => format!("unexpected HTTP status {}", status),
+        })),
+        Err(ureq::Error::Transport(transport)) => Err(error(transport.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+
+    /// A server answering each request with the next of `responses`, given as
+    /// status and body, and sending back the lines of each request it received.
+    fn stub_server(responses: Vec<(u16, &'static str)>) -> (String, mpsc::Receiver<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(&stream);
+                let mut request: Vec<String> = (&mut reader)
+                    .lines()
+                    .map(|line| line.unwrap())
+                    .take_while(|line| !line.is_empty())
+                    .collect();
+                let length = request
+                    .iter()
+                    .find_map(|line| line.strip_prefix("Content-Length: "))
+                    .map_or(0, |length| length.parse().unwrap());
+                let mut content = vec![0; length];
+                reader.read_exact(&mut content).unwrap();
+                request.push(String::from_utf8(content).unwrap());
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+                sender.send(request).unwrap();
+            }
+        });
+        (url, receiver)
+    }
+
+    fn stub_client(url: &str) -> Client {
+        let mut client = Client::new("0123abcd");
+        client.set_base_url(url);
+        client.set_min_interval(Duration::ZERO);
+        client
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let (url, requests) = stub_server(vec![(200, "1 2\n3 4\n")]);
+        let mut client = stub_client(&format!("{}/", url));
+
+        assert_eq!(client.fetch_input(2024, 7).unwrap(), "1 2\n3 4\n");
+
+        let request = requests.recv().unwrap();
+        assert_eq!(request[0], "GET /2024/day/7/input HTTP/1.1");
+        assert!(request.contains(&String::from("Cookie: session=0123abcd")));
+        assert!(request.contains(&format!("User-Agent: {}", USER_AGENT)));
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let (url, _requests) = stub_server(vec![(400, "Please log in"), (404, ""), (500, "")]);
+        let mut client = stub_client(&url);
+
+        let error = client.fetch_input(2024, 1).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "{}/2024/day/1/input: the session cookie was refused (400)",
+                url
+            )
+        );
+        let error = client.fetch_input(2024, 25).unwrap_err();
+        assert!(error
+            .to_string()
+            .ends_with("the puzzle is not available yet"));
+        let error = client.fetch_input(2024, 2).unwrap_err();
+        assert!(error.to_string().ends_with("unexpected HTTP status 500"));
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let (url, requests) = stub_server(vec![
+            (200, "<article><p>That's the right answer!</p></article>"),
+            (200, "<article><p>Something unexpected</p></article>"),
+        ]);
+        let mut client = stub_client(&url);
+
+        let verdict = client.submit_answer(2024, 7, Part::Two, &Answer::Number(1234));
+        assert_eq!(verdict.unwrap(), Verdict::Correct);
+        let request = requests.recv().unwrap();
+        assert_eq!(request[0], "POST /2024/day/7/answer HTTP/1.1");
+        assert!(request.contains(&String::from("Cookie: session=0123abcd")));
+        assert_eq!(request.last().unwrap(), "level=2&answer=1234");
+
+        let error = client
+            .submit_answer(2024, 7, Part::Two, &Answer::Number(1234))
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "{}/2024/day/7/answer: the response could not be understood",
+                url
+            )
+        );
+    }
+
+    #[test]
+    fn test_cached_input() {
+        let dir = crate::test_dir("client-cache");
+        let (url, requests) = stub_server(vec![(200, "downloaded\n")]);
+        let mut client = stub_client(&url);
+
+        let path = client.cached_input(2023, 3, &dir).unwrap();
+        assert_eq!(path, dir.join("day3.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "downloaded\n");
+        assert!(requests.recv().is_ok());
+
+        // The stub server is gone, so this must not send anything
+        assert_eq!(client.cached_input(2023, 3, &dir).unwrap(), path);
+    }
+
+    #[test]
+    fn test_min_interval() {
+        let (url, _requests) = stub_server(vec![(200, ""), (200, "")]);
+        let mut client = stub_client(&url);
+        client.set_min_interval(Duration::from_millis(200));
+
+        let start = Instant::now();
+        client.fetch_input(2024, 1).unwrap();
+        client.fetch_input(2024, 2).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+}
@@ -0,0 +1,232 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// How the sequence `start, step(start), step(step(start)), ...` repeats:
+/// after `prefix` steps, it goes through the same `period` states forever.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<S> {
+    pub prefix: usize,
+    pub period: usize,
+    /// The first state of the cycle, reached after `prefix` steps.
+    pub state: S,
+}
+
+impl<S> Cycle<S> {
+    /// The smallest number of steps reaching the same state as `n` steps.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+}
+
+/// Brent's cycle detection: a few more steps than needed, but only equality
+/// and constant memory are required.
+pub fn brent<S, F>(start: &S, mut step: F) -> Cycle<S>
+where
+    S: Clone + PartialEq,
+    F: FnMut(&S) -> S,
+{
+    let (mut power, mut period) = (1, 1);
+    let mut tortoise = start.clone();
+    let mut hare = step(start);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    let mut tortoise = start.clone();
+    let mut hare = start.clone();
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    Cycle {
+        prefix,
+        period,
+        state: tortoise,
+    }
+}
+
+/// Floyd's "tortoise and hare" cycle detection, in constant memory.
+pub fn floyd<S, F>(start: &S, mut step: F) -> Cycle<S>
+where
+    S: Clone + PartialEq,
+    F: FnMut(&S) -> S,
+{
+    let mut tortoise = step(start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    let mut tortoise = start.clone();
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    let mut period = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        period += 1;
+    }
+
+    Cycle {
+        prefix,
+        period,
+        state: tortoise,
+    }
+}
+
+/// Cycle detection remembering every state, so that each step is done once.
+pub fn hashed<S, F>(start: S, step: F) -> Cycle<S>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    match walk(start, step, None) {
+        Walk::Cycle(cycle, _) => cycle,
+        Walk::Reached(_) => unreachable!("the walk only stops on a cycle"),
+    }
+}
+
+/// The state after `n` steps, skipping whole cycles once one is found.
+pub fn nth<S, F>(start: S, step: F, n: usize) -> S
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    match walk(start, step, Some(n)) {
+        Walk::Reached(state) => state,
+        Walk::Cycle(cycle, mut states) => states.swap_remove(cycle.equivalent_step(n)),
+    }
+}
+
+enum Walk<S> {
+    Cycle(Cycle<S>, Vec<S>),
+    Reached(S),
+}
+
+fn walk<S, F>(start: S, mut step: F, limit: Option<usize>) -> Walk<S>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = start;
+    loop {
+        if limit == Some(states.len()) {
+            return Walk::Reached(state);
+        }
+        if let Some(&prefix) = seen.get(&state) {
+            let cycle = Cycle {
+                prefix,
+                period: states.len() - prefix,
+                state,
+            };
+            return Walk::Cycle(cycle, states);
+        }
+        let next = step(&state);
+        seen.insert(state.clone(), states.len());
+        states.push(state);
+        state = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0, 1, 2, 3, 4, 5, 6, 7, then 3 again.
+    fn step(&n: &u32) -> u32 {
+        if n == 7 {
+            3
+        } else {
+            n + 1
+        }
+    }
+
+    #[test]
+    fn test_detection() {
+        let expected = Cycle {
+            prefix: 3,
+            period: 5,
+            state: 3,
+        };
+        assert_eq!(brent(&0, step), expected);
+        assert_eq!(floyd(&0, step), expected);
+        assert_eq!(hashed(0, step), expected);
+
+        let from_cycle = Cycle {
+            prefix: 0,
+            period: 5,
+            state: 5,
+        };
+        assert_eq!(brent(&5, step), from_cycle);
+        assert_eq!(floyd(&5, step), from_cycle);
+        assert_eq!(hashed(5, step), from_cycle);
+    }
+
+    #[test]
+    fn test_fixed_point() {
+        let stop = |&n: &u32| n.saturating_sub(1);
+        let expected = Cycle {
+            prefix: 4,
+            period: 1,
+            state: 0,
+        };
+        assert_eq!(brent(&4, stop), expected);
+        assert_eq!(floyd(&4, stop), expected);
+        assert_eq!(hashed(4, stop), expected);
+    }
+
+    #[test]
+    fn test_nth() {
+        let mut state = 0;
+        for n in 0..30 {
+            assert_eq!(nth(0, step, n), state, "{}", n);
+            state = step(&state);
+        }
+        assert_eq!(nth(0, step, 1_000_000_000), 5);
+
+        let cycle = hashed(0, step);
+        assert_eq!(cycle.equivalent_step(2), 2);
+        assert_eq!(cycle.equivalent_step(8), 3);
+        assert_eq!(cycle.equivalent_step(1_000_000_000), 5);
+    }
+
+    #[test]
+    fn test_large_state() {
+        // Rotating a vector, the step counter making the prefix
+        let step = |(i, v): &(u8, Vec<u8>)| {
+            let mut v = v.clone();
+            v.rotate_left(1);
+            ((*i + 1).min(10), v)
+        };
+        let start = (0, vec![1, 2, 3, 4]);
+        let cycle = brent(&start, step);
+        assert_eq!((cycle.prefix, cycle.period), (10, 4));
+        assert_eq!(cycle.state, (10, vec![3, 4, 1, 2]));
+        assert_eq!(nth(start, step, 1001), (10, vec![2, 3, 4, 1]));
+    }
+}
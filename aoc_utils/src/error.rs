@@ -0,0 +1,157 @@
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    /// An input file could not be read.
+    Io { path: PathBuf, source: io::Error },
+    /// Some input `text` could not be understood, optionally found at a
+    /// given line (number and content) of a file.
+    Parse {
+        file: Option<PathBuf>,
+        line: Option<(usize, String)>,
+        text: String,
+        message: String,
+    },
+    /// A request to the Advent of Code website at `url` failed.
+    Http { url: String, message: String },
+}
+
+impl Error {
+    pub fn parse<M: Display>(text: &str, message: M) -> Error {
+        Error::Parse {
+            file: None,
+            line: None,
+            text: text.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Locate a parse error at the (1-based) line `number` whose content is `content`.
+    ///
+    /// Errors that already know their line are left untouched.
+    pub fn at_line(self, number: usize, content: &str) -> Error {
+        match self {
+            Error::Parse {
+                file,
+                line: None,
+                text,
+                message,
+            } => Error::Parse {
+                file,
+                line: Some((number, content.to_string())),
+                text,
+                message,
+            },
+            error => error,
+        }
+    }
+
+    /// Attach the file being parsed to a parse error which does not know it yet.
+    pub fn in_file<P: AsRef<Path>>(self, path: P) -> Error {
+        match self {
+            Error::Parse {
+                file: None,
+                line,
+                text,
+                message,
+            } => Error::Parse {
+                file: Some(path.as_ref().to_path_buf()),
+                line,
+                text,
+                message,
+            },
+            error => error,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            Error::Http { url, message } => write!(f, "{}: {}", url, message),
+            Error::Parse {
+                file,
+                line,
+                text,
+                message,
+            } => {
+                if let Some(file) = file {
+                    write!(f, "{}:", file.display())?;
+                }
+                if let Some((number, _)) = line {
+                    write!(f, "{}:", number)?;
+                }
+                if file.is_some() || line.is_some() {
+                    write!(f, " ")?;
+                }
+                write!(f, "{}: `{}`", message, text)?;
+                match line {
+                    Some((_, content)) if content != text => write!(f, " in `{}`", content),
+                    _ => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Parse { .. } | Error::Http { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_parse() {
+        let error = Error::parse("X", "invalid card");
+        assert_eq!(error.to_string(), "invalid card: `X`");
+
+        let error = error.at_line(12, "32T3X 765");
+        assert_eq!(error.to_string(), "12: invalid card: `X` in `32T3X 765`");
+
+        let error = error.in_file("input/day7.txt");
+        assert_eq!(
+            error.to_string(),
+            "input/day7.txt:12: invalid card: `X` in `32T3X 765`"
+        );
+    }
+
+    #[test]
+    fn test_display_whole_line() {
+        let error = Error::parse("three", "invalid digit").at_line(3, "three");
+        assert_eq!(error.to_string(), "3: invalid digit: `three`");
+    }
+
+    #[test]
+    fn test_context_is_kept() {
+        let error = Error::parse("X", "invalid card")
+            .at_line(12, "32T3X 765")
+            .at_line(1, "other")
+            .in_file("day7.txt")
+            .in_file("other.txt");
+        assert_eq!(
+            error.to_string(),
+            "day7.txt:12: invalid card: `X` in `32T3X 765`"
+        );
+    }
+
+    #[test]
+    fn test_display_io() {
+        let error = Error::Io {
+            path: PathBuf::from("input/day0.txt"),
+            source: io::Error::new(io::ErrorKind::NotFound, "not found"),
+        };
+        assert_eq!(error.to_string(), "input/day0.txt: not found");
+    }
+}
@@ -0,0 +1,246 @@
+//! Extract the examples of a puzzle, and their expected answers, from its saved
+//! HTML page.
+
+use crate::answer::Part;
+use crate::{Error, Result};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// An example block of a puzzle page, with the answers the page gives for it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    pub text: String,
+    pub answers: Vec<(Part, String)>,
+}
+
+/// The examples of the page, i.e. its `<pre><code>` blocks, in order.
+///
+/// The last emphasized code of the description of a part is taken as the
+/// answer of the last example before it, which holds for most puzzles.
+pub fn extract(html: &str) -> Vec<Example> {
+    let mut examples: Vec<Example> = Vec::new();
+    for (article, part) in elements(html, "<article", "</article>").zip([Part::One, Part::Two]) {
+        examples.extend(
+            elements(article, "<pre><code>", "</code></pre>").map(|code| Example {
+                text: to_text(code),
+                answers: Vec::new(),
+            }),
+        );
+        let answer = elements(article, "<code><em>", "</em></code>").last();
+        if let (Some(answer), Some(example)) = (answer, examples.last_mut()) {
+            example.answers.push((part, to_text(answer)));
+        }
+    }
+    examples
+}
+
+/// Write the examples of a day to `dayN-exampleK.txt` files in `dir`, and
+/// their answers to `dayN-examples.toml` when `with_answers` is set, which
+/// [`Answers::read_examples`](crate::check::Answers::read_examples) reads back.
+///
+/// Some example files are curated by hand, so nothing is written when one of
+/// the files already exists, unless `overwrite` is set.
+///
+/// Returns the files written.
+pub fn write<P: AsRef<Path>>(
+    dir: P,
+    day: u8,
+    examples: &[Example],
+    with_answers: bool,
+    overwrite: bool,
+) -> Result<Vec<PathBuf>> {
+    let dir = dir.as_ref();
+    let mut files = Vec::new();
+    let mut answers =
+        String::from("# Expected answers of the examples, as emphasized in the puzzle\n");
+    for (i, example) in examples.iter().enumerate() {
+        let name = format!("example{}", i + 1);
+        files.push((
+            dir.join(format!("day{}-{}.txt", day, name)),
+            example.text.clone(),
+        ));
+
+        if !example.answers.is_empty() {
+            answers.push_str(&format!("\n[{}]\n", name));
+        }
+        for (part, answer) in example.answers.iter() {
+            let answer = crate::check::quote(answer);
+            answers.push_str(&format!("part{} = {}\n", part.number(), answer));
+        }
+    }
+    if with_answers {
+        files.push((dir.join(format!("day{}-examples.toml", day)), answers));
+    }
+
+    if !overwrite {
+        if let Some((path, _)) = files.iter().find(|(path, _)| path.exists()) {
+            return Err(Error::Io {
+                path: path.clone(),
+                source: io::Error::new(
+                    io::ErrorKind::AlreadyExists,
+                    "already exists, use `--force` to overwrite it",
+                ),
+            });
+        }
+    }
+    for (path, text) in files.iter() {
+        write_file(path, text)?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+fn write_file(path: &Path, text: &str) -> Result<()> {
+    fs::write(path, text).map_err(|source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// The contents of the elements opened by `start` and closed by `end`.
+fn elements<'a>(html: &'a str, start: &'a str, end: &'a str) -> impl Iterator<Item = &'a str> {
+    let mut rest = html;
+    std::iter::from_fn(move || {
+        let (_, after_start) = rest.split_once(start)?;
+        let after_start = match start.ends_with('>') {
+            true => after_start,
+            false => after_start.split_once('>')?.1,
+        };
+        let (content, after_end) = after_start.split_once(end)?;
+        rest = after_end;
+        Some(content)
+    })
+}
+
+/// The text of some HTML, without its tags and with its entities decoded.
+fn to_text(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+    while let Some((before, after)) = rest.split_once('<') {
+        text.push_str(before);
+        rest = after.split_once('>').map_or("", |(_, after)| after);
+    }
+    text.push_str(rest);
+
+    [
+        ("&lt;", "<"),
+        ("&gt;", ">"),
+        ("&quot;", "\""),
+        ("&#39;", "'"),
+        ("&apos;", "'"),
+        ("&amp;", "&"),
+    ]
+    .iter()
+    .fold(text, |text, (entity, character)| {
+        text.replace(entity, character)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check::Answers;
+
+    fn page() -> &'static str {
+        "<html><body><main>\n\
+         <article class=\"day-desc\"><h2>--- Day 7: Example ---</h2>\n\
+         <p>For example:</p>\n\
+         <pre><code>1 &lt; 2\n<em>3</em> &amp; 4\n</code></pre>\n\
+         <p>Here, <code>x</code> is <code><em>12</em></code>.</p>\n\
+         <pre><code>5\n</code></pre>\n\
+         <p>So the answer is <code><em>42</em></code>.</p>\n\
+         </article>\n\
+         <p>Your puzzle answer was <code>1234</code>.</p>\n\
+         <article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2>\n\
+         <p>Now the same example gives <code><em>-7</em></code>.</p>\n\
+         </article>\n\
+         </main></body></html>"
+    }
+
+    #[test]
+    fn test_extract() {
+        assert_eq!(
+            extract(page()),
+            vec![
+                Example {
+                    text: String::from("1 < 2\n3 & 4\n"),
+                    answers: vec![],
+                },
+                Example {
+                    text: String::from("5\n"),
+                    answers: vec![
+                        (Part::One, String::from("42")),
+                        (Part::Two, String::from("-7"))
+                    ],
+                },
+            ]
+        );
+        assert_eq!(extract("<html>Not a puzzle</html>"), vec![]);
+    }
+
+    #[test]
+    fn test_to_text() {
+        assert_eq!(to_text("<em>a</em> &amp;lt; b"), "a &lt; b");
+        assert_eq!(to_text("a<b"), "a");
+    }
+
+    #[test]
+    fn test_write() {
+        let dir = crate::test_dir("examples");
+
+        let written = write(&dir, 7, &extract(page()), true, false).unwrap();
+        assert_eq!(
+            written,
+            vec![
+                dir.join("day7-example1.txt"),
+                dir.join("day7-example2.txt"),
+                dir.join("day7-examples.toml"),
+            ]
+        );
+        assert_eq!(fs::read_to_string(&written[1]).unwrap(), "5\n");
+        assert_eq!(
+            fs::read_to_string(&written[2]).unwrap(),
+            "# Expected answers of the examples, as emphasized in the puzzle\n\n\
+             [example2]\npart1 = \"42\"\npart2 = \"-7\"\n"
+        );
+
+        let answers = Answers::read_examples(&written[2]).unwrap();
+        assert_eq!(answers.expected(1, Part::One), None);
+        assert_eq!(answers.expected(2, Part::One), Some("42"));
+        assert_eq!(answers.expected(2, Part::Two), Some("-7"));
+
+        assert_eq!(
+            write(&dir, 7, &extract(page()), false, true).unwrap().len(),
+            2
+        );
+
+        let quoted = Example {
+            text: String::from("x\n"),
+            answers: vec![(Part::One, String::from(r#"say "\o/""#))],
+        };
+        let written = write(&dir, 8, &[quoted], true, false).unwrap();
+        assert!(fs::read_to_string(&written[1])
+            .unwrap()
+            .ends_with("[example1]\npart1 = \"say \\\"\\\\o/\\\"\"\n"));
+        let answers = Answers::read_examples(&written[1]).unwrap();
+        assert_eq!(answers.expected(1, Part::One), Some(r#"say "\o/""#));
+    }
+
+    #[test]
+    fn test_write_existing() {
+        let dir = crate::test_dir("examples_existing");
+        let curated = dir.join("day7-example2.txt");
+        fs::write(&curated, "curated\n").unwrap();
+
+        let error = write(&dir, 7, &extract(page()), true, false).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with(&curated.display().to_string()));
+        assert_eq!(fs::read_to_string(&curated).unwrap(), "curated\n");
+        assert!(!dir.join("day7-example1.txt").exists());
+        assert!(!dir.join("day7-examples.toml").exists());
+
+        write(&dir, 7, &extract(page()), true, true).unwrap();
+        assert_eq!(fs::read_to_string(&curated).unwrap(), "5\n");
+    }
+}
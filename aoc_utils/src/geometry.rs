@@ -0,0 +1,389 @@
+use num_traits::Signed;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// One of the 4 orthogonal directions, with `y` growing downwards as in the
+/// puzzle inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+/// One of the 8 directions, orthogonal or diagonal, with `y` growing downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction4 {
+    /// All the directions, clockwise from `Up`.
+    pub const ALL: [Direction4; 4] = [
+        Direction4::Up,
+        Direction4::Right,
+        Direction4::Down,
+        Direction4::Left,
+    ];
+
+    /// Read `^>v<`, `URDL` or `NESW`.
+    pub fn from_char(c: char) -> Option<Direction4> {
+        match c {
+            '^' | 'U' | 'N' => Some(Direction4::Up),
+            '>' | 'R' | 'E' => Some(Direction4::Right),
+            'v' | 'D' | 'S' => Some(Direction4::Down),
+            '<' | 'L' | 'W' => Some(Direction4::Left),
+            _ => None,
+        }
+    }
+
+    fn from_index(index: usize) -> Direction4 {
+        Direction4::ALL[index % 4]
+    }
+
+    /// A quarter turn clockwise.
+    pub fn turn_right(self) -> Direction4 {
+        Direction4::from_index(self as usize + 1)
+    }
+
+    /// A quarter turn counterclockwise.
+    pub fn turn_left(self) -> Direction4 {
+        Direction4::from_index(self as usize + 3)
+    }
+
+    pub fn opposite(self) -> Direction4 {
+        Direction4::from_index(self as usize + 2)
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction4::Left | Direction4::Right)
+    }
+
+    /// The move of one step in this direction.
+    pub fn delta<T: Signed>(self) -> Point2<T> {
+        Direction8::from(self).delta()
+    }
+}
+
+impl Direction8 {
+    /// All the directions, clockwise from `Up`.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    fn from_index(index: usize) -> Direction8 {
+        Direction8::ALL[index % 8]
+    }
+
+    /// An eighth of a turn clockwise.
+    pub fn turn_right(self) -> Direction8 {
+        Direction8::from_index(self as usize + 1)
+    }
+
+    /// An eighth of a turn counterclockwise.
+    pub fn turn_left(self) -> Direction8 {
+        Direction8::from_index(self as usize + 7)
+    }
+
+    pub fn opposite(self) -> Direction8 {
+        Direction8::from_index(self as usize + 4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// The move of one step in this direction, as `(dx, dy)`.
+    pub fn offset(self) -> (isize, isize) {
+        use Direction8::*;
+        match self {
+            Up => (0, -1),
+            UpRight => (1, -1),
+            Right => (1, 0),
+            DownRight => (1, 1),
+            Down => (0, 1),
+            DownLeft => (-1, 1),
+            Left => (-1, 0),
+            UpLeft => (-1, -1),
+        }
+    }
+
+    /// The move of one step in this direction.
+    pub fn delta<T: Signed>(self) -> Point2<T> {
+        let unit = |d: isize| match d {
+            -1 => -T::one(),
+            0 => T::zero(),
+            _ => T::one(),
+        };
+        let (dx, dy) = self.offset();
+        Point2::new(unit(dx), unit(dy))
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Direction8 {
+        Direction8::from_index(direction as usize * 2)
+    }
+}
+
+/// A point, or a vector, in 2 dimensions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A point, or a vector, in 3 dimensions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+fn abs_diff<T: PartialOrd + Sub<Output = T>>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Point2<T> {
+        Point2 { x, y }
+    }
+}
+
+impl<T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T>> Point2<T> {
+    pub fn manhattan(self, other: Point2<T>) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+}
+
+impl<T: Signed + Copy> Point2<T> {
+    /// The point one step away in `direction`.
+    pub fn step<D: Into<Direction8>>(self, direction: D) -> Point2<T> {
+        self + direction.into().delta()
+    }
+}
+
+impl Point2<usize> {
+    /// The point one step away in `direction`, if it is inside a `width` by
+    /// `height` area starting at the origin.
+    pub fn step_within<D: Into<Direction8>>(
+        self,
+        direction: D,
+        width: usize,
+        height: usize,
+    ) -> Option<Point2<usize>> {
+        let (dx, dy) = direction.into().offset();
+        let x = self.x.checked_add_signed(dx)?;
+        let y = self.y.checked_add_signed(dy)?;
+        (x < width && y < height).then_some(Point2::new(x, y))
+    }
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Point3<T> {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T>> Point3<T> {
+    pub fn manhattan(self, other: Point3<T>) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y) + abs_diff(self.z, other.z)
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Point2<T> {
+        Point2 { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(point: Point2<T>) -> (T, T) {
+        (point.x, point.y)
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Point3<T> {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T: Display> Display for Point2<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl<T: Display> Display for Point3<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+macro_rules! point_operators {
+    ($point:ident { $($field:ident),* }) => {
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = $point<T>;
+            fn add(self, rhs: $point<T>) -> $point<T> {
+                $point { $($field: self.$field + rhs.$field),* }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = $point<T>;
+            fn sub(self, rhs: $point<T>) -> $point<T> {
+                $point { $($field: self.$field - rhs.$field),* }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = $point<T>;
+            fn neg(self) -> $point<T> {
+                $point { $($field: -self.$field),* }
+            }
+        }
+
+        impl<T: Mul<Output = T> + Copy> Mul<T> for $point<T> {
+            type Output = $point<T>;
+            fn mul(self, rhs: T) -> $point<T> {
+                $point { $($field: self.$field * rhs),* }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $point<T> {
+            fn add_assign(&mut self, rhs: $point<T>) {
+                $(self.$field += rhs.$field;)*
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $point<T> {
+            fn sub_assign(&mut self, rhs: $point<T>) {
+                $(self.$field -= rhs.$field;)*
+            }
+        }
+    };
+}
+
+point_operators!(Point2 { x, y });
+point_operators!(Point3 { x, y, z });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direction4() {
+        use Direction4::*;
+        assert_eq!(Up.turn_right(), Right);
+        assert_eq!(Up.turn_left(), Left);
+        assert_eq!(Left.turn_right(), Up);
+        assert_eq!(Down.opposite(), Up);
+        assert_eq!(Right.opposite(), Left);
+        assert!(Left.is_horizontal());
+        assert!(!Up.is_horizontal());
+        assert_eq!(Up.delta::<i32>(), Point2::new(0, -1));
+        assert_eq!(Right.delta::<i64>(), Point2::new(1, 0));
+        assert_eq!(Direction4::from_char('v'), Some(Down));
+        assert_eq!(Direction4::from_char('W'), Some(Left));
+        assert_eq!(Direction4::from_char('.'), None);
+        for direction in Direction4::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(
+                direction.delta::<i8>() + direction.opposite().delta(),
+                Point2::default()
+            );
+        }
+    }
+
+    #[test]
+    fn test_direction8() {
+        use Direction8::*;
+        assert_eq!(Up.turn_right(), UpRight);
+        assert_eq!(Up.turn_left(), UpLeft);
+        assert_eq!(DownLeft.opposite(), UpRight);
+        assert!(DownRight.is_diagonal());
+        assert!(!Down.is_diagonal());
+        assert_eq!(UpLeft.delta::<i32>(), Point2::new(-1, -1));
+        assert_eq!(Direction8::from(Direction4::Left), Left);
+        assert_eq!(
+            Direction8::ALL
+                .iter()
+                .map(|d| d.delta::<i32>())
+                .fold(Point2::default(), |a, b| a + b),
+            Point2::default()
+        );
+    }
+
+    #[test]
+    fn test_point2() {
+        let a = Point2::new(3, -2);
+        let b = Point2::new(-1, 4);
+        assert_eq!(a + b, Point2::new(2, 2));
+        assert_eq!(a - b, Point2::new(4, -6));
+        assert_eq!(-a, Point2::new(-3, 2));
+        assert_eq!(a * 2, Point2::new(6, -4));
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.step(Direction4::Up), Point2::new(3, -3));
+        assert_eq!(a.step(Direction8::DownLeft), Point2::new(2, -1));
+        assert_eq!(a.to_string(), "3,-2");
+
+        let mut c = a;
+        c += b;
+        c -= Point2::new(1, 1);
+        assert_eq!(c, Point2::from((1, 1)));
+        assert_eq!(<(i32, i32)>::from(c), (1, 1));
+
+        assert_eq!(Point2::new(5usize, 1).manhattan(Point2::new(2, 3)), 5);
+    }
+
+    #[test]
+    fn test_step_within() {
+        let corner = Point2::new(0usize, 0);
+        assert_eq!(corner.step_within(Direction4::Up, 3, 2), None);
+        assert_eq!(corner.step_within(Direction4::Left, 3, 2), None);
+        assert_eq!(
+            corner.step_within(Direction8::DownRight, 3, 2),
+            Some(Point2::new(1, 1))
+        );
+        assert_eq!(
+            Point2::new(2usize, 1).step_within(Direction4::Right, 3, 2),
+            None
+        );
+        assert_eq!(
+            Point2::new(2usize, 1).step_within(Direction4::Down, 3, 2),
+            None
+        );
+    }
+
+    #[test]
+    fn test_point3() {
+        let a = Point3::new(1i64, 2, 3);
+        let b = Point3::from((4, -5, 6));
+        assert_eq!(a + b, Point3::new(5, -3, 9));
+        assert_eq!(b - a, Point3::new(3, -7, 3));
+        assert_eq!(a * 3, Point3::new(3, 6, 9));
+        assert_eq!(a.manhattan(b), 3 + 7 + 3);
+        assert_eq!(b.to_string(), "4,-5,6");
+    }
+}
@@ -0,0 +1,458 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// A graph whose nodes are named, each name being interned to a dense index
+/// from 0 the first time it is seen.
+///
+/// Edges are directed, an undirected edge being stored in both directions.
+#[derive(Debug, Clone, Default)]
+pub struct Graph {
+    names: Vec<String>,
+    indices: HashMap<String, usize>,
+    adjacency: Vec<Vec<usize>>,
+}
+
+/// A minimum cut between a source and a sink, found along the maximum flow.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cut {
+    /// The value of the maximum flow, i.e. the number of edges cut.
+    pub flow: usize,
+    /// The nodes remaining connected to the source, sorted.
+    pub source_side: Vec<usize>,
+}
+
+impl Graph {
+    pub fn new() -> Graph {
+        Graph::default()
+    }
+
+    /// The index of the node `name`, added if it is new.
+    pub fn node(&mut self, name: &str) -> usize {
+        if let Some(&index) = self.indices.get(name) {
+            return index;
+        }
+        let index = self.names.len();
+        self.names.push(name.to_string());
+        self.indices.insert(name.to_string(), index);
+        self.adjacency.push(Vec::new());
+        index
+    }
+
+    /// Add the edge `from` -> `to`, unless it already exists.
+    pub fn add_edge(&mut self, from: &str, to: &str) {
+        let (from, to) = (self.node(from), self.node(to));
+        self.link(from, to);
+    }
+
+    /// Add the edges `a` -> `b` and `b` -> `a`, unless they already exist.
+    pub fn add_undirected_edge(&mut self, a: &str, b: &str) {
+        let (a, b) = (self.node(a), self.node(b));
+        self.link(a, b);
+        self.link(b, a);
+    }
+
+    fn link(&mut self, from: usize, to: usize) {
+        if !self.adjacency[from].contains(&to) {
+            self.adjacency[from].push(to);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// The number of directed edges, undirected ones counting twice.
+    pub fn edge_count(&self) -> usize {
+        self.adjacency.iter().map(Vec::len).sum()
+    }
+
+    pub fn index(&self, name: &str) -> Option<usize> {
+        self.indices.get(name).copied()
+    }
+
+    pub fn name(&self, node: usize) -> &str {
+        &self.names[node]
+    }
+
+    /// The nodes reached by an edge from `node`, in the order they were added.
+    pub fn neighbours(&self, node: usize) -> &[usize] {
+        &self.adjacency[node]
+    }
+
+    pub fn has_edge(&self, from: usize, to: usize) -> bool {
+        self.adjacency[from].contains(&to)
+    }
+
+    /// The maximum flow from `source` to `sink`, each edge having a capacity of 1.
+    pub fn max_flow(&self, source: usize, sink: usize) -> usize {
+        self.min_cut(source, sink).flow
+    }
+
+    /// A minimum cut separating `source` from `sink`, each edge having a
+    /// capacity of 1, found with the Edmonds–Karp algorithm.
+    pub fn min_cut(&self, source: usize, sink: usize) -> Cut {
+        assert_ne!(source, sink, "the source cannot be the sink");
+
+        let mut residual = vec![HashMap::<usize, i32>::new(); self.len()];
+        for (from, targets) in self.adjacency.iter().enumerate() {
+            for &to in targets {
+                *residual[from].entry(to).or_default() += 1;
+                residual[to].entry(from).or_default();
+            }
+        }
+
+        let mut flow = 0;
+        loop {
+            let mut previous = vec![None; self.len()];
+            previous[source] = Some(source);
+            let mut queue = VecDeque::from([source]);
+            while let Some(node) = queue.pop_front() {
+                if node == sink {
+                    break;
+                }
+                for (&next, &capacity) in residual[node].iter() {
+                    if capacity > 0 && previous[next].is_none() {
+                        previous[next] = Some(node);
+                        queue.push_back(next);
+                    }
+                }
+            }
+
+            if previous[sink].is_none() {
+                let source_side = (0..self.len())
+                    .filter(|&node| previous[node].is_some())
+                    .collect();
+                return Cut { flow, source_side };
+            }
+
+            let mut node = sink;
+            while let Some(before) = previous[node].filter(|_| node != source) {
+                *residual[before].get_mut(&node).unwrap() -= 1;
+                *residual[node].get_mut(&before).unwrap() += 1;
+                node = before;
+            }
+            flow += 1;
+        }
+    }
+
+    /// All the maximal cliques, each one sorted, the edges being considered
+    /// undirected. Uses the Bron–Kerbosch algorithm with pivoting.
+    pub fn maximal_cliques(&self) -> Vec<Vec<usize>> {
+        let mut neighbours = vec![HashSet::new(); self.len()];
+        for (from, targets) in self.adjacency.iter().enumerate() {
+            for &to in targets.iter().filter(|&&to| to != from) {
+                neighbours[from].insert(to);
+                neighbours[to].insert(from);
+            }
+        }
+
+        let mut cliques = Vec::new();
+        bron_kerbosch(
+            &neighbours,
+            &mut Vec::new(),
+            (0..self.len()).collect(),
+            HashSet::new(),
+            &mut cliques,
+        );
+        cliques
+    }
+
+    /// The nodes ordered so that every edge goes forward, `None` if there is a
+    /// cycle. Nodes are taken in index order when there is a choice.
+    pub fn topological_sort(&self) -> Option<Vec<usize>> {
+        let mut in_degrees = vec![0; self.len()];
+        for targets in self.adjacency.iter() {
+            for &to in targets {
+                in_degrees[to] += 1;
+            }
+        }
+
+        let mut queue = (0..self.len())
+            .filter(|&node| in_degrees[node] == 0)
+            .collect::<VecDeque<_>>();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+            for &next in self.neighbours(node) {
+                in_degrees[next] -= 1;
+                if in_degrees[next] == 0 {
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        (order.len() == self.len()).then_some(order)
+    }
+
+    /// The number of distinct paths from `from` to `to`, `None` if the graph
+    /// has a cycle.
+    pub fn count_paths(&self, from: usize, to: usize) -> Option<usize> {
+        let mut counts = vec![0; self.len()];
+        counts[from] = 1;
+        for node in self.topological_sort()? {
+            if counts[node] > 0 {
+                for &next in self.neighbours(node) {
+                    counts[next] += counts[node];
+                }
+            }
+        }
+        Some(counts[to])
+    }
+}
+
+fn bron_kerbosch(
+    neighbours: &[HashSet<usize>],
+    clique: &mut Vec<usize>,
+    mut candidates: HashSet<usize>,
+    mut excluded: HashSet<usize>,
+    cliques: &mut Vec<Vec<usize>>,
+) {
+    let Some(&pivot) = candidates
+        .union(&excluded)
+        .max_by_key(|&&node| neighbours[node].intersection(&candidates).count())
+    else {
+        let mut found = clique.clone();
+        found.sort();
+        cliques.push(found);
+        return;
+    };
+
+    let outside_pivot = candidates
+        .difference(&neighbours[pivot])
+        .copied()
+        .collect::<Vec<_>>();
+    for node in outside_pivot {
+        clique.push(node);
+        bron_kerbosch(
+            neighbours,
+            clique,
+            candidates
+                .intersection(&neighbours[node])
+                .copied()
+                .collect(),
+            excluded.intersection(&neighbours[node]).copied().collect(),
+            cliques,
+        );
+        clique.pop();
+        candidates.remove(&node);
+        excluded.insert(node);
+    }
+}
+
+/// Disjoint sets of the elements `0..n`, a.k.a. union-find.
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    count: usize,
+}
+
+impl DisjointSet {
+    /// Each element in its own set.
+    pub fn new(n: usize) -> DisjointSet {
+        DisjointSet {
+            parents: (0..n).collect(),
+            sizes: vec![1; n],
+            count: n,
+        }
+    }
+
+    /// The representative of the set of `element`.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        let mut current = element;
+        while self.parents[current] != root {
+            let next = self.parents[current];
+            self.parents[current] = root;
+            current = next;
+        }
+        root
+    }
+
+    /// Merge the sets of `a` and `b`, returning whether they were separate.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.sizes[a] < self.sizes[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parents[b] = a;
+        self.sizes[a] += self.sizes[b];
+        self.count -= 1;
+        true
+    }
+
+    pub fn same_set(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// The size of the set of `element`.
+    pub fn set_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.sizes[root]
+    }
+
+    /// The sizes of all the sets.
+    pub fn set_sizes(&self) -> Vec<usize> {
+        (0..self.parents.len())
+            .filter(|&element| self.parents[element] == element)
+            .map(|root| self.sizes[root])
+            .collect()
+    }
+
+    /// The number of sets.
+    pub fn set_count(&self) -> usize {
+        self.count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn undirected(edges: &[(&str, &str)]) -> Graph {
+        let mut graph = Graph::new();
+        for (a, b) in edges {
+            graph.add_undirected_edge(a, b);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_builder() {
+        let mut graph = Graph::new();
+        graph.add_edge("a", "b");
+        graph.add_edge("a", "c");
+        graph.add_edge("a", "b");
+        graph.add_undirected_edge("c", "d");
+
+        assert_eq!(graph.len(), 4);
+        assert_eq!(graph.edge_count(), 4);
+        assert_eq!(graph.index("c"), Some(2));
+        assert_eq!(graph.index("e"), None);
+        assert_eq!(graph.name(3), "d");
+        assert_eq!(graph.node("b"), 1);
+        assert_eq!(graph.neighbours(0), &[1, 2]);
+        assert!(graph.has_edge(3, 2));
+        assert!(!graph.has_edge(1, 0));
+    }
+
+    #[test]
+    fn test_min_cut() {
+        // Two triangles joined by two bridges
+        let graph = undirected(&[
+            ("a", "b"),
+            ("b", "c"),
+            ("c", "a"),
+            ("x", "y"),
+            ("y", "z"),
+            ("z", "x"),
+            ("a", "x"),
+            ("b", "y"),
+        ]);
+        let (a, z) = (graph.index("a").unwrap(), graph.index("z").unwrap());
+
+        let cut = graph.min_cut(a, z);
+        assert_eq!(cut.flow, 2);
+        assert_eq!(cut.source_side, vec![0, 1, 2]);
+        assert_eq!(graph.max_flow(z, a), 2);
+
+        let (b, c) = (graph.index("b").unwrap(), graph.index("c").unwrap());
+        assert_eq!(graph.max_flow(b, c), 2);
+    }
+
+    #[test]
+    fn test_max_flow_directed() {
+        let mut graph = Graph::new();
+        for (from, to) in [("s", "a"), ("s", "b"), ("a", "t"), ("b", "a"), ("t", "s")] {
+            graph.add_edge(from, to);
+        }
+        let (s, t) = (graph.index("s").unwrap(), graph.index("t").unwrap());
+        assert_eq!(graph.max_flow(s, t), 1);
+        assert_eq!(graph.max_flow(t, s), 1);
+    }
+
+    #[test]
+    fn test_maximal_cliques() {
+        let graph = undirected(&[("a", "b"), ("a", "c"), ("b", "c"), ("c", "d"), ("d", "e")]);
+        let mut cliques = graph.maximal_cliques();
+        cliques.sort();
+        assert_eq!(cliques, vec![vec![0, 1, 2], vec![2, 3], vec![3, 4]]);
+
+        let mut graph = undirected(&[("a", "b")]);
+        graph.node("alone");
+        assert_eq!(graph.maximal_cliques().len(), 2);
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        let mut graph = Graph::new();
+        for (from, to) in [("c", "a"), ("a", "b"), ("c", "b"), ("d", "c")] {
+            graph.add_edge(from, to);
+        }
+        let names = |order: Vec<usize>| {
+            order
+                .into_iter()
+                .map(|node| graph.name(node).to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            names(graph.topological_sort().unwrap()),
+            vec!["d", "c", "a", "b"]
+        );
+
+        let mut cyclic = graph.clone();
+        cyclic.add_edge("b", "d");
+        assert_eq!(cyclic.topological_sort(), None);
+        assert_eq!(cyclic.count_paths(0, 1), None);
+    }
+
+    #[test]
+    fn test_count_paths() {
+        let mut graph = Graph::new();
+        for (from, to) in [
+            ("s", "a"),
+            ("s", "b"),
+            ("a", "c"),
+            ("b", "c"),
+            ("c", "t"),
+            ("a", "t"),
+        ] {
+            graph.add_edge(from, to);
+        }
+        let node = |name| graph.index(name).unwrap();
+        assert_eq!(graph.count_paths(node("s"), node("t")), Some(3));
+        assert_eq!(graph.count_paths(node("b"), node("t")), Some(1));
+        assert_eq!(graph.count_paths(node("t"), node("s")), Some(0));
+        assert_eq!(graph.count_paths(node("s"), node("s")), Some(1));
+    }
+
+    #[test]
+    fn test_disjoint_set() {
+        let mut sets = DisjointSet::new(6);
+        assert_eq!(sets.set_count(), 6);
+
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+
+        assert_eq!(sets.set_count(), 3);
+        assert!(sets.same_set(0, 3));
+        assert!(!sets.same_set(0, 4));
+        assert_eq!(sets.set_size(2), 4);
+        assert_eq!(sets.set_size(5), 1);
+
+        let mut sizes = sets.set_sizes();
+        sizes.sort();
+        assert_eq!(sizes, vec![1, 1, 4]);
+    }
+}
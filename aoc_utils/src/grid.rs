@@ -0,0 +1,336 @@
+use crate::geometry::{Direction4, Direction8, Point2};
+use crate::{Error, Result};
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// Coordinates of a cell: `(x, y)`, i.e. column then row, from the top left.
+pub type Position = (usize, usize);
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Swap rows and columns.
+    pub fn transposed(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    /// Rotate a quarter turn, the first row becoming the last column.
+    pub fn rotated_clockwise(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |(x, y)| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    /// Rotate a quarter turn, the first row becoming the first column upside down.
+    pub fn rotated_counterclockwise(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |(x, y)| {
+            self[(self.width - 1 - y, x)].clone()
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    /// Build a grid with the value of each cell given by `f`.
+    pub fn from_fn<F: FnMut(Position) -> T>(width: usize, height: usize, mut f: F) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parse each character of `lines` with `parser`, all lines having the same length.
+    pub fn from_lines<S, F>(lines: &[S], mut parser: F) -> Result<Grid<T>>
+    where
+        S: AsRef<str>,
+        F: FnMut(char) -> Result<T>,
+    {
+        let width = lines
+            .first()
+            .map_or(0, |line| line.as_ref().chars().count());
+        let mut cells = Vec::with_capacity(width * lines.len());
+
+        for (i, line) in lines.iter().enumerate() {
+            let line = line.as_ref();
+            let length = line.chars().count();
+            if length != width {
+                return Err(Error::parse(
+                    line,
+                    format!("expected {} cells, found {}", width, length),
+                )
+                .at_line(i + 1, line));
+            }
+            for c in line.chars() {
+                cells.push(parser(c).map_err(|error| error.at_line(i + 1, line))?);
+            }
+        }
+
+        Ok(Grid {
+            width,
+            height: lines.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Position) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    /// The position moved by `(dx, dy)` from `position`, if it is in the grid.
+    pub fn neighbour(&self, (x, y): Position, (dx, dy): (isize, isize)) -> Option<Position> {
+        let neighbour = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(neighbour).then_some(neighbour)
+    }
+
+    /// The position one step away from `position` in `direction`, if it is in the grid.
+    pub fn step<D: Into<Direction8>>(&self, position: Position, direction: D) -> Option<Position> {
+        self.neighbour(position, direction.into().offset())
+    }
+
+    /// The positions above, right, below and left of `position` that are in the grid.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction4::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// The positions around `position`, clockwise from above, that are in the grid.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// All the positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// All the cells along with their position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} out of the grid", x);
+        (0..self.height).map(move |y| &self.cells[y * self.width + x])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn index_of(&self, position: Position) -> Option<usize> {
+        self.contains(position)
+            .then(|| position.1 * self.width + position.0)
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// The first position holding `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<Position> {
+        self.find_all(value).next()
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Position> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(position, _)| position)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{:?} out of the grid", position))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{:?} out of the grid", position))
+    }
+}
+
+impl<T> Index<Point2<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point2<usize>) -> &T {
+        &self[(point.x, point.y)]
+    }
+}
+
+impl<T> IndexMut<Point2<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point2<usize>) -> &mut T {
+        &mut self[(point.x, point.y)]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        Grid::from_lines(&["abc", "def"], Ok).unwrap()
+    }
+
+    #[test]
+    fn test_from_lines() {
+        let grid = example();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[(0, 0)], 'a');
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+        assert_eq!(grid.to_string(), "abc\ndef");
+
+        let digits = Grid::from_lines(&["12", "34"], |c| {
+            c.to_digit(10)
+                .ok_or_else(|| Error::parse(&c.to_string(), "not a digit"))
+        })
+        .unwrap();
+        assert_eq!(digits[(1, 1)], 4);
+    }
+
+    #[test]
+    fn test_from_lines_invalid() {
+        let error = Grid::from_lines(&["ab", "c"], Ok).unwrap_err();
+        assert_eq!(error.to_string(), "2: expected 2 cells, found 1: `c`");
+
+        let error = Grid::from_lines(&["12", "3x"], |c| {
+            c.to_digit(10)
+                .ok_or_else(|| Error::parse(&c.to_string(), "not a digit"))
+        })
+        .unwrap_err();
+        assert_eq!(error.to_string(), "2: not a digit: `x` in `3x`");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(
+            grid.neighbours4((1, 1)).collect::<Vec<_>>(),
+            vec![(1, 0), (2, 1), (1, 2), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours8((2, 2)).collect::<Vec<_>>(),
+            vec![(2, 1), (1, 2), (1, 1)]
+        );
+        assert_eq!(grid.neighbour((2, 0), (1, 0)), None);
+        assert_eq!(grid.step((2, 0), Direction4::Down), Some((2, 1)));
+        assert_eq!(grid.step((2, 0), Direction8::UpLeft), None);
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = example();
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["ad", "be", "cf"]
+        );
+
+        assert_eq!(grid.transposed().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotated_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotated_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotated_clockwise().rotated_counterclockwise(), grid);
+    }
+
+    #[test]
+    fn test_find() {
+        let mut grid = Grid::from_lines(&["#.#", ".#."], Ok).unwrap();
+        assert_eq!(grid.find(&'.'), Some((1, 0)));
+        assert_eq!(
+            grid.find_all(&'#').collect::<Vec<_>>(),
+            vec![(0, 0), (2, 0), (1, 1)]
+        );
+        assert_eq!(grid.find(&'x'), None);
+
+        grid[(1, 0)] = 'x';
+        assert_eq!(grid.find(&'x'), Some((1, 0)));
+        grid[Point2::new(2, 1)] = 'y';
+        assert_eq!(grid[(2, 1)], 'y');
+        assert_eq!(
+            grid.map(|&c| c == '#').iter().filter(|(_, &c)| c).count(),
+            3
+        );
+    }
+}
@@ -0,0 +1,210 @@
+use crate::Result;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+/// Environment variable holding extra directories to look for inputs into.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+const DEFAULT_DIR: &str = "input";
+
+static INPUTS: RwLock<Option<Inputs>> = RwLock::new(None);
+
+/// Where to find the input files of the puzzles.
+///
+/// A file is looked up by name, first in the explicit overrides, then in each
+/// directory of the search path in order. When it is found nowhere, the path
+/// in the first directory is returned so that the error mentions it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Inputs {
+    search_path: Vec<PathBuf>,
+    overrides: HashMap<String, PathBuf>,
+}
+
+impl Inputs {
+    /// Search `$AOC_INPUT_DIR` then `./input`.
+    pub fn from_env() -> Inputs {
+        Inputs::from_env_dirs(None)
+    }
+
+    /// Search `$AOC_INPUT_DIR/<year>` before each directory of `$AOC_INPUT_DIR`.
+    ///
+    /// `./input` is left out as it belongs to a single year.
+    pub fn for_year(year: u16) -> Inputs {
+        Inputs::from_env_dirs(Some(year))
+    }
+
+    fn from_env_dirs(year: Option<u16>) -> Inputs {
+        let mut inputs = Inputs::default();
+        if let Some(dirs) = env::var_os(INPUT_DIR_VAR) {
+            for dir in env::split_paths(&dirs) {
+                if let Some(year) = year {
+                    inputs.add_dir(dir.join(year.to_string()));
+                }
+                inputs.add_dir(dir);
+            }
+        }
+        if year.is_none() {
+            inputs.add_dir(DEFAULT_DIR);
+        }
+        inputs
+    }
+
+    /// Add a directory at the end of the search path.
+    pub fn add_dir<P: Into<PathBuf>>(&mut self, dir: P) {
+        let dir = dir.into();
+        if !self.search_path.contains(&dir) {
+            self.search_path.push(dir);
+        }
+    }
+
+    /// Add a directory at the start of the search path.
+    pub fn prepend_dir<P: Into<PathBuf>>(&mut self, dir: P) {
+        let dir = dir.into();
+        self.search_path.retain(|existing| existing != &dir);
+        self.search_path.insert(0, dir);
+    }
+
+    /// Read the file `name` from `path` instead of the search path.
+    pub fn set_override<P: Into<PathBuf>>(&mut self, name: &str, path: P) {
+        self.overrides.insert(name.to_string(), path.into());
+    }
+
+    pub fn resolve(&self, name: &str) -> PathBuf {
+        if let Some(path) = self.overrides.get(name) {
+            return path.clone();
+        }
+
+        let candidates = self.search_path.iter().map(|dir| dir.join(name));
+        let mut missing = None;
+        for candidate in candidates {
+            if candidate.is_file() {
+                return candidate;
+            }
+            missing.get_or_insert(candidate);
+        }
+        missing.unwrap_or_else(|| Path::new(DEFAULT_DIR).join(name))
+    }
+}
+
+/// Replace the inputs used by [`path`] and the read functions of this module.
+pub fn configure(inputs: Inputs) {
+    *INPUTS.write().unwrap() = Some(inputs);
+}
+
+/// Resolve the path of the input file `name`, e.g. `day7.txt`.
+pub fn path(name: &str) -> PathBuf {
+    let mut inputs = INPUTS.write().unwrap();
+    inputs.get_or_insert_with(Inputs::from_env).resolve(name)
+}
+
+/// Whether `path` holds an input, an empty file being a placeholder for it.
+pub fn is_available<P: AsRef<Path>>(path: P) -> bool {
+    fs::metadata(path).is_ok_and(|metadata| metadata.is_file() && metadata.len() > 0)
+}
+
+pub fn read_line(name: &str) -> Result<String> {
+    super::read_line(path(name))
+}
+
+pub fn read_lines(name: &str) -> Result<Vec<String>> {
+    super::read_lines(path(name))
+}
+
+/// Read the input file `name` and parse all its lines at once with `parser`.
+pub fn parse<T, F>(name: &str, parser: F) -> Result<T>
+where
+    F: FnOnce(Vec<String>) -> Result<T>,
+{
+    let path = path(name);
+    parser(super::read_lines(&path)?).map_err(|error| error.in_file(&path))
+}
+
+/// Read the input file `name` and parse each of its lines with `parser`.
+pub fn parse_lines<T, F>(name: &str, parser: F) -> Result<Vec<T>>
+where
+    F: FnMut(&str) -> Result<T>,
+{
+    parse(name, |lines| crate::parse::lines(&lines, parser))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        crate::test_dir(&format!("input-{}", name))
+    }
+
+    #[test]
+    fn test_resolve_search_path() {
+        let first = temp_dir("first");
+        let second = temp_dir("second");
+        fs::write(first.join("day1.txt"), "first").unwrap();
+        fs::write(second.join("day1.txt"), "second").unwrap();
+        fs::write(second.join("day2.txt"), "second").unwrap();
+
+        let mut inputs = Inputs::default();
+        inputs.add_dir(&first);
+        inputs.add_dir(&second);
+
+        assert_eq!(inputs.resolve("day1.txt"), first.join("day1.txt"));
+        assert_eq!(inputs.resolve("day2.txt"), second.join("day2.txt"));
+        assert_eq!(inputs.resolve("day3.txt"), first.join("day3.txt"));
+
+        inputs.prepend_dir(&second);
+        assert_eq!(inputs.resolve("day1.txt"), second.join("day1.txt"));
+    }
+
+    #[test]
+    fn test_resolve_override() {
+        let mut inputs = Inputs::default();
+        inputs.add_dir(temp_dir("override"));
+        inputs.set_override("day1.txt", "/somewhere/else.txt");
+
+        assert_eq!(inputs.resolve("day1.txt"), Path::new("/somewhere/else.txt"));
+    }
+
+    #[test]
+    fn test_parse_reports_file() {
+        let dir = temp_dir("parse");
+        fs::write(dir.join("day1.txt"), "1\n2\nthree\n").unwrap();
+        let mut inputs = Inputs::default();
+        inputs.add_dir(&dir);
+        configure(inputs);
+
+        let error = parse_lines("day1.txt", crate::parse::value::<u32>).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "{}:3: invalid digit found in string: `three`",
+                dir.join("day1.txt").display()
+            )
+        );
+
+        let error = read_lines("day0.txt").unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with(&format!("{}: ", dir.join("day0.txt").display())));
+    }
+
+    #[test]
+    fn test_is_available() {
+        let dir = temp_dir("available");
+        fs::write(dir.join("day1.txt"), "1\n").unwrap();
+        fs::write(dir.join("day2.txt"), "").unwrap();
+
+        assert!(is_available(dir.join("day1.txt")));
+        assert!(!is_available(dir.join("day2.txt")));
+        assert!(!is_available(dir.join("day3.txt")));
+        assert!(!is_available(&dir));
+    }
+
+    #[test]
+    fn test_resolve_nowhere() {
+        let inputs = Inputs::default();
+        assert_eq!(inputs.resolve("day1.txt"), Path::new("input/day1.txt"));
+    }
+}
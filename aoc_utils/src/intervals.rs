@@ -0,0 +1,325 @@
+use num_traits::PrimInt;
+use std::ops::Range;
+
+/// A set of integers stored as sorted, disjoint and non-adjacent half-open
+/// ranges, so that huge sets of consecutive values stay cheap.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: PrimInt> RangeSet<T> {
+    pub fn new() -> RangeSet<T> {
+        RangeSet { ranges: Vec::new() }
+    }
+
+    /// The ranges of the set, in increasing order.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The number of values in the set.
+    pub fn len(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::zero(), |total, range| total + (range.end - range.start))
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.ranges.last().map(|range| range.end - T::one())
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|range| range.end <= value);
+        self.ranges
+            .get(index)
+            .is_some_and(|range| range.start <= value)
+    }
+
+    /// Add all the values of `range`, merging it with the ranges it touches.
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self.ranges.partition_point(|other| other.end < range.start);
+        let last = self
+            .ranges
+            .partition_point(|other| other.start <= range.end);
+        let merged = match self.ranges[first..last] {
+            [] => range,
+            [ref head, .., ref tail] | [ref head @ ref tail] => {
+                range.start.min(head.start)..range.end.max(tail.end)
+            }
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut result = self.clone();
+        for range in other.ranges.iter() {
+            result.insert(range.clone());
+        }
+        result
+    }
+
+    pub fn intersection(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let (start, end) = (a.start.max(b.start), a.end.min(b.end));
+            if start < end {
+                ranges.push(start..end);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        RangeSet { ranges }
+    }
+
+    /// The values of `self` which are not in `other`.
+    pub fn difference(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+        for range in self.ranges.iter() {
+            let mut start = range.start;
+            while j < other.ranges.len() && other.ranges[j].end <= start {
+                j += 1;
+            }
+            let mut k = j;
+            while k < other.ranges.len() && other.ranges[k].start < range.end {
+                if start < other.ranges[k].start {
+                    ranges.push(start..other.ranges[k].start);
+                }
+                start = start.max(other.ranges[k].end);
+                k += 1;
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        RangeSet { ranges }
+    }
+
+    /// Every value of the set, in increasing order.
+    pub fn values(&self) -> impl Iterator<Item = T> + '_ {
+        self.ranges.iter().flat_map(|range| {
+            let (mut next, end) = (range.start, range.end);
+            std::iter::from_fn(move || {
+                let value = next;
+                next = next + T::one();
+                (value < end).then_some(value)
+            })
+        })
+    }
+}
+
+impl<T: PrimInt> From<Range<T>> for RangeSet<T> {
+    fn from(range: Range<T>) -> RangeSet<T> {
+        let mut set = RangeSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: PrimInt> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(ranges: I) -> RangeSet<T> {
+        let mut set = RangeSet::new();
+        for range in ranges {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+/// A piecewise-linear mapping of integers: each source range is shifted onto
+/// a destination, the values outside every source range map to themselves.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RangeMap<T> {
+    pieces: Vec<(Range<T>, T)>,
+}
+
+impl<T: PrimInt> RangeMap<T> {
+    pub fn new() -> RangeMap<T> {
+        RangeMap { pieces: Vec::new() }
+    }
+
+    /// Map the values of `source` onto those starting at `destination`.
+    ///
+    /// Panics if `source` overlaps the source of another piece.
+    pub fn insert(&mut self, source: Range<T>, destination: T) {
+        let index = self
+            .pieces
+            .partition_point(|(other, _)| other.start < source.start);
+        let overlaps_previous = index > 0 && self.pieces[index - 1].0.end > source.start;
+        let overlaps_next = self
+            .pieces
+            .get(index)
+            .is_some_and(|(other, _)| other.start < source.end);
+        assert!(
+            !overlaps_previous && !overlaps_next,
+            "source ranges should not overlap"
+        );
+        self.pieces.insert(index, (source, destination));
+    }
+
+    pub fn map(&self, value: T) -> T {
+        let index = self
+            .pieces
+            .partition_point(|(source, _)| source.end <= value);
+        match self.pieces.get(index) {
+            Some((source, destination)) if source.start <= value => {
+                *destination + (value - source.start)
+            }
+            _ => value,
+        }
+    }
+
+    /// The image of all the values of `set`, computed range by range.
+    pub fn map_set(&self, set: &RangeSet<T>) -> RangeSet<T> {
+        let mut result = set.difference(&self.sources());
+        for (source, destination) in self.pieces.iter() {
+            let mapped = set.intersection(&RangeSet::from(source.clone()));
+            for range in mapped.ranges() {
+                let start = *destination + (range.start - source.start);
+                result.insert(start..start + (range.end - range.start));
+            }
+        }
+        result
+    }
+
+    /// All the values moved by a piece.
+    pub fn sources(&self) -> RangeSet<T> {
+        self.pieces
+            .iter()
+            .map(|(source, _)| source.clone())
+            .collect()
+    }
+
+    /// All the values reached by a piece.
+    pub fn destinations(&self) -> RangeSet<T> {
+        self.pieces
+            .iter()
+            .map(|(source, destination)| *destination..*destination + (source.end - source.start))
+            .collect()
+    }
+
+    /// The mapping going back from the destinations to the sources, if the
+    /// destinations do not overlap.
+    pub fn inverse(&self) -> RangeMap<T> {
+        let mut inverse = RangeMap::new();
+        for (source, destination) in self.pieces.iter() {
+            inverse.insert(
+                *destination..*destination + (source.end - source.start),
+                source.start,
+            );
+        }
+        inverse
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert() {
+        let mut set = RangeSet::new();
+        set.insert(10..15);
+        set.insert(20..25);
+        set.insert(0..0);
+        assert_eq!(set.ranges(), &[10..15, 20..25]);
+
+        set.insert(15..17);
+        assert_eq!(set.ranges(), &[10..17, 20..25]);
+        set.insert(12..22);
+        assert_eq!(set, RangeSet::from(10..25));
+        set.insert(0..5);
+        set.insert(30..40);
+        assert_eq!(set.ranges(), &[0..5, 10..25, 30..40]);
+        set.insert(-5..50);
+        assert_eq!(set, RangeSet::from(-5..50));
+
+        let set = [3..6, 10..15, 16..21, 12..19]
+            .into_iter()
+            .collect::<RangeSet<u64>>();
+        assert_eq!(set.ranges(), &[3..6, 10..21]);
+        assert_eq!(set.len(), 14);
+        assert_eq!(set.min(), Some(3));
+        assert_eq!(set.max(), Some(20));
+    }
+
+    #[test]
+    fn test_contains() {
+        let set = [3..6, 10..21].into_iter().collect::<RangeSet<u64>>();
+        let contained = (0..25).filter(|&n| set.contains(n)).collect::<Vec<_>>();
+        assert_eq!(contained, set.values().collect::<Vec<_>>());
+        assert_eq!(contained.len(), 14);
+        assert!(!RangeSet::new().contains(0));
+    }
+
+    #[test]
+    fn test_operations() {
+        let a = [0..10, 20..30].into_iter().collect::<RangeSet<i32>>();
+        let b = [5..25, 28..40].into_iter().collect::<RangeSet<i32>>();
+
+        assert_eq!(a.union(&b), RangeSet::from(0..40));
+        assert_eq!(a.intersection(&b).ranges(), &[5..10, 20..25, 28..30]);
+        assert_eq!(a.difference(&b).ranges(), &[0..5, 25..28]);
+        assert_eq!(b.difference(&a).ranges(), &[10..20, 30..40]);
+        assert_eq!(a.difference(&a), RangeSet::new());
+        assert_eq!(a.intersection(&RangeSet::new()), RangeSet::new());
+        assert_eq!(
+            RangeSet::from(0..100)
+                .difference(&[10..20, 30..40].into_iter().collect())
+                .len(),
+            80
+        );
+    }
+
+    #[test]
+    fn test_range_map() {
+        let mut map = RangeMap::new();
+        map.insert(98..100, 50);
+        map.insert(50..98, 52);
+
+        assert_eq!(map.map(0), 0);
+        assert_eq!(map.map(50), 52);
+        assert_eq!(map.map(97), 99);
+        assert_eq!(map.map(98), 50);
+        assert_eq!(map.map(100), 100);
+        assert_eq!(map.sources(), map.destinations());
+
+        let set = [45..55, 95..105].into_iter().collect::<RangeSet<u64>>();
+        let mapped = map.map_set(&set);
+        assert_eq!(mapped.ranges(), &[45..57, 97..105]);
+        assert_eq!(mapped.len(), set.len());
+        for value in set.values() {
+            assert!(mapped.contains(map.map(value)));
+        }
+
+        let inverse = map.inverse();
+        for value in 0..110 {
+            assert_eq!(inverse.map(map.map(value)), value);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "should not overlap")]
+    fn test_range_map_overlap() {
+        let mut map = RangeMap::new();
+        map.insert(10..20, 0);
+        map.insert(15..25, 30);
+    }
+}
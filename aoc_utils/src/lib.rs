@@ -1,83 +1,55 @@
-use num_traits::PrimInt;
-use std::collections::{HashMap, HashSet};
-use std::fs::read_to_string;
-use std::ops::RemAssign;
-
-pub fn read_line(filename: &str) -> String {
-    read_to_string(filename).unwrap()
-}
-
-pub fn read_lines(filename: &str) -> Vec<String> {
-    read_to_string(filename)
-        .unwrap()
-        .lines()
-        .map(String::from)
-        .collect()
-}
-
-#[test]
-fn test_prime_factors() {
-    fn check(number: u32, factors: Vec<u32>) {
-        let result = prime_factors(&number);
-        assert_eq!(
-            result.iter().map(|(_n, &exponent)| exponent).sum::<u32>(),
-            factors.len() as u32
-        );
+pub mod answer;
+pub mod check;
+#[cfg(feature = "client")]
+pub mod client;
+pub mod cycle;
+pub mod error;
+pub mod examples;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod intervals;
+pub mod num;
+pub mod number_theory;
+pub mod parallel;
+pub mod parse;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+pub mod submission;
+pub mod timing;
+
+pub use answer::{Answer, DayResult};
+pub use error::{Error, Result};
 
-        for (number, &exponent) in result.iter() {
-            let expected = factors.iter().filter(|&n| n == number).count() as u32;
-            assert_eq!(exponent, expected);
-        }
-    }
-
-    check(2, vec![2]);
-    check(3, vec![3]);
-    check(4, vec![2, 2]);
-    check(5, vec![5]);
-    check(6, vec![2, 3]);
-    check(7, vec![7]);
-    check(8, vec![2, 2, 2]);
-    check(9, vec![3, 3]);
-}
-
-pub fn prime_factors(number: &u32) -> HashMap<u32, u32> {
-    let mut result = HashMap::new();
-
-    let mut n = *number;
-    for i in primes(&n) {
-        while n % i == 0 {
-            n = &n / i;
-
-            let count = result.entry(i).or_insert(0);
-            *count += 1;
-        }
-    }
-    result
+use std::fs::read_to_string;
+use std::path::Path;
+
+pub fn read_line<P: AsRef<Path>>(filename: P) -> Result<String> {
+    let path = filename.as_ref();
+    read_to_string(path).map_err(|source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    })
 }
 
-pub fn primes(number: &u32) -> impl Iterator<Item = u32> {
-    let mut sieve = HashSet::new();
-    let &n = number;
-
-    let upper_bound = (n as f64).sqrt().floor() as u32 + 1;
-    for x in 2..upper_bound {
-        for y in 2..(n / x) + 1 {
-            sieve.insert(x * y as u32);
-        }
-    }
-
-    (2..n + 1).filter(move |&i| !&sieve.contains(&i))
+pub fn read_lines<P: AsRef<Path>>(filename: P) -> Result<Vec<String>> {
+    Ok(read_line(filename)?.lines().map(String::from).collect())
 }
 
-pub fn gcd<T: PrimInt + RemAssign>(mut n: T, mut m: T) -> T {
-    let zero = T::zero();
-
-    assert!(n != zero && m != zero);
-    while m != zero {
-        if m < n {
-            std::mem::swap(&mut m, &mut n);
-        }
-        m %= n;
-    }
-    n
+/// An empty temporary directory for a test, named after the process so that
+/// concurrent test runs do not share it.
+#[cfg(test)]
+fn test_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "{}-{}-{}",
+        env!("CARGO_PKG_NAME"),
+        std::process::id(),
+        name
+    ));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
 }
@@ -0,0 +1,351 @@
+use crate::number_theory::gcd;
+use num_traits::{PrimInt, Signed};
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// An exact fraction, always reduced with a positive denominator so that
+/// equal values have equal representations.
+///
+/// The operators panic on overflow, the `checked_` methods return `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational<T = i128> {
+    numerator: T,
+    denominator: T,
+}
+
+impl<T: PrimInt + Signed> Rational<T> {
+    /// `numerator / denominator`, which panics if `denominator` is 0.
+    pub fn new(numerator: T, denominator: T) -> Rational<T> {
+        assert!(!denominator.is_zero(), "the denominator should not be 0");
+        Self::checked_new(numerator, denominator).expect("rational overflow")
+    }
+
+    /// `numerator / denominator`, `None` if `denominator` is 0.
+    pub fn checked_new(numerator: T, denominator: T) -> Option<Rational<T>> {
+        if denominator.is_zero() {
+            return None;
+        }
+        let divisor = gcd(numerator, denominator).abs();
+        let (numerator, denominator) = (numerator / divisor, denominator / divisor);
+        if denominator.is_negative() {
+            Some(Rational {
+                numerator: T::zero().checked_sub(&numerator)?,
+                denominator: T::zero().checked_sub(&denominator)?,
+            })
+        } else {
+            Some(Rational {
+                numerator,
+                denominator,
+            })
+        }
+    }
+
+    pub fn zero() -> Rational<T> {
+        Rational::from(T::zero())
+    }
+
+    pub fn one() -> Rational<T> {
+        Rational::from(T::one())
+    }
+
+    pub fn numerator(&self) -> T {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> T {
+        self.denominator
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numerator.is_zero()
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator.is_one()
+    }
+
+    /// The value as an integer, if it is one.
+    pub fn to_integer(&self) -> Option<T> {
+        self.is_integer().then_some(self.numerator)
+    }
+
+    /// The largest integer not above the value.
+    pub fn floor(&self) -> T {
+        let quotient = self.numerator / self.denominator;
+        if self.numerator.is_negative() && !(self.numerator % self.denominator).is_zero() {
+            quotient - T::one()
+        } else {
+            quotient
+        }
+    }
+
+    pub fn checked_add(&self, other: &Rational<T>) -> Option<Rational<T>> {
+        let divisor = gcd(self.denominator, other.denominator);
+        let (left, right) = (self.denominator / divisor, other.denominator / divisor);
+        let numerator = self
+            .numerator
+            .checked_mul(&right)?
+            .checked_add(&other.numerator.checked_mul(&left)?)?;
+        Rational::checked_new(numerator, left.checked_mul(&other.denominator)?)
+    }
+
+    pub fn checked_sub(&self, other: &Rational<T>) -> Option<Rational<T>> {
+        self.checked_add(&other.checked_neg()?)
+    }
+
+    pub fn checked_mul(&self, other: &Rational<T>) -> Option<Rational<T>> {
+        // Reducing crosswise first keeps the products as small as possible
+        let first = gcd(self.numerator, other.denominator).abs();
+        let second = gcd(other.numerator, self.denominator).abs();
+        let numerator = (self.numerator / first).checked_mul(&(other.numerator / second))?;
+        let denominator = (self.denominator / second).checked_mul(&(other.denominator / first))?;
+        Rational::checked_new(numerator, denominator)
+    }
+
+    /// `None` if `other` is 0 too.
+    pub fn checked_div(&self, other: &Rational<T>) -> Option<Rational<T>> {
+        self.checked_mul(&Rational::checked_new(other.denominator, other.numerator)?)
+    }
+
+    pub fn checked_neg(&self) -> Option<Rational<T>> {
+        Some(Rational {
+            numerator: T::zero().checked_sub(&self.numerator)?,
+            denominator: self.denominator,
+        })
+    }
+}
+
+impl<T: PrimInt + Signed> From<T> for Rational<T> {
+    fn from(value: T) -> Rational<T> {
+        Rational {
+            numerator: value,
+            denominator: T::one(),
+        }
+    }
+}
+
+impl<T: PrimInt + Signed> Add for Rational<T> {
+    type Output = Rational<T>;
+    fn add(self, other: Rational<T>) -> Rational<T> {
+        self.checked_add(&other).expect("rational overflow")
+    }
+}
+
+impl<T: PrimInt + Signed> Sub for Rational<T> {
+    type Output = Rational<T>;
+    fn sub(self, other: Rational<T>) -> Rational<T> {
+        self.checked_sub(&other).expect("rational overflow")
+    }
+}
+
+impl<T: PrimInt + Signed> Mul for Rational<T> {
+    type Output = Rational<T>;
+    fn mul(self, other: Rational<T>) -> Rational<T> {
+        self.checked_mul(&other).expect("rational overflow")
+    }
+}
+
+impl<T: PrimInt + Signed> Div for Rational<T> {
+    type Output = Rational<T>;
+    fn div(self, other: Rational<T>) -> Rational<T> {
+        assert!(!other.is_zero(), "division by 0");
+        self.checked_div(&other).expect("rational overflow")
+    }
+}
+
+impl<T: PrimInt + Signed> Neg for Rational<T> {
+    type Output = Rational<T>;
+    fn neg(self) -> Rational<T> {
+        self.checked_neg().expect("rational overflow")
+    }
+}
+
+impl<T: PrimInt + Signed> Ord for Rational<T> {
+    fn cmp(&self, other: &Rational<T>) -> Ordering {
+        // Comparing the integer parts first, then the inverses of what remains,
+        // needs no multiplication that could overflow
+        let (floor, other_floor) = (self.floor(), other.floor());
+        if floor != other_floor {
+            return floor.cmp(&other_floor);
+        }
+        let remainder = *self - Rational::from(floor);
+        let other_remainder = *other - Rational::from(other_floor);
+        match (remainder.is_zero(), other_remainder.is_zero()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => {
+                let inverse = Rational::new(remainder.denominator, remainder.numerator);
+                let other_inverse =
+                    Rational::new(other_remainder.denominator, other_remainder.numerator);
+                other_inverse.cmp(&inverse)
+            }
+        }
+    }
+}
+
+impl<T: PrimInt + Signed> PartialOrd for Rational<T> {
+    fn partial_cmp(&self, other: &Rational<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: PrimInt + Signed + Display> Display for Rational<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+/// Solve the system `matrix * x == rhs`, of 2 or 3 equations for instance,
+/// by exact Gaussian elimination.
+///
+/// Returns `None` if there is not a single solution, or if the computation
+/// overflows.
+pub fn solve<T: PrimInt + Signed, const N: usize>(
+    matrix: [[Rational<T>; N]; N],
+    rhs: [Rational<T>; N],
+) -> Option<[Rational<T>; N]> {
+    let mut rows = matrix
+        .iter()
+        .zip(rhs)
+        .map(|(row, value)| row.iter().copied().chain([value]).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    for column in 0..N {
+        let pivot = (column..N).find(|&row| !rows[row][column].is_zero())?;
+        rows.swap(column, pivot);
+        let pivot_row = rows[column].clone();
+        for (i, row) in rows.iter_mut().enumerate() {
+            if i == column || row[column].is_zero() {
+                continue;
+            }
+            let factor = row[column].checked_div(&pivot_row[column])?;
+            for (value, pivot_value) in row.iter_mut().zip(pivot_row.iter()).skip(column) {
+                *value = value.checked_sub(&factor.checked_mul(pivot_value)?)?;
+            }
+        }
+    }
+
+    let mut solution = [Rational::zero(); N];
+    for (i, value) in solution.iter_mut().enumerate() {
+        *value = rows[i][N].checked_div(&rows[i][i])?;
+    }
+    Some(solution)
+}
+
+/// The sum of all the `values`, `None` if it overflows.
+pub fn checked_sum<T: PrimInt, I: IntoIterator<Item = T>>(values: I) -> Option<T> {
+    values
+        .into_iter()
+        .try_fold(T::zero(), |total, value| total.checked_add(&value))
+}
+
+/// The product of all the `values`, `None` if it overflows.
+pub fn checked_product<T: PrimInt, I: IntoIterator<Item = T>>(values: I) -> Option<T> {
+    values
+        .into_iter()
+        .try_fold(T::one(), |total, value| total.checked_mul(&value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn r(numerator: i128, denominator: i128) -> Rational {
+        Rational::new(numerator, denominator)
+    }
+
+    #[test]
+    fn test_rational() {
+        assert_eq!(r(6, -4), r(-3, 2));
+        assert_eq!(r(-3, 2).numerator(), -3);
+        assert_eq!(r(-3, 2).denominator(), 2);
+        assert_eq!(r(0, -7), Rational::zero());
+        assert_eq!(r(8, 4).to_integer(), Some(2));
+        assert_eq!(r(7, 4).to_integer(), None);
+        assert_eq!(r(7, 4).floor(), 1);
+        assert_eq!(r(-7, 4).floor(), -2);
+        assert_eq!(r(-8, 4).floor(), -2);
+        assert_eq!(r(-7, 4).to_string(), "-7/4");
+        assert_eq!(r(12, 4).to_string(), "3");
+        assert_eq!(Rational::<i64>::checked_new(1, 0), None);
+    }
+
+    #[test]
+    fn test_operations() {
+        assert_eq!(r(1, 3) + r(1, 6), r(1, 2));
+        assert_eq!(r(1, 3) - r(1, 2), r(-1, 6));
+        assert_eq!(r(2, 3) * r(9, 4), r(3, 2));
+        assert_eq!(r(2, 3) / r(-4, 9), r(-3, 2));
+        assert_eq!(-r(2, 3), r(-2, 3));
+        assert_eq!(r(1, 3).checked_div(&Rational::zero()), None);
+
+        let big = Rational::from(i128::MAX / 2);
+        assert_eq!(big.checked_add(&big), Some(Rational::from(i128::MAX - 1)));
+        assert_eq!(big.checked_add(&big).unwrap().checked_add(&r(2, 1)), None);
+        assert_eq!(big.checked_mul(&r(3, 1)), None);
+        assert_eq!(big.checked_mul(&r(4, 6)), Some(r(i128::MAX / 3, 1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn test_overflow() {
+        let _ = Rational::from(i128::MAX) + Rational::one();
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(r(1, 3) < r(1, 2));
+        assert!(r(-1, 2) < r(-1, 3));
+        assert!(r(7, 3) > r(2, 1));
+        assert!(r(2, 1) < r(7, 3));
+        assert_eq!(r(4, 6).cmp(&r(2, 3)), Ordering::Equal);
+
+        // Cross products would overflow
+        let (big, bigger) = (i128::MAX / 3, i128::MAX / 2);
+        assert!(r(big, bigger) < r(big + 1, bigger));
+        assert!(r(bigger, big) > r(bigger - 1, big));
+        let mut values = vec![r(5, 2), r(-1, 3), r(0, 1), r(7, 3), r(-1, 2)];
+        values.sort();
+        assert_eq!(values, vec![r(-1, 2), r(-1, 3), r(0, 1), r(7, 3), r(5, 2)]);
+    }
+
+    #[test]
+    fn test_solve() {
+        let int = |n: i128| Rational::from(n);
+
+        // 94a + 22b = 8400, 34a + 67b = 5400
+        let solution = solve(
+            [[int(94), int(22)], [int(34), int(67)]],
+            [int(8400), int(5400)],
+        );
+        assert_eq!(solution, Some([int(80), int(40)]));
+
+        let solution = solve(
+            [
+                [int(0), int(2), int(1)],
+                [int(1), int(-1), int(0)],
+                [int(3), int(0), int(-1)],
+            ],
+            [int(1), int(2), int(3)],
+        );
+        assert_eq!(solution, Some([r(8, 5), r(-2, 5), r(9, 5)]));
+
+        let singular = solve([[int(1), int(2)], [int(2), int(4)]], [int(3), int(6)]);
+        assert_eq!(singular, None);
+    }
+
+    #[test]
+    fn test_checked_helpers() {
+        assert_eq!(checked_sum([1u8, 2, 3]), Some(6));
+        assert_eq!(checked_sum([200u8, 100]), None);
+        assert_eq!(checked_sum(Vec::<u128>::new()), Some(0));
+        assert_eq!(checked_product([2u64, 3, 7]), Some(42));
+        assert_eq!(checked_product([u64::MAX, 2]), None);
+    }
+}
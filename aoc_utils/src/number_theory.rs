@@ -0,0 +1,333 @@
+use num_traits::{PrimInt, Signed};
+
+/// Sieve of Eratosthenes up to `limit` included, one bit per number.
+#[derive(Debug, Clone)]
+pub struct Sieve {
+    limit: usize,
+    composite: Vec<u64>,
+}
+
+impl Sieve {
+    pub fn new(limit: usize) -> Sieve {
+        let mut sieve = Sieve {
+            limit,
+            composite: vec![0; limit / 64 + 1],
+        };
+        sieve.mark(0);
+        sieve.mark(1);
+        let mut n = 2;
+        while n * n <= limit {
+            if !sieve.is_marked(n) {
+                for multiple in (n * n..=limit).step_by(n) {
+                    sieve.mark(multiple);
+                }
+            }
+            n += 1;
+        }
+        sieve
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    pub fn is_prime(&self, n: usize) -> bool {
+        assert!(n <= self.limit, "{} is above the sieve limit", n);
+        !self.is_marked(n)
+    }
+
+    /// All the primes up to the limit, in increasing order.
+    pub fn primes(&self) -> impl Iterator<Item = usize> + '_ {
+        (2..=self.limit).filter(|&n| !self.is_marked(n))
+    }
+
+    fn mark(&mut self, n: usize) {
+        if n <= self.limit {
+            self.composite[n / 64] |= 1 << (n % 64);
+        }
+    }
+
+    fn is_marked(&self, n: usize) -> bool {
+        self.composite[n / 64] & (1 << (n % 64)) != 0
+    }
+}
+
+/// All the primes up to `limit` included.
+pub fn primes(limit: usize) -> Vec<usize> {
+    Sieve::new(limit).primes().collect()
+}
+
+/// Deterministic Miller–Rabin primality test.
+pub fn is_prime(n: u64) -> bool {
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    for p in WITNESSES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+
+    let d = (n - 1) >> (n - 1).trailing_zeros();
+    let s = (n - 1).trailing_zeros();
+    'witness: for a in WITNESSES {
+        let mut x = mod_pow(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// The prime factors of `n` with their exponent, in increasing order.
+///
+/// Small factors are found by trial division, the others by Pollard's rho.
+pub fn prime_factors(n: u64) -> Vec<(u64, u32)> {
+    let mut factors = Vec::new();
+    let mut n = n;
+    for p in [2, 3, 5].into_iter().chain((7..1000).step_by(2)) {
+        if p * p > n {
+            break;
+        }
+        while n.is_multiple_of(p) {
+            factors.push(p);
+            n /= p;
+        }
+    }
+    if n > 1 {
+        split_factors(n, &mut factors);
+    }
+
+    factors.sort_unstable();
+    let mut result: Vec<(u64, u32)> = Vec::new();
+    for factor in factors {
+        match result.last_mut() {
+            Some((last, exponent)) if *last == factor => *exponent += 1,
+            _ => result.push((factor, 1)),
+        }
+    }
+    result
+}
+
+fn split_factors(n: u64, factors: &mut Vec<u64>) {
+    if n == 1 {
+        return;
+    }
+    if is_prime(n) {
+        factors.push(n);
+        return;
+    }
+    let divisor = pollard_rho(n);
+    split_factors(divisor, factors);
+    split_factors(n / divisor, factors);
+}
+
+/// A non-trivial divisor of the composite `n`, using Floyd's cycle detection.
+fn pollard_rho(n: u64) -> u64 {
+    if n.is_multiple_of(2) {
+        return 2;
+    }
+    for c in 1.. {
+        let f = |x: u64| ((mul_mod(x, x, n) as u128 + c as u128) % n as u128) as u64;
+        let (mut x, mut y, mut divisor) = (2, 2, 1);
+        while divisor == 1 {
+            x = f(x);
+            y = f(f(y));
+            divisor = gcd(x.abs_diff(y), n);
+        }
+        if divisor != n {
+            return divisor;
+        }
+    }
+    unreachable!()
+}
+
+/// `a * b % m` without overflow.
+pub fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+/// `base ^ exponent % m` by repeated squaring.
+pub fn mod_pow(base: u64, exponent: u64, m: u64) -> u64 {
+    let mut result = 1 % m;
+    let (mut base, mut exponent) = (base % m, exponent);
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Greatest common divisor, `gcd(n, 0)` being `n`.
+pub fn gcd<T: PrimInt>(mut n: T, mut m: T) -> T {
+    while m != T::zero() {
+        (n, m) = (m, n % m);
+    }
+    n
+}
+
+/// Least common multiple, 0 if either is 0.
+pub fn lcm<T: PrimInt>(n: T, m: T) -> T {
+    if n == T::zero() || m == T::zero() {
+        return T::zero();
+    }
+    n / gcd(n, m) * m
+}
+
+/// Least common multiple of all the `values`, 1 if there are none.
+pub fn lcm_all<T: PrimInt, I: IntoIterator<Item = T>>(values: I) -> T {
+    values.into_iter().fold(T::one(), lcm)
+}
+
+/// `(g, x, y)` such that `a * x + b * y == g`, `g` being the gcd of `a` and `b`.
+pub fn extended_gcd<T: PrimInt + Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::one(), T::zero());
+    let (mut old_y, mut y) = (T::zero(), T::one());
+    while r != T::zero() {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < T::zero() {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The inverse of `a` modulo `m`, in `0..m`, if they are coprime.
+pub fn mod_inverse<T: PrimInt + Signed>(a: T, m: T) -> Option<T> {
+    let (g, x, _) = extended_gcd(modulo(a, m), m);
+    (g == T::one()).then(|| modulo(x, m))
+}
+
+fn modulo<T: PrimInt + Signed>(a: T, m: T) -> T {
+    let remainder = a % m;
+    if remainder < T::zero() {
+        remainder + m
+    } else {
+        remainder
+    }
+}
+
+/// Solve the system of `x ≡ remainder (mod modulus)` for all the
+/// `(remainder, modulus)` congruences, the moduli not needing to be coprime.
+///
+/// Returns `(x, modulus)` with `x` the smallest non-negative solution and
+/// `modulus` the lcm of the moduli, or `None` if there is no solution.
+pub fn crt<I: IntoIterator<Item = (i64, i64)>>(congruences: I) -> Option<(i64, i64)> {
+    let (mut x, mut modulus) = (0i128, 1i128);
+    for (remainder, m) in congruences {
+        let (remainder, m) = (remainder as i128, m as i128);
+        assert!(m > 0, "the modulus {} should be positive", m);
+        let (g, p, _) = extended_gcd(modulus, m);
+        let difference = remainder - x;
+        if difference % g != 0 {
+            return None;
+        }
+        let step = m / g;
+        let k = (difference / g % step * p % step).rem_euclid(step);
+        x += modulus * k;
+        modulus *= step;
+        x = x.rem_euclid(modulus);
+    }
+    Some((x as i64, modulus as i64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sieve() {
+        let sieve = Sieve::new(100);
+        assert_eq!(sieve.primes().count(), 25);
+        assert!(sieve.is_prime(97));
+        assert!(!sieve.is_prime(91));
+        assert!(!sieve.is_prime(1));
+        assert_eq!(primes(20), vec![2, 3, 5, 7, 11, 13, 17, 19]);
+        assert_eq!(primes(1), vec![]);
+        assert_eq!(Sieve::new(1_000_000).primes().count(), 78498);
+    }
+
+    #[test]
+    fn test_is_prime() {
+        let sieve = Sieve::new(10_000);
+        for n in 0..10_000 {
+            assert_eq!(is_prime(n as u64), sieve.is_prime(n), "{}", n);
+        }
+        assert!(is_prime(1_000_000_007));
+        assert!(is_prime(18_446_744_073_709_551_557));
+        assert!(!is_prime(3_215_031_751));
+    }
+
+    #[test]
+    fn test_prime_factors() {
+        assert_eq!(prime_factors(1), vec![]);
+        assert_eq!(prime_factors(2), vec![(2, 1)]);
+        assert_eq!(prime_factors(8), vec![(2, 3)]);
+        assert_eq!(prime_factors(360), vec![(2, 3), (3, 2), (5, 1)]);
+        assert_eq!(
+            prime_factors(1_000_000_007 * 998_244_353),
+            vec![(998_244_353, 1), (1_000_000_007, 1)]
+        );
+        assert_eq!(
+            prime_factors(u64::MAX),
+            vec![
+                (3, 1),
+                (5, 1),
+                (17, 1),
+                (257, 1),
+                (641, 1),
+                (65537, 1),
+                (6_700_417, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(7u64, 13), 1);
+        assert_eq!(gcd(5, 0), 5);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(lcm_all([2u64, 3, 4, 5]), 60);
+        assert_eq!(lcm_all(Vec::<u32>::new()), 1);
+    }
+
+    #[test]
+    fn test_modular() {
+        let (g, x, y) = extended_gcd(240i64, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+
+        assert_eq!(mod_inverse(3i64, 11), Some(4));
+        assert_eq!(mod_inverse(-3i64, 11), Some(7));
+        assert_eq!(mod_inverse(4i64, 8), None);
+        assert_eq!(mod_pow(2, 10, 1000), 24);
+        assert_eq!(mul_mod(u64::MAX, u64::MAX, 7), 1);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt([]), Some((0, 1)));
+    }
+}
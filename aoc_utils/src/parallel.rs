@@ -0,0 +1,46 @@
+//! Data parallelism for independent computations, with threads only when the
+//! `parallel` feature is enabled: the results are the same either way.
+
+/// Apply `f` to all the `items`, keeping the results in the same order.
+#[cfg(feature = "parallel")]
+pub fn map<T, R, F>(items: Vec<T>, f: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync + Send,
+{
+    use rayon::prelude::*;
+    items.into_par_iter().map(f).collect()
+}
+
+/// Apply `f` to all the `items`, keeping the results in the same order.
+#[cfg(not(feature = "parallel"))]
+pub fn map<T, R, F>(items: Vec<T>, f: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync + Send,
+{
+    items.into_iter().map(f).collect()
+}
+
+/// Whether [`map`] runs on several threads.
+pub const fn is_enabled() -> bool {
+    cfg!(feature = "parallel")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_keeps_order() {
+        let squares = map((0..1000u64).collect(), |n| n * n);
+        assert_eq!(squares.len(), 1000);
+        assert!(squares
+            .iter()
+            .enumerate()
+            .all(|(n, &s)| s == (n * n) as u64));
+        assert_eq!(map(Vec::<u8>::new(), |n| n), vec![]);
+    }
+}
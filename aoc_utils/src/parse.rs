@@ -0,0 +1,277 @@
+use crate::{Error, Result};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Parse `text` into a `T`, reporting `text` when it fails.
+pub fn value<T>(text: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    text.parse().map_err(|error| Error::parse(text, error))
+}
+
+/// Parse each of the `lines` with `parser`, reporting the line that fails.
+pub fn lines<S, T, F>(lines: &[S], parser: F) -> Result<Vec<T>>
+where
+    S: AsRef<str>,
+    F: FnMut(&str) -> Result<T>,
+{
+    lines_at(lines, 0, parser)
+}
+
+/// Like [`lines`], for `lines` found at index `offset` of their file.
+pub fn lines_at<S, T, F>(lines: &[S], offset: usize, mut parser: F) -> Result<Vec<T>>
+where
+    S: AsRef<str>,
+    F: FnMut(&str) -> Result<T>,
+{
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let line = line.as_ref();
+            parser(line).map_err(|error| error.at_line(offset + i + 1, line))
+        })
+        .collect()
+}
+
+/// Like [`str::split_once`], but reporting `text` when `delimiter` is missing.
+pub fn split_once_or_err<'a>(text: &'a str, delimiter: &str) -> Result<(&'a str, &'a str)> {
+    text.split_once(delimiter)
+        .ok_or_else(|| Error::parse(text, format!("missing `{}`", delimiter)))
+}
+
+/// The blocks of `lines` separated by blank lines, each with the index of its
+/// first line so that it can be given to [`lines_at`]. Empty blocks are skipped.
+pub fn paragraphs<S: AsRef<str>>(lines: &[S]) -> impl Iterator<Item = (usize, &[S])> {
+    let mut offset = 0;
+    lines
+        .split(|line| line.as_ref().trim().is_empty())
+        .map(move |block| {
+            let start = offset;
+            offset += block.len() + 1;
+            (start, block)
+        })
+        .filter(|(_, block)| !block.is_empty())
+}
+
+/// All the integers found in `text`, ignoring anything else.
+///
+/// A `-` is read as a sign when it is followed by a digit and does not follow
+/// a letter or a digit, so `x=-3` gives -3 but `1-3` gives 1 and 3.
+pub fn ints<T>(text: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let bytes = text.as_bytes();
+    let mut result = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let is_sign = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && (i == 0 || !bytes[i - 1].is_ascii_alphanumeric());
+        if bytes[i].is_ascii_digit() || is_sign {
+            let start = i;
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            result.push(value(&text[start..i])?);
+        } else {
+            i += 1;
+        }
+    }
+    Ok(result)
+}
+
+/// Like [`ints`], but expecting exactly `N` integers.
+pub fn ints_exact<T, const N: usize>(text: &str) -> Result<[T; N]>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    ints(text)?.try_into().map_err(|found: Vec<T>| {
+        Error::parse(
+            text,
+            format!("expected {} integers, found {}", N, found.len()),
+        )
+    })
+}
+
+/// The parts of `text` matching each `{}` of `pattern`, the rest of `pattern`
+/// having to match exactly, e.g. `captures::<2>("x={}, y={}", "x=1, y=2")`
+/// gives `["1", "2"]`.
+///
+/// Each `{}` stops at the first occurrence of the text following it in
+/// `pattern`, or at the end of `text` for a final `{}`.
+pub fn captures<'a, const N: usize>(pattern: &str, text: &'a str) -> Result<[&'a str; N]> {
+    let literals = pattern.split("{}").collect::<Vec<_>>();
+    assert_eq!(
+        literals.len(),
+        N + 1,
+        "`{}` should have {} placeholders",
+        pattern,
+        N
+    );
+
+    let mut rest = text
+        .strip_prefix(literals[0])
+        .ok_or_else(|| Error::parse(text, format!("expected `{}` at the start", literals[0])))?;
+    let mut result = [""; N];
+    for (capture, literal) in result.iter_mut().zip(&literals[1..]) {
+        let end = if literal.is_empty() {
+            rest.len()
+        } else {
+            rest.find(literal)
+                .ok_or_else(|| Error::parse(text, format!("missing `{}`", literal)))?
+        };
+        *capture = &rest[..end];
+        rest = &rest[end + literal.len()..];
+    }
+    if !rest.is_empty() {
+        return Err(Error::parse(
+            text,
+            format!("unexpected `{}` at the end", rest),
+        ));
+    }
+    Ok(result)
+}
+
+/// Like [`captures`], parsing each of the captured parts into a `T`.
+pub fn scan<T, const N: usize>(pattern: &str, text: &str) -> Result<[T; N]>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let parts = captures::<N>(pattern, text)?;
+    let values = parts
+        .iter()
+        .map(|part| value(part))
+        .collect::<Result<Vec<T>>>()?;
+    Ok(values
+        .try_into()
+        .unwrap_or_else(|_| unreachable!("there are {} captures", N)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value() {
+        assert_eq!(value::<i32>("-12").unwrap(), -12);
+        assert_eq!(
+            value::<u8>("300").unwrap_err().to_string(),
+            "number too large to fit in target type: `300`"
+        );
+    }
+
+    #[test]
+    fn test_lines() {
+        let lines = vec!["1", "2", "3"];
+        assert_eq!(self::lines(&lines, value::<u8>).unwrap(), vec![1, 2, 3]);
+
+        let lines = vec![String::from("1 2"), String::from("3 x")];
+        let parse_pair = |line: &str| {
+            let (a, b) = split_once_or_err(line, " ")?;
+            Ok((value::<u8>(a)?, value::<u8>(b)?))
+        };
+        assert_eq!(
+            self::lines(&lines, parse_pair).unwrap_err().to_string(),
+            "2: invalid digit found in string: `x` in `3 x`"
+        );
+        assert_eq!(
+            lines_at(&lines, 10, parse_pair).unwrap_err().to_string(),
+            "12: invalid digit found in string: `x` in `3 x`"
+        );
+    }
+
+    #[test]
+    fn test_split_once_or_err() {
+        assert_eq!(split_once_or_err("a: b", ": ").unwrap(), ("a", "b"));
+        assert_eq!(
+            split_once_or_err("a b", ": ").unwrap_err().to_string(),
+            "missing `: `: `a b`"
+        );
+    }
+
+    #[test]
+    fn test_paragraphs() {
+        let lines = vec!["a", "b", "", "c", "", "", "d", "e", ""];
+        let blocks = paragraphs(&lines).collect::<Vec<_>>();
+        assert_eq!(
+            blocks,
+            vec![(0, &["a", "b"][..]), (3, &["c"][..]), (6, &["d", "e"][..]),]
+        );
+        assert_eq!(
+            lines_at(blocks[2].1, blocks[2].0, value::<u8>)
+                .unwrap_err()
+                .to_string(),
+            "7: invalid digit found in string: `d`"
+        );
+        assert_eq!(paragraphs::<&str>(&[]).count(), 0);
+    }
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(ints::<i32>("p=0,4 v=3,-3").unwrap(), vec![0, 4, 3, -3]);
+        assert_eq!(
+            ints::<i64>("19, 13, 30 @ -2,  1, -2").unwrap(),
+            vec![19, 13, 30, -2, 1, -2]
+        );
+        assert_eq!(ints::<u8>("1-3 a-2").unwrap(), vec![1, 3, 2]);
+        assert_eq!(ints::<u8>("none").unwrap(), vec![]);
+        assert_eq!(
+            ints::<u8>("1 -2").unwrap_err().to_string(),
+            "invalid digit found in string: `-2`"
+        );
+
+        let [x, y] = ints_exact::<i64, 2>("Button A: X+94, Y+34").unwrap();
+        assert_eq!((x, y), (94, 34));
+        assert_eq!(
+            ints_exact::<i64, 2>("1 2 3").unwrap_err().to_string(),
+            "expected 2 integers, found 3: `1 2 3`"
+        );
+    }
+
+    #[test]
+    fn test_captures() {
+        assert_eq!(
+            captures::<2>("x={}, y={}", "x=1, y=-2").unwrap(),
+            ["1", "-2"]
+        );
+        assert_eq!(captures::<1>("{}:", "abc:").unwrap(), ["abc"]);
+        assert_eq!(captures::<0>("abc", "abc").unwrap(), [""; 0]);
+
+        assert_eq!(
+            captures::<1>("x={}", "y=1").unwrap_err().to_string(),
+            "expected `x=` at the start: `y=1`"
+        );
+        assert_eq!(
+            captures::<2>("{},{}", "12").unwrap_err().to_string(),
+            "missing `,`: `12`"
+        );
+        assert_eq!(
+            captures::<1>("{}:", "a:b").unwrap_err().to_string(),
+            "unexpected `b` at the end: `a:b`"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "should have 2 placeholders")]
+    fn test_captures_count() {
+        let _ = captures::<2>("{}x{}: {}", "4x5: 1 2");
+    }
+
+    #[test]
+    fn test_scan() {
+        let [px, py, vx, vy] = scan::<i32, 4>("p={},{} v={},{}", "p=0,4 v=3,-3").unwrap();
+        assert_eq!((px, py, vx, vy), (0, 4, 3, -3));
+        assert_eq!(
+            scan::<u8, 2>("{}x{}", "4xa").unwrap_err().to_string(),
+            "invalid digit found in string: `a`"
+        );
+    }
+}
@@ -0,0 +1,997 @@
+#[cfg(feature = "client")]
+use crate::answer::Answer;
+use crate::answer::{DayResult, Part};
+use crate::check::{Answers, Check, ANSWERS_FILE};
+#[cfg(feature = "client")]
+use crate::client::Client;
+use crate::examples;
+use crate::input::{self, Inputs};
+use crate::parallel;
+use crate::scaffold;
+use crate::solution::{self, Solution};
+#[cfg(feature = "client")]
+use crate::submission::{Attempt, Submissions, SUBMISSIONS_FILE};
+use crate::timing::{self, Stats, Timings};
+use std::env;
+use std::path::PathBuf;
+use std::process::exit;
+use std::time::Duration;
+
+const USAGE: &str = "\
+Usage: aoc [YEAR|all] [DAYS] [OPTIONS]
+       aoc submit YEAR DAY --part <PART> [OPTIONS]
+       aoc new-day YEAR DAY
+       aoc examples YEAR DAY --page <FILE> [--answers] [--force]
+
+Commands:
+  submit              Submit the answer to a part of a day to the website,
+                      unless the submissions.log file found along the inputs
+                      of the year already tells its verdict, then log it
+                      (in the input directory of the year, for a new log)
+                      (needs the `client` feature)
+  new-day             Create the module and an empty input file of a day,
+                      as well as the crate of its year if needed
+  examples            Write the examples of a saved puzzle page to
+                      day<DAY>-example<N>.txt files in the input directory of
+                      the year
+
+Arguments:
+  [YEAR|all]          Run only this year, all of them by default
+  [DAYS]              Run only these days, same as `--days`
+
+Options:
+  -d, --day <DAY>     Run only this day (can be repeated)
+      --days <DAYS>   Run a list of days, e.g. `1-10` or `1,3,5-7`
+  -p, --part <PART>   Only solve part 1 or part 2
+      --input-dir <DIR>
+                      Look for input files in this directory first, or in
+                      its <YEAR> subdirectory (can be repeated, see also
+                      $AOC_INPUT_DIR)
+  -i, --input <FILE>  Read the input of the selected year and day from this file
+      --page <FILE>   The saved HTML page of the puzzle, for `examples`
+      --answers       Also write the expected answers of the examples to
+                      day<DAY>-examples.toml, for `examples`
+      --force         Overwrite the existing example files, for `examples`
+      --fetch         Download the missing inputs into the input directory of
+                      their year, with the session cookie of $AOC_SESSION
+                      (needs the `client` feature)
+  -c, --check         Compare the answers to those of the answers.toml file
+                      found along the inputs of each year
+  -t, --time          Print the time spent in each day and part
+      --bench <RUNS>  Run each day this many times and print timing statistics
+  -h, --help          Print this help
+";
+
+pub struct Day {
+    pub number: u8,
+    /// Solve the given part of the day, or both parts when it is `None`.
+    pub solve: fn(Option<Part>) -> crate::Result<DayResult>,
+}
+
+impl Day {
+    pub const fn new(number: u8, solve: fn(Option<Part>) -> crate::Result<DayResult>) -> Day {
+        Day { number, solve }
+    }
+
+    /// The day solved by `S`, which must be the day `number`.
+    pub const fn of<S: Solution>(number: u8) -> Day {
+        assert!(number == S::DAY, "the solution is for another day");
+        Day::new(number, solution::solve::<S>)
+    }
+
+    /// Solve the day, or only `part` of it, and measure it.
+    fn measure(&self, part: Option<Part>) -> (crate::Result<DayResult>, Timings) {
+        let (result, timings) = timing::measure(|| (self.solve)(part));
+        (result, timings.for_part(part))
+    }
+}
+
+/// The solutions of one year, with the directory holding their inputs.
+///
+/// A year crate declares its own with [`year!`](crate::year!).
+pub struct Year {
+    pub number: u16,
+    pub days: &'static [Day],
+    pub input_dir: &'static str,
+}
+
+impl Year {
+    pub const fn new(number: u16, days: &'static [Day], input_dir: &'static str) -> Year {
+        Year {
+            number,
+            days,
+            input_dir,
+        }
+    }
+}
+
+/// Declare the `YEAR` of a year crate, whose inputs are in its `input`
+/// directory, from the [`Solution`] of each of its days:
+///
+/// ```ignore
+/// aoc_utils::year!(2025, [
+///     1 => day1::Day1,
+///     2 => day2::Day2,
+/// ]);
+/// ```
+#[macro_export]
+macro_rules! year {
+    ($year:literal, [$($day:literal => $module:ident :: $solution:ident),* $(,)?]) => {
+        pub const YEAR: $crate::runner::Year = $crate::runner::Year::new(
+            $year,
+            &[$($crate::runner::Day::of::<$module::$solution>($day)),*],
+            concat!(env!("CARGO_MANIFEST_DIR"), "/input"),
+        );
+    };
+}
+
+/// What the runner does with the selected days.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Command {
+    /// Solve the days and print their answers.
+    #[default]
+    Run,
+    /// Solve a day and submit the answer to one of its parts.
+    Submit,
+    /// Create the files of a day which is not solved yet.
+    NewDay,
+    /// Extract the examples of a day from its puzzle page.
+    Examples,
+}
+
+impl Command {
+    fn from_text(text: &str) -> Option<Command> {
+        match text {
+            "submit" => Some(Command::Submit),
+            "new-day" => Some(Command::NewDay),
+            "examples" => Some(Command::Examples),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Command::Run => "run",
+            Command::Submit => "submit",
+            Command::NewDay => "new-day",
+            Command::Examples => "examples",
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Options {
+    pub command: Command,
+    pub year: Option<u16>,
+    pub days: Option<Vec<u8>>,
+    pub part: Option<Part>,
+    pub input_dirs: Vec<PathBuf>,
+    pub input: Option<PathBuf>,
+    pub page: Option<PathBuf>,
+    pub answers: bool,
+    pub force: bool,
+    pub fetch: bool,
+    pub check: bool,
+    pub time: bool,
+    pub bench: Option<usize>,
+    pub help: bool,
+}
+
+impl Options {
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Result<Options, String> {
+        let mut options = Options::default();
+        let mut args = args.into_iter();
+        let mut positionals = 0;
+
+        while let Some(arg) = args.next() {
+            let (flag, inline_value) = match arg.split_once('=') {
+                Some((flag, value)) if flag.starts_with("--") => (flag, Some(value.to_string())),
+                _ => (arg.as_str(), None),
+            };
+            let mut value = || {
+                inline_value
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or(format!("Missing value for `{}`", flag))
+            };
+
+            match flag {
+                "-d" | "--day" | "--days" => {
+                    let days = parse_days(&value()?)?;
+                    options.days.get_or_insert_with(Vec::new).extend(days);
+                }
+                "-p" | "--part" => options.part = Some(Part::from_text(&value()?)?),
+                "--input-dir" => options.input_dirs.push(value()?.into()),
+                "-i" | "--input" => options.input = Some(value()?.into()),
+                "--page" => options.page = Some(value()?.into()),
+                "--answers" => options.answers = true,
+                "--force" => options.force = true,
+                "--fetch" => options.fetch = true,
+                "-c" | "--check" => options.check = true,
+                "-t" | "--time" => options.time = true,
+                "--bench" => options.bench = Some(parse_runs(&value()?)?),
+                "-h" | "--help" => options.help = true,
+                _ if !arg.starts_with('-') => {
+                    match (positionals, Command::from_text(&arg)) {
+                        (0, Some(command)) if options.command == Command::Run => {
+                            options.command = command;
+                            continue;
+                        }
+                        _ => {}
+                    }
+                    match positionals {
+                        0 if arg == "all" => options.year = None,
+                        0 => options.year = Some(parse_year(&arg)?),
+                        1 => {
+                            let days = parse_days(&arg)?;
+                            options.days.get_or_insert_with(Vec::new).extend(days);
+                        }
+                        _ => return Err(format!("Unexpected argument `{}`", arg)),
+                    }
+                    positionals += 1;
+                }
+                _ => return Err(format!("Unexpected argument `{}`", arg)),
+            }
+        }
+
+        if let Some(days) = options.days.as_mut() {
+            days.sort();
+            days.dedup();
+        }
+        if options.input.is_some() && options.days.as_ref().is_none_or(|days| days.len() != 1) {
+            return Err(String::from("`--input` requires selecting exactly one day"));
+        }
+        if options.input.is_some() && options.check {
+            return Err(String::from("`--check` cannot be used with `--input`"));
+        }
+        let submit = options.command == Command::Submit;
+        if (options.fetch || submit) && !cfg!(feature = "client") {
+            return Err(String::from(
+                "`--fetch` and `submit` require building with `--features client`",
+            ));
+        }
+        let examples_only = options.page.is_some() || options.answers || options.force;
+        if options.command == Command::Run && examples_only {
+            return Err(String::from(
+                "`--page`, `--answers` and `--force` are only used with `examples`",
+            ));
+        }
+        if options.command != Command::Run {
+            let name = options.command.name();
+            let one_day = options.days.as_ref().is_some_and(|days| days.len() == 1);
+            if options.year.is_none() || !one_day {
+                return Err(format!("`{}` requires selecting a year and a day", name));
+            }
+            if (options.command == Command::Examples) != options.page.is_some() {
+                return Err(String::from(
+                    "`--page` is required by, and only used with, `examples`",
+                ));
+            }
+            if submit && options.part.is_none() {
+                return Err(String::from("`submit` requires selecting a part"));
+            }
+            if options.input.is_some() || options.check {
+                return Err(format!(
+                    "`{}` cannot be used with `--input` or `--check`",
+                    name
+                ));
+            }
+        }
+        Ok(options)
+    }
+
+    fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![Part::One, Part::Two],
+        }
+    }
+
+    fn inputs(&self, year: &Year) -> Inputs {
+        let mut inputs = Inputs::for_year(year.number);
+        inputs.add_dir(year.input_dir);
+        for dir in self.input_dirs.iter().rev() {
+            inputs.prepend_dir(dir);
+            inputs.prepend_dir(dir.join(year.number.to_string()));
+        }
+        if let (Some(path), Some(days)) = (&self.input, &self.days) {
+            inputs.set_override(&format!("day{}.txt", days[0]), path);
+        }
+        inputs
+    }
+
+    /// The selected days of each selected year.
+    ///
+    /// Days missing from an explicitly selected year are an error, whereas they
+    /// are skipped when running all the years.
+    fn select<'a>(&self, years: &'a [Year]) -> Result<Vec<(&'a Year, Vec<&'a Day>)>, String> {
+        let years = match self.year {
+            Some(number) => vec![years
+                .iter()
+                .find(|year| year.number == number)
+                .ok_or(format!("Year {} is not available", number))?],
+            None => years.iter().collect(),
+        };
+
+        let mut selected = Vec::new();
+        for year in years {
+            let days = match &self.days {
+                None => year.days.iter().collect(),
+                Some(numbers) => {
+                    let mut days = Vec::new();
+                    for &number in numbers {
+                        match year.days.iter().find(|day| day.number == number) {
+                            Some(day) => days.push(day),
+                            None if self.year.is_some() => {
+                                return Err(format!(
+                                    "Day {} of {} is not available",
+                                    number, year.number
+                                ))
+                            }
+                            None => {}
+                        }
+                    }
+                    days
+                }
+            };
+            if !days.is_empty() {
+                selected.push((year, days));
+            }
+        }
+
+        if selected.is_empty() {
+            return Err(String::from("No day is available in the selection"));
+        }
+        if self.input.is_some() && selected.len() != 1 {
+            return Err(String::from(
+                "`--input` requires selecting exactly one year",
+            ));
+        }
+        Ok(selected)
+    }
+}
+
+fn parse_year(text: &str) -> Result<u16, String> {
+    match text.parse::<u16>() {
+        Ok(year) if year >= 2015 => Ok(year),
+        _ => Err(format!(
+            "Invalid year `{}`, expected `all` or a year from 2015",
+            text
+        )),
+    }
+}
+
+fn parse_days(text: &str) -> Result<Vec<u8>, String> {
+    let parse_day = |day: &str| match day.trim().parse::<u8>() {
+        Ok(number) if (1..=25).contains(&number) => Ok(number),
+        _ => Err(format!(
+            "Invalid day `{}`, expected a number from 1 to 25",
+            day
+        )),
+    };
+
+    let mut days = Vec::new();
+    for item in text.split(',') {
+        match item.split_once('-') {
+            Some((first, last)) => {
+                let (first, last) = (parse_day(first)?, parse_day(last)?);
+                if first > last {
+                    return Err(format!("Invalid range of days `{}`", item));
+                }
+                days.extend(first..=last);
+            }
+            None => days.push(parse_day(item)?),
+        }
+    }
+    Ok(days)
+}
+
+fn parse_runs(text: &str) -> Result<usize, String> {
+    match text.parse::<usize>() {
+        Ok(runs) if runs > 0 => Ok(runs),
+        _ => Err(format!(
+            "Invalid number of runs `{}`, expected a positive number",
+            text
+        )),
+    }
+}
+
+/// Download the inputs of `days` found nowhere in the configured inputs into
+/// the input directory of `year`.
+#[cfg(feature = "client")]
+fn fetch_inputs(client: &mut Option<Client>, year: &Year, days: &[&Day]) -> crate::Result<()> {
+    for day in days {
+        if input::is_available(input::path(&format!("day{}.txt", day.number))) {
+            continue;
+        }
+        if client.is_none() {
+            *client = Some(Client::from_env()?);
+        }
+        let client = client.as_mut().unwrap();
+        let path = client.cached_input(year.number, day.number, year.input_dir)?;
+        eprintln!("Downloaded {}", path.display());
+    }
+    Ok(())
+}
+
+/// Submit the answer to a part of a day and log it, unless the previous
+/// submissions already tell its verdict. Returns the verdict to print.
+#[cfg(feature = "client")]
+fn submit(
+    client: &mut Option<Client>,
+    year: &Year,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+) -> crate::Result<String> {
+    if !answer.is_solved() {
+        return Ok(String::from("not submitted, unsolved"));
+    }
+    let path = submissions_path(input::path(SUBMISSIONS_FILE), year.input_dir);
+    let mut submissions = Submissions::read(&path)?;
+    if let Some(verdict) = submissions.known_verdict(day, part, answer) {
+        return Ok(format!("{}, not submitted again", verdict));
+    }
+
+    if client.is_none() {
+        *client = Some(Client::from_env()?);
+    }
+    let client = client.as_mut().unwrap();
+    let verdict = client.submit_answer(year.number, day, part, answer)?;
+    let attempt = Attempt {
+        day,
+        part,
+        answer: answer.clone(),
+        verdict,
+    };
+    submissions.record(&path, attempt)?;
+    Ok(verdict.to_string())
+}
+
+/// The submissions log found along the inputs, or a new one in the input
+/// directory of the year: the first directory searched may not exist.
+#[cfg(feature = "client")]
+fn submissions_path(found: PathBuf, input_dir: &str) -> PathBuf {
+    match found.is_file() {
+        true => found,
+        false => PathBuf::from(input_dir).join(SUBMISSIONS_FILE),
+    }
+}
+
+/// The answers of the configured inputs, none when there is no answers file.
+fn load_answers() -> crate::Result<Answers> {
+    let path = input::path(ANSWERS_FILE);
+    if !path.is_file() {
+        return Ok(Answers::default());
+    }
+    Answers::read(path)
+}
+
+fn print_check_summary(checks: &[Check]) {
+    let count = |predicate: fn(&Check) -> bool| checks.iter().filter(|c| predicate(c)).count();
+    println!(
+        "\nChecked {} answers: {} ok, {} failed, {} missing",
+        checks.len(),
+        count(|check| *check == Check::Pass),
+        count(|check| matches!(check, Check::Fail { .. })),
+        count(|check| *check == Check::Missing),
+    );
+}
+
+fn format_duration(duration: Option<Duration>) -> String {
+    match duration {
+        Some(duration) => format!("{:.2?}", duration),
+        None => String::from("-"),
+    }
+}
+
+fn print_timings(measures: &[(String, Vec<Timings>)]) {
+    println!(
+        "\n{:>7} {:>10} {:>10} {:>10} {:>10}",
+        "Day", "Parse", "Part 1", "Part 2", "Total"
+    );
+    for (label, timings) in measures {
+        let timings = &timings[0];
+        println!(
+            "{:>7} {:>10} {:>10} {:>10} {:>10}",
+            label,
+            format_duration(timings.parse),
+            format_duration(timings.part1),
+            format_duration(timings.part2),
+            format_duration(Some(timings.total)),
+        );
+    }
+}
+
+fn print_bench(measures: &[(String, Vec<Timings>)], runs: usize) {
+    println!("\nTimings over {} runs", runs);
+    println!(
+        "{:>7} {:<6} {:>10} {:>10} {:>10}",
+        "Day", "Phase", "Min", "Median", "Max"
+    );
+    for (label, timings) in measures {
+        let mut day = label.clone();
+        for (i, (phase, _)) in Timings::default().phases().into_iter().enumerate() {
+            let durations = timings
+                .iter()
+                .map(|timings| timings.phases()[i].1)
+                .collect::<Option<Vec<_>>>();
+            let Some(stats) = durations.and_then(Stats::from_durations) else {
+                continue;
+            };
+            println!(
+                "{:>7} {:<6} {:>10} {:>10} {:>10}",
+                day,
+                phase,
+                format_duration(Some(stats.min)),
+                format_duration(Some(stats.median)),
+                format_duration(Some(stats.max)),
+            );
+            day.clear();
+        }
+    }
+}
+
+fn new_day(options: &Options) {
+    let (Some(year), Some(days)) = (options.year, &options.days) else {
+        unreachable!("the options of `new-day` are validated");
+    };
+    match scaffold::new_day(scaffold::workspace_root(), year, days[0]) {
+        Ok(changed) => {
+            for path in changed {
+                println!("Wrote {}", path.display());
+            }
+        }
+        Err(error) => {
+            eprintln!("error: {}", error);
+            exit(1);
+        }
+    }
+}
+
+fn extract_examples(options: &Options, years: &[Year]) {
+    let Some(page) = &options.page else {
+        unreachable!("the options of `examples` are validated");
+    };
+    let selected = options.select(years).unwrap_or_else(|message| {
+        eprintln!("{}", message);
+        exit(2);
+    });
+    let (year, days) = &selected[0];
+    let written = crate::read_line(page).and_then(|html| {
+        let examples = examples::extract(&html);
+        let day = days[0].number;
+        examples::write(
+            year.input_dir,
+            day,
+            &examples,
+            options.answers,
+            options.force,
+        )
+    });
+    match written {
+        Ok(written) if written.is_empty() => println!("No example found in {}", page.display()),
+        Ok(written) => {
+            for path in written {
+                println!("Wrote {}", path.display());
+            }
+        }
+        Err(error) => {
+            eprintln!("error: {}", error);
+            exit(1);
+        }
+    }
+}
+
+/// Run the years and days selected on the command line.
+///
+/// Input files of a year are searched for in its `input_dir` after the
+/// directories given on the command line and in `$AOC_INPUT_DIR`.
+///
+/// With `--fetch`, the missing inputs are first downloaded to the `input_dir`.
+/// With `submit`, the answer of the selected part is submitted after solving.
+/// With `new-day`, nothing is solved, the files of the day are created instead.
+/// With `examples`, the examples of the day are extracted instead.
+///
+/// With the `parallel` feature, the days of a year are solved concurrently
+/// unless their time is measured, their answers still printed in order.
+pub fn run(years: &[Year]) {
+    let options = Options::from_args(env::args().skip(1)).unwrap_or_else(|message| {
+        eprintln!("{}\n\n{}", message, USAGE);
+        exit(2);
+    });
+    if options.help {
+        print!("{}", USAGE);
+        return;
+    }
+    if options.command == Command::NewDay {
+        new_day(&options);
+        return;
+    }
+    if options.command == Command::Examples {
+        extract_examples(&options, years);
+        return;
+    }
+
+    let selected = options.select(years).unwrap_or_else(|message| {
+        eprintln!("{}", message);
+        exit(2);
+    });
+
+    let runs = options.bench.unwrap_or(1);
+    let many_years = selected.len() > 1;
+    let mut measures = Vec::new();
+    let mut checks = Vec::new();
+    let mut failed = false;
+    #[cfg(feature = "client")]
+    let mut client = None;
+    for (year, days) in selected {
+        input::configure(options.inputs(year));
+        #[cfg(feature = "client")]
+        if options.fetch {
+            if let Err(error) = fetch_inputs(&mut client, year, &days) {
+                eprintln!("Year {}: error: {}", year.number, error);
+                failed = true;
+            }
+        }
+        if many_years {
+            println!("Year {}", year.number);
+        }
+        let answers = match options.check {
+            true => load_answers().unwrap_or_else(|error| {
+                eprintln!("Year {}: error: {}", year.number, error);
+                failed = true;
+                Answers::default()
+            }),
+            false => Answers::default(),
+        };
+
+        // Measures are only meaningful when each day runs alone
+        let concurrent = parallel::is_enabled() && !options.time && options.bench.is_none();
+        let solve = |day| (day, Day::measure(day, options.part));
+        let solved: Box<dyn Iterator<Item = _>> = match concurrent {
+            true => Box::new(parallel::map(days, solve).into_iter()),
+            false => Box::new(days.into_iter().map(solve)),
+        };
+
+        for (day, (result, first_timings)) in solved {
+            match result {
+                #[cfg(feature = "client")]
+                Ok(result) if options.command == Command::Submit => {
+                    let part = options.part.unwrap_or(Part::One);
+                    let answer = result.part(part);
+                    match submit(&mut client, year, day.number, part, answer) {
+                        Ok(report) => println!("Day {:>2}: {} [{}]", day.number, answer, report),
+                        Err(error) => {
+                            eprintln!("Day {:>2}: error: {}", day.number, error);
+                            failed = true;
+                        }
+                    }
+                }
+                Ok(result) if options.check => {
+                    let mut report = Vec::new();
+                    for part in options.parts() {
+                        let answer = result.part(part);
+                        let check = answers.check(day.number, part, answer);
+                        report.push(format!("{} [{}]", answer, check));
+                        checks.push(check);
+                    }
+                    println!("Day {:>2}: {}", day.number, report.join(" "));
+                }
+                Ok(result) => match options.part {
+                    Some(part) => println!("Day {:>2}: {}", day.number, result.part(part)),
+                    None => println!("Day {:>2}: {}", day.number, result),
+                },
+                Err(error) => {
+                    eprintln!("Day {:>2}: error: {}", day.number, error);
+                    failed = true;
+                    continue;
+                }
+            }
+
+            let mut timings = vec![first_timings];
+            for _ in 1..runs {
+                timings.push(day.measure(options.part).1);
+            }
+            measures.push((format!("{} {:>2}", year.number, day.number), timings));
+        }
+    }
+
+    if options.bench.is_some() {
+        print_bench(&measures, runs);
+    } else if options.time {
+        print_timings(&measures);
+    }
+    if options.check {
+        print_check_summary(&checks);
+        failed |= checks
+            .iter()
+            .any(|check| matches!(check, Check::Fail { .. }));
+    }
+    if failed {
+        exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(args: &[&str]) -> Result<Options, String> {
+        Options::from_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_no_options() {
+        assert_eq!(options(&[]), Ok(Options::default()));
+    }
+
+    #[test]
+    fn test_day_options() {
+        assert_eq!(options(&["--day", "17"]).unwrap().days, Some(vec![17]));
+        assert_eq!(
+            options(&["-d", "3", "-d", "1"]).unwrap().days,
+            Some(vec![1, 3])
+        );
+        assert_eq!(options(&["--day=5"]).unwrap().days, Some(vec![5]));
+        assert_eq!(
+            options(&["--days", "1-4,8"]).unwrap().days,
+            Some(vec![1, 2, 3, 4, 8])
+        );
+        assert_eq!(
+            options(&["--days", "3-5", "--day", "4"]).unwrap().days,
+            Some(vec![3, 4, 5])
+        );
+    }
+
+    #[test]
+    fn test_positional_options() {
+        let options_2024 = options(&["2024", "17"]).unwrap();
+        assert_eq!(options_2024.year, Some(2024));
+        assert_eq!(options_2024.days, Some(vec![17]));
+
+        assert_eq!(options(&["2023"]).unwrap().year, Some(2023));
+        assert_eq!(options(&["all"]).unwrap(), Options::default());
+        assert_eq!(
+            options(&["all", "1-3", "-p", "1"]).unwrap(),
+            Options {
+                days: Some(vec![1, 2, 3]),
+                part: Some(Part::One),
+                ..Options::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_part_options() {
+        assert_eq!(options(&["--part", "1"]).unwrap().part, Some(Part::One));
+        assert_eq!(options(&["-p", "2"]).unwrap().part, Some(Part::Two));
+        assert_eq!(options(&["--part=2"]).unwrap().part, Some(Part::Two));
+    }
+
+    #[test]
+    fn test_invalid_options() {
+        assert!(options(&["--day"]).is_err());
+        assert!(options(&["--day", "0"]).is_err());
+        assert!(options(&["--day", "26"]).is_err());
+        assert!(options(&["--days", "10-1"]).is_err());
+        assert!(options(&["--days", "1-x"]).is_err());
+        assert!(options(&["--part", "3"]).is_err());
+        assert!(options(&["--bogus"]).is_err());
+        assert!(options(&["17"]).is_err());
+        assert!(options(&["2024", "1", "2"]).is_err());
+        assert!(options(&["2024", "x"]).is_err());
+        assert!(options(&["--input", "mine.txt"]).is_err());
+        assert!(options(&["--input", "mine.txt", "--days", "1-2"]).is_err());
+        assert!(options(&["--bench", "0"]).is_err());
+        assert!(options(&["--bench", "many"]).is_err());
+        assert!(options(&["2024", "1", "--input", "mine.txt", "--check"]).is_err());
+    }
+
+    #[test]
+    fn test_fetch_options() {
+        let fetch = options(&["2024", "--fetch"]);
+        match cfg!(feature = "client") {
+            true => assert!(fetch.unwrap().fetch),
+            false => assert!(fetch.is_err()),
+        }
+    }
+
+    #[test]
+    fn test_submit_options() {
+        let submit = options(&["submit", "2024", "7", "-p", "2"]);
+        match cfg!(feature = "client") {
+            true => {
+                let submit = submit.unwrap();
+                assert_eq!(submit.command, Command::Submit);
+                assert_eq!(submit.year, Some(2024));
+                assert_eq!(submit.days, Some(vec![7]));
+                assert_eq!(submit.part, Some(Part::Two));
+            }
+            false => assert!(submit.is_err()),
+        }
+        assert!(options(&["submit", "2024", "7"]).is_err());
+        assert!(options(&["submit", "2024", "-p", "1"]).is_err());
+        assert!(options(&["submit", "all", "7", "-p", "1"]).is_err());
+        assert!(options(&["submit", "2024", "7", "-p", "1", "-c"]).is_err());
+        assert!(options(&["submit", "submit", "2024", "7", "-p", "1"]).is_err());
+    }
+
+    #[test]
+    fn test_new_day_options() {
+        let new_day = options(&["new-day", "2026", "1"]).unwrap();
+        assert_eq!(new_day.command, Command::NewDay);
+        assert_eq!(new_day.year, Some(2026));
+        assert_eq!(new_day.days, Some(vec![1]));
+
+        assert!(options(&["new-day", "2026"]).is_err());
+        assert!(options(&["new-day", "2026", "1-2"]).is_err());
+        assert!(options(&["new-day", "2026", "1", "-i", "mine.txt"]).is_err());
+        assert!(options(&["2026", "new-day", "1"]).is_err());
+    }
+
+    #[test]
+    fn test_examples_options() {
+        let examples = options(&["examples", "2024", "7", "--page", "day7.html"]).unwrap();
+        assert_eq!(examples.command, Command::Examples);
+        assert_eq!(examples.page, Some(PathBuf::from("day7.html")));
+        assert!(!examples.answers);
+        assert!(!examples.force);
+        assert!(
+            options(&["examples", "2024", "7", "--page=p.html", "--force"])
+                .unwrap()
+                .force
+        );
+        assert!(
+            options(&["examples", "2024", "7", "--page=p.html", "--answers"])
+                .unwrap()
+                .answers
+        );
+
+        assert!(options(&["examples", "2024", "7"]).is_err());
+        assert!(options(&["examples", "2024", "--page", "p.html"]).is_err());
+        assert!(options(&["2024", "7", "--page", "p.html"]).is_err());
+        assert!(options(&["2024", "7", "--answers"]).is_err());
+        assert!(options(&["2024", "7", "--force"]).is_err());
+        assert!(options(&["new-day", "2024", "7", "--page", "p.html"]).is_err());
+    }
+
+    #[test]
+    fn test_check_options() {
+        assert!(options(&["--check"]).unwrap().check);
+        assert!(options(&["2024", "-c"]).unwrap().check);
+        assert_eq!(options(&["-p", "2"]).unwrap().parts(), vec![Part::Two]);
+        assert_eq!(options(&[]).unwrap().parts(), vec![Part::One, Part::Two]);
+    }
+
+    #[test]
+    fn test_timing_options() {
+        assert!(options(&["--time"]).unwrap().time);
+        assert!(options(&["-t"]).unwrap().time);
+        assert_eq!(options(&["--bench", "10"]).unwrap().bench, Some(10));
+        assert_eq!(options(&["--bench=3"]).unwrap().bench, Some(3));
+    }
+
+    #[test]
+    fn test_input_options() {
+        let options = options(&["2024", "7", "-i", "mine.txt", "--input-dir", "a"]).unwrap();
+        assert_eq!(options.input, Some(PathBuf::from("mine.txt")));
+        assert_eq!(options.input_dirs, vec![PathBuf::from("a")]);
+
+        let inputs = options.inputs(&Year::new(2024, &[], "/nowhere"));
+        assert_eq!(inputs.resolve("day7.txt"), PathBuf::from("mine.txt"));
+        assert_eq!(inputs.resolve("day8.txt"), PathBuf::from("a/2024/day8.txt"));
+    }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn test_submissions_path() {
+        use crate::submission::Verdict;
+
+        let dir = crate::test_dir("submit");
+        let input_dir: &'static str = dir.to_str().unwrap().to_string().leak();
+        let options = Options {
+            input_dirs: vec![dir.join("missing")],
+            ..Options::default()
+        };
+        let inputs = options.inputs(&Year::new(2024, &[], input_dir));
+        let found = inputs.resolve(SUBMISSIONS_FILE);
+        assert!(found.starts_with(dir.join("missing")));
+
+        let path = submissions_path(found, input_dir);
+        assert_eq!(path, dir.join(SUBMISSIONS_FILE));
+        let attempt = Attempt {
+            day: 7,
+            part: Part::One,
+            answer: Answer::from(42),
+            verdict: Verdict::Wrong,
+        };
+        Submissions::default().record(&path, attempt).unwrap();
+
+        let found = inputs.resolve(SUBMISSIONS_FILE);
+        assert_eq!(submissions_path(found, input_dir), path);
+        assert_eq!(Submissions::read(&path).unwrap().attempts().len(), 1);
+    }
+
+    fn solve(_part: Option<Part>) -> crate::Result<DayResult> {
+        Ok(DayResult::new(1, 2))
+    }
+
+    const YEARS: &[Year] = &[
+        Year::new(2023, &[Day::new(1, solve), Day::new(2, solve)], "/nowhere"),
+        Year::new(
+            2024,
+            &[Day::new(1, solve), Day::new(2, solve), Day::new(5, solve)],
+            "/nowhere",
+        ),
+    ];
+
+    mod registry {
+        use crate::answer::Answer;
+        use crate::solution::Solution;
+
+        mod day4 {
+            pub struct Day4;
+
+            impl crate::solution::Solution for Day4 {
+                const DAY: u8 = 4;
+                type Input = ();
+
+                fn parse(_lines: &[String]) -> crate::Result<()> {
+                    Ok(())
+                }
+
+                fn part1(_input: &()) -> crate::Answer {
+                    crate::Answer::Unsolved
+                }
+
+                fn part2(_input: &()) -> crate::Answer {
+                    crate::Answer::from(4)
+                }
+            }
+        }
+
+        crate::year!(2030, [4 => day4::Day4]);
+
+        #[test]
+        fn test_year_macro() {
+            assert_eq!(YEAR.number, 2030);
+            assert!(YEAR.input_dir.ends_with("/input"));
+
+            let days = YEAR.days.iter().map(|day| day.number).collect::<Vec<_>>();
+            assert_eq!(days, vec![4]);
+            assert_eq!(day4::Day4::part2(&()), Answer::Number(4));
+        }
+    }
+
+    fn selected_days(args: &[&str]) -> Result<Vec<(u16, Vec<u8>)>, String> {
+        let selected = options(args)?.select(YEARS)?;
+        Ok(selected
+            .iter()
+            .map(|(year, days)| (year.number, days.iter().map(|day| day.number).collect()))
+            .collect())
+    }
+
+    #[test]
+    fn test_select_days() {
+        assert_eq!(
+            selected_days(&[]),
+            Ok(vec![(2023, vec![1, 2]), (2024, vec![1, 2, 5])])
+        );
+        assert_eq!(
+            selected_days(&["2024", "--days", "1,5"]),
+            Ok(vec![(2024, vec![1, 5])])
+        );
+        assert_eq!(selected_days(&["all", "5"]), Ok(vec![(2024, vec![5])]));
+        assert_eq!(
+            selected_days(&["--day", "2"]),
+            Ok(vec![(2023, vec![2]), (2024, vec![2])])
+        );
+
+        assert!(selected_days(&["2023", "5"]).is_err());
+        assert!(selected_days(&["2019"]).is_err());
+        assert!(selected_days(&["all", "3"]).is_err());
+        assert!(selected_days(&["all", "2", "-i", "mine.txt"]).is_err());
+        assert!(selected_days(&["2024", "2", "-i", "mine.txt"]).is_ok());
+    }
+}
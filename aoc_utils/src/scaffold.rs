@@ -0,0 +1,371 @@
+//! Generate the files of a new day, and of its year crate when it is the first.
+
+use crate::{Error, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const DAY_TEMPLATE: &str = r#"use aoc_utils::Answer;
+use aoc_utils::solution::Solution;
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    const DAY: u8 = {day};
+    type Input = Vec<String>;
+
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Ok(lines.to_vec())
+    }
+
+    fn part1(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = Day{day}::parse(&example()).unwrap();
+        assert_eq!(Day{day}::part1(&input), Answer::Unsolved);
+        assert_eq!(Day{day}::part2(&input), Answer::Unsolved);
+    }
+
+    #[test]
+    fn test_mine() {
+        let result = aoc_utils::solution::execute::<Day{day}>().unwrap();
+        assert_eq!(result.part1, Answer::Unsolved);
+        assert_eq!(result.part2, Answer::Unsolved);
+    }
+
+    fn example() -> Vec<String> {
+        vec![String::from("")]
+    }
+}
+"#;
+
+const CARGO_TEMPLATE: &str = r#"[package]
+name = "year_{year}"
+version = "0.1.0"
+edition = "2024"
+
+[dependencies]
+aoc_utils = { path = "../aoc_utils" }
+"#;
+
+const LIB_TEMPLATE: &str = r#"aoc_utils::year!({year}, [
+]);
+"#;
+
+/// The root of the workspace holding this crate and the year crates.
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc_utils should be in a workspace")
+        .to_path_buf()
+}
+
+/// Create the module of a day with an empty input, and register it in its
+/// year crate, which is created and registered in the workspace if needed.
+///
+/// Returns the files created or modified.
+pub fn new_day<P: AsRef<Path>>(root: P, year: u16, day: u8) -> Result<Vec<PathBuf>> {
+    let root = root.as_ref();
+    let crate_name = format!("year_{}", year);
+    let crate_dir = root.join(&crate_name);
+    let module = crate_dir.join("src").join(format!("day{}.rs", day));
+    if module.exists() {
+        return Err(Error::parse(
+            &module.display().to_string(),
+            "the day already exists",
+        ));
+    }
+
+    let mut changed = Vec::new();
+    if !crate_dir.join("Cargo.toml").exists() {
+        changed.extend(new_year(root, year)?);
+    }
+
+    let day_text = DAY_TEMPLATE.replace("{day}", &day.to_string());
+    write(&module, &day_text)?;
+    changed.push(module);
+
+    let lib = crate_dir.join("src").join("lib.rs");
+    edit(&lib, |text| register_day(text, day))?;
+    changed.push(lib);
+
+    let input = crate_dir.join("input").join(format!("day{}.txt", day));
+    if !input.exists() {
+        write(&input, "")?;
+        changed.push(input);
+    }
+    Ok(changed)
+}
+
+/// Create the crate of a year and add it to the workspace and the runner.
+fn new_year(root: &Path, year: u16) -> Result<Vec<PathBuf>> {
+    let crate_name = format!("year_{}", year);
+    let crate_dir = root.join(&crate_name);
+    let year_text = year.to_string();
+
+    let mut changed = Vec::new();
+    for (path, template) in [
+        (crate_dir.join("Cargo.toml"), CARGO_TEMPLATE),
+        (crate_dir.join("src").join("lib.rs"), LIB_TEMPLATE),
+    ] {
+        write(&path, &template.replace("{year}", &year_text))?;
+        changed.push(path);
+    }
+
+    let workspace = root.join("Cargo.toml");
+    edit(&workspace, |text| add_workspace_member(text, &crate_name))?;
+    changed.push(workspace);
+
+    let runner_manifest = root.join("aoc").join("Cargo.toml");
+    edit(&runner_manifest, |text| {
+        add_runner_dependency(text, &crate_name)
+    })?;
+    changed.push(runner_manifest);
+
+    let runner_main = root.join("aoc").join("src").join("main.rs");
+    edit(&runner_main, |text| add_runner_year(text, &crate_name))?;
+    changed.push(runner_main);
+
+    Ok(changed)
+}
+
+fn write(path: &Path, text: &str) -> Result<()> {
+    let io_error = |source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    fs::write(path, text).map_err(io_error)
+}
+
+fn edit<F>(path: &Path, change: F) -> Result<()>
+where
+    F: FnOnce(&str) -> Result<String>,
+{
+    let text = crate::read_line(path)?;
+    let text = change(&text).map_err(|error| error.in_file(path))?;
+    write(path, &text)
+}
+
+/// Add `mod dayN;` to the last group of modules, in the order of `rustfmt`,
+/// and the day to the `year!` of the crate, in the order of the days.
+fn register_day(text: &str, day: u8) -> Result<String> {
+    let mut lines = text.lines().map(String::from).collect::<Vec<_>>();
+
+    let module = format!("mod day{};", day);
+    let is_module = |line: &String| line.starts_with("mod day");
+    match lines.iter().rposition(is_module) {
+        Some(last) => {
+            let first = lines[..last]
+                .iter()
+                .rposition(|line| !is_module(line))
+                .map_or(0, |i| i + 1);
+            lines.insert(last + 1, module);
+            lines[first..last + 2]
+                .sort_by(|a, b| a.trim_end_matches(';').cmp(b.trim_end_matches(';')));
+        }
+        None => {
+            lines.insert(0, String::new());
+            lines.insert(0, module);
+        }
+    }
+
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("aoc_utils::year!("))
+        .ok_or_else(|| Error::parse("year!", "the list of days is missing"))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line == "]);")
+            .ok_or_else(|| Error::parse("year!", "the list of days is not terminated"))?;
+    lines.insert(end, format!("    {} => day{}::Day{},", day, day, day));
+    let day_number = |line: &String| {
+        line.trim()
+            .split_once(" => ")
+            .and_then(|(number, _)| number.parse::<u8>().ok())
+    };
+    lines[start + 1..end + 1].sort_by_key(day_number);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Add a crate to the `members` of the workspace, in alphabetical order.
+fn add_workspace_member(text: &str, crate_name: &str) -> Result<String> {
+    let mut lines = text.lines().map(String::from).collect::<Vec<_>>();
+    let line = lines
+        .iter_mut()
+        .find(|line| line.starts_with("members = ["))
+        .ok_or_else(|| Error::parse("members", "the workspace members are missing"))?;
+    let members = line
+        .strip_prefix("members = [")
+        .and_then(|rest| rest.strip_suffix(']'))
+        .ok_or_else(|| Error::parse(line, "expected the members on a single line"))?;
+
+    let mut members = members
+        .split(',')
+        .map(|member| member.trim().to_string())
+        .filter(|member| !member.is_empty())
+        .collect::<Vec<_>>();
+    members.push(format!("\"{}\"", crate_name));
+    members.sort();
+    *line = format!("members = [{}]", members.join(", "));
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Make the year an optional dependency of the runner, enabled by default.
+fn add_runner_dependency(text: &str, crate_name: &str) -> Result<String> {
+    let mut lines = text.lines().map(String::from).collect::<Vec<_>>();
+
+    let default = lines
+        .iter_mut()
+        .find(|line| line.starts_with("default = ["))
+        .ok_or_else(|| Error::parse("default", "the default features are missing"))?;
+    let features = default
+        .strip_suffix(']')
+        .ok_or_else(|| Error::parse(default, "expected the features on a single line"))?;
+    *default = match features.ends_with('[') {
+        true => format!("{}\"{}\"]", features, crate_name),
+        false => format!("{}, \"{}\"]", features, crate_name),
+    };
+
+    let last_year = lines
+        .iter()
+        .rposition(|line| line.starts_with("year_"))
+        .or_else(|| lines.iter().position(|line| line == "[dependencies]"))
+        .ok_or_else(|| Error::parse("[dependencies]", "the dependencies are missing"))?;
+    lines.insert(
+        last_year + 1,
+        format!(
+            "{} = {{ path = \"../{}\", optional = true }}",
+            crate_name, crate_name
+        ),
+    );
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Add the year to the `YEARS` run by the runner.
+fn add_runner_year(text: &str, crate_name: &str) -> Result<String> {
+    let mut lines = text.lines().map(String::from).collect::<Vec<_>>();
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("const YEARS: &[Year] = &["))
+        .ok_or_else(|| Error::parse("YEARS", "the list of years is missing"))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line == "];")
+            .ok_or_else(|| Error::parse("YEARS", "the list of years is not terminated"))?;
+    lines.splice(
+        end..end,
+        [
+            format!("    #[cfg(feature = \"{}\")]", crate_name),
+            format!("    {}::YEAR,", crate_name),
+        ],
+    );
+
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workspace(name: &str) -> PathBuf {
+        let root = crate::test_dir(&format!("scaffold-{}", name));
+        let files = [
+            (
+                "Cargo.toml",
+                "[workspace]\nmembers = [\"aoc\", \"aoc_utils\", \"year_2024\"]\nresolver = \"2\"\n",
+            ),
+            (
+                "aoc/Cargo.toml",
+                "[features]\ndefault = [\"year_2024\"]\n\n[dependencies]\n\
+                 aoc_utils = { path = \"../aoc_utils\" }\n\
+                 year_2024 = { path = \"../year_2024\", optional = true }\n",
+            ),
+            (
+                "aoc/src/main.rs",
+                "const YEARS: &[Year] = &[\n    #[cfg(feature = \"year_2024\")]\n    \
+                 year_2024::YEAR,\n];\n",
+            ),
+        ];
+        for (path, text) in files {
+            write(&root.join(path), text).unwrap();
+        }
+        root
+    }
+
+    fn read(root: &Path, path: &str) -> String {
+        fs::read_to_string(root.join(path)).unwrap()
+    }
+
+    #[test]
+    fn test_new_year() {
+        let root = workspace("new-year");
+
+        let changed = new_day(&root, 2026, 10).unwrap();
+        assert_eq!(changed.len(), 8);
+        assert!(new_day(&root, 2026, 3).is_ok());
+        assert!(new_day(&root, 2026, 1).is_ok());
+        assert!(new_day(&root, 2026, 3).is_err());
+
+        assert_eq!(
+            read(&root, "year_2026/src/lib.rs"),
+            "mod day1;\nmod day10;\nmod day3;\n\n\
+             aoc_utils::year!(2026, [\n    \
+             1 => day1::Day1,\n    \
+             3 => day3::Day3,\n    \
+             10 => day10::Day10,\n]);\n"
+        );
+        assert!(read(&root, "year_2026/src/day3.rs").contains("impl Solution for Day3 {"));
+        assert_eq!(read(&root, "year_2026/input/day3.txt"), "");
+        assert!(read(&root, "year_2026/Cargo.toml").contains("name = \"year_2026\""));
+
+        assert!(read(&root, "Cargo.toml")
+            .contains("members = [\"aoc\", \"aoc_utils\", \"year_2024\", \"year_2026\"]"));
+        assert!(read(&root, "aoc/Cargo.toml").ends_with(
+            "year_2024 = { path = \"../year_2024\", optional = true }\n\
+             year_2026 = { path = \"../year_2026\", optional = true }\n"
+        ));
+        assert!(read(&root, "aoc/Cargo.toml").contains("default = [\"year_2024\", \"year_2026\"]"));
+        assert!(read(&root, "aoc/src/main.rs")
+            .ends_with("    #[cfg(feature = \"year_2026\")]\n    year_2026::YEAR,\n];\n"));
+    }
+
+    #[test]
+    fn test_register_day() {
+        let lib = "mod day1;\nmod day2;\n\nmod day10;\nmod day4;\n\n\
+                   aoc_utils::year!(2025, [\n    \
+                   1 => day1::Day1,\n    \
+                   2 => day2::Day2,\n    \
+                   4 => day4::Day4,\n    \
+                   10 => day10::Day10,\n]);\n";
+        assert_eq!(
+            register_day(lib, 5).unwrap(),
+            "mod day1;\nmod day2;\n\nmod day10;\nmod day4;\nmod day5;\n\n\
+             aoc_utils::year!(2025, [\n    \
+             1 => day1::Day1,\n    \
+             2 => day2::Day2,\n    \
+             4 => day4::Day4,\n    \
+             5 => day5::Day5,\n    \
+             10 => day10::Day10,\n]);\n"
+        );
+        assert!(register_day("mod day1;\n", 2).is_err());
+    }
+}
@@ -0,0 +1,353 @@
+use num_traits::Zero;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The outcome of a search: the distance of every node reached from the
+/// starts, and how they were reached.
+///
+/// The predecessors of a node are all the nodes from which it is reached at
+/// its best distance, so they form the DAG of all the shortest paths.
+#[derive(Debug, Clone)]
+pub struct Paths<N, C> {
+    distances: HashMap<N, C>,
+    predecessors: HashMap<N, Vec<N>>,
+    goals: Vec<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Paths<N, C> {
+    fn new() -> Paths<N, C> {
+        Paths {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            goals: Vec::new(),
+        }
+    }
+
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<N, C> {
+        &self.distances
+    }
+
+    /// The nodes preceding `node` on its shortest paths, none for a start.
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.predecessors.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    /// The goals reached at the best distance, in the order they were reached.
+    pub fn goals(&self) -> &[N] {
+        &self.goals
+    }
+
+    /// The first goal reached, with its distance.
+    pub fn goal(&self) -> Option<(&N, C)> {
+        let goal = self.goals.first()?;
+        Some((goal, self.distances[goal]))
+    }
+
+    /// One of the shortest paths from a start to `node`, both included.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        self.distances.get(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.predecessors(path.last().unwrap()).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// All the nodes lying on any shortest path to any of `targets`.
+    pub fn nodes_on_paths_to<I: IntoIterator<Item = N>>(&self, targets: I) -> HashSet<N> {
+        let mut queue = targets
+            .into_iter()
+            .filter(|target| self.distances.contains_key(target))
+            .collect::<Vec<_>>();
+        let mut nodes = queue.iter().cloned().collect::<HashSet<_>>();
+        while let Some(node) = queue.pop() {
+            for previous in self.predecessors(&node) {
+                if nodes.insert(previous.clone()) {
+                    queue.push(previous.clone());
+                }
+            }
+        }
+        nodes
+    }
+
+    fn add_predecessor(&mut self, node: &N, previous: &N) {
+        let predecessors = self.predecessors.entry(node.clone()).or_default();
+        if !predecessors.contains(previous) {
+            predecessors.push(previous.clone());
+        }
+    }
+}
+
+/// Breadth-first search from `starts`, each step costing 1.
+///
+/// The search stops once all the goals at the smallest distance are reached,
+/// use `|_| false` as `is_goal` to explore everything reachable.
+pub fn bfs<N, S, F, I, G>(starts: S, mut successors: F, mut is_goal: G) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    S: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    let mut paths = Paths::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !paths.distances.contains_key(&start) {
+            paths.distances.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let distance = paths.distances[&node];
+        if paths.goal().is_some_and(|(_, best)| distance > best) {
+            break;
+        }
+        if is_goal(&node) {
+            paths.goals.push(node.clone());
+            continue;
+        }
+
+        for next in successors(&node) {
+            match paths.distances.get(&next) {
+                None => {
+                    paths.distances.insert(next.clone(), distance + 1);
+                    paths.add_predecessor(&next, &node);
+                    queue.push_back(next);
+                }
+                Some(&existing) if existing == distance + 1 => {
+                    paths.add_predecessor(&next, &node);
+                }
+                Some(_) => {}
+            }
+        }
+    }
+    paths
+}
+
+/// Dijkstra's search from `starts`, `successors` giving the next nodes along
+/// with the cost to reach them.
+///
+/// The search stops once all the goals at the smallest distance are reached,
+/// use `|_| false` as `is_goal` to explore everything reachable.
+pub fn dijkstra<N, C, S, F, I, G>(starts: S, successors: F, is_goal: G) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Zero + Add<Output = C>,
+    S: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    G: FnMut(&N) -> bool,
+{
+    astar(starts, successors, |_| C::zero(), is_goal)
+}
+
+/// A* search from `starts`, guided by a `heuristic` that never overestimates
+/// the remaining cost to a goal and is consistent.
+///
+/// Only the nodes needed to reach the best goals are guaranteed to have their
+/// shortest distance and predecessors.
+pub fn astar<N, C, S, F, I, H, G>(
+    starts: S,
+    mut successors: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Zero + Add<Output = C>,
+    S: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let mut paths = Paths::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        if !paths.distances.contains_key(&start) {
+            paths.distances.insert(start.clone(), C::zero());
+            queue.push(State {
+                estimate: heuristic(&start),
+                distance: C::zero(),
+                node: start,
+            });
+        }
+    }
+
+    while let Some(State {
+        estimate,
+        distance,
+        node,
+    }) = queue.pop()
+    {
+        if distance > paths.distances[&node] {
+            continue;
+        }
+        if paths.goal().is_some_and(|(_, best)| estimate > best) {
+            break;
+        }
+        if is_goal(&node) {
+            paths.goals.push(node.clone());
+            continue;
+        }
+
+        for (next, cost) in successors(&node) {
+            let next_distance = distance + cost;
+            match paths.distances.get(&next) {
+                Some(&existing) if existing < next_distance => {}
+                Some(&existing) if existing == next_distance => {
+                    paths.add_predecessor(&next, &node);
+                }
+                _ => {
+                    paths.distances.insert(next.clone(), next_distance);
+                    paths.predecessors.insert(next.clone(), vec![node.clone()]);
+                    queue.push(State {
+                        estimate: next_distance + heuristic(&next),
+                        distance: next_distance,
+                        node: next,
+                    });
+                }
+            }
+        }
+    }
+    paths
+}
+
+/// An entry of the priority queue, the smallest estimate coming first.
+struct State<N, C> {
+    estimate: C,
+    distance: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for State<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.distance.cmp(&other.distance))
+    }
+}
+
+impl<N, C: Ord> PartialOrd for State<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for State<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for State<N, C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{Grid, Position};
+
+    fn maze() -> Grid<bool> {
+        Grid::from_lines(
+            &[
+                "S...#", //
+                ".##.#", //
+                "....E", //
+            ],
+            |c| Ok(c != '#'),
+        )
+        .unwrap()
+    }
+
+    fn open_neighbours(maze: &Grid<bool>, position: &Position) -> Vec<Position> {
+        maze.neighbours4(*position)
+            .filter(|&next| maze[next])
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let maze = maze();
+        let paths = bfs(
+            [(0, 0)],
+            |position| open_neighbours(&maze, position),
+            |&position| position == (4, 2),
+        );
+        assert_eq!(paths.goal(), Some((&(4, 2), 6)));
+        assert_eq!(paths.distance(&(3, 1)), Some(4));
+        assert_eq!(paths.predecessors(&(0, 0)), &[]);
+
+        let path = paths.path_to(&(4, 2)).unwrap();
+        assert_eq!(path.len(), 7);
+        assert_eq!(path[0], (0, 0));
+
+        // Both ways around the wall are shortest paths
+        let on_paths = paths.nodes_on_paths_to([(4, 2)]);
+        assert_eq!(on_paths.len(), 11);
+        assert!(on_paths.contains(&(1, 0)) && on_paths.contains(&(0, 1)));
+    }
+
+    #[test]
+    fn test_bfs_exhaustive() {
+        let maze = maze();
+        let paths = bfs([(0, 0)], |p| open_neighbours(&maze, p), |_| false);
+        assert_eq!(paths.goals(), &[]);
+        assert_eq!(paths.distances().len(), 11);
+        assert_eq!(paths.distance(&(4, 0)), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // Moving is cheaper along the first row
+        let maze = maze();
+        let successors = |position: &Position| {
+            open_neighbours(&maze, position)
+                .into_iter()
+                .map(|next| (next, if next.1 == 0 { 1 } else { 2 }))
+                .collect::<Vec<_>>()
+        };
+        let paths = dijkstra([(0, 0)], successors, |&position| position == (4, 2));
+        assert_eq!(paths.goal(), Some((&(4, 2), 9)));
+        assert_eq!(
+            paths.path_to(&(4, 2)).unwrap(),
+            vec![(0, 0), (1, 0), (2, 0), (3, 0), (3, 1), (3, 2), (4, 2)]
+        );
+        assert_eq!(paths.nodes_on_paths_to([(4, 2)]).len(), 7);
+    }
+
+    #[test]
+    fn test_astar() {
+        let size = 20i32;
+        let goal = (size - 1, 0);
+        let successors = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                .into_iter()
+                .filter(|&(x, y)| (0..size).contains(&x) && (0..size).contains(&y))
+                .map(|next| (next, 1))
+                .collect::<Vec<_>>()
+        };
+        let heuristic = |&(x, y): &(i32, i32)| (goal.0 - x).abs() + (goal.1 - y).abs();
+
+        let paths = astar([(0, 0)], successors, heuristic, |&node| node == goal);
+        assert_eq!(paths.goal(), Some((&goal, 19)));
+        assert_eq!(paths.path_to(&goal).unwrap().len(), 20);
+        assert!(paths.distances().len() < 2 * size as usize);
+    }
+
+    #[test]
+    fn test_several_starts() {
+        let paths = bfs([0, 10], |&n: &i32| [n + 1], |&n| n == 12);
+        assert_eq!(paths.goal(), Some((&12, 2)));
+        assert_eq!(paths.path_to(&12).unwrap(), vec![10, 11, 12]);
+    }
+}
@@ -0,0 +1,76 @@
+//! The common shape of the solution of a day: parse the input once, then
+//! solve each part from it.
+
+use crate::answer::{Answer, DayResult, Part};
+use crate::Result;
+
+pub trait Solution {
+    /// The day of the puzzle, whose input is read from `dayN.txt`.
+    const DAY: u8;
+
+    /// The puzzle input once parsed.
+    type Input;
+
+    fn parse(lines: &[String]) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(input: &Self::Input) -> Answer;
+}
+
+/// Read and parse the input of the day of `S`, then solve `part`, or both
+/// parts when it is `None`, recording the time of each phase.
+///
+/// The answer of a part which is not solved is [`Answer::Unsolved`].
+pub fn solve<S: Solution>(part: Option<Part>) -> Result<DayResult> {
+    let input = crate::input::parse(&format!("day{}.txt", S::DAY), |lines| S::parse(&lines))?;
+    crate::timing::parsed();
+
+    let part1 = match part {
+        Some(Part::Two) => Answer::Unsolved,
+        _ => S::part1(&input),
+    };
+    crate::timing::part1_solved();
+    let part2 = match part {
+        Some(Part::One) => Answer::Unsolved,
+        _ => S::part2(&input),
+    };
+
+    Ok(DayResult::new(part1, part2))
+}
+
+/// Solve both parts of the day of `S`.
+pub fn execute<S: Solution>() -> Result<DayResult> {
+    solve::<S>(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sums;
+
+    impl Solution for Sums {
+        const DAY: u8 = 24;
+        type Input = Vec<u32>;
+
+        fn parse(lines: &[String]) -> Result<Self::Input> {
+            crate::parse::lines(lines, crate::parse::value)
+        }
+
+        fn part1(input: &Self::Input) -> Answer {
+            input.iter().sum::<u32>().into()
+        }
+
+        fn part2(input: &Self::Input) -> Answer {
+            input.iter().map(|n| n * n).sum::<u32>().into()
+        }
+    }
+
+    #[test]
+    fn test_parts() {
+        let input = Sums::parse(&[String::from("1"), String::from("2")]).unwrap();
+        assert_eq!(Sums::part1(&input), 3);
+        assert_eq!(Sums::part2(&input), 5);
+    }
+}
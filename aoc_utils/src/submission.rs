@@ -0,0 +1,317 @@
+use crate::answer::{Answer, Part};
+use crate::{Error, Result};
+use std::fmt::{Display, Formatter};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+
+/// Name of the file logging the answers submitted for a year, next to its inputs.
+pub const SUBMISSIONS_FILE: &str = "submissions.log";
+
+/// What the website replied to a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The previous answer was submitted too recently, retry after this delay.
+    Wait(Duration),
+    /// The part is already solved, so the answer was not checked.
+    AlreadySolved,
+}
+
+impl Verdict {
+    /// Understand the page returned after submitting an answer.
+    pub fn from_response(html: &str) -> Option<Verdict> {
+        if html.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if html.contains("That's not the right answer") {
+            Some(if html.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if html.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            })
+        } else if html.contains("You gave an answer too recently") {
+            Some(Verdict::Wait(parse_wait(html).unwrap_or_default()))
+        } else if html.contains("You don't seem to be solving the right level") {
+            Some(Verdict::AlreadySolved)
+        } else {
+            None
+        }
+    }
+
+    /// Read back a verdict written with its `Display` implementation.
+    pub fn parse(text: &str) -> Result<Verdict> {
+        match text {
+            "correct" => Ok(Verdict::Correct),
+            "too-high" => Ok(Verdict::TooHigh),
+            "too-low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            "already-solved" => Ok(Verdict::AlreadySolved),
+            _ => match text.strip_prefix("wait-").and_then(|s| s.strip_suffix('s')) {
+                Some(seconds) => Ok(Verdict::Wait(Duration::from_secs(crate::parse::value(
+                    seconds,
+                )?))),
+                None => Err(Error::parse(text, "unknown verdict")),
+            },
+        }
+    }
+
+    /// Whether the answer was compared to the solution.
+    pub fn is_judged(&self) -> bool {
+        !matches!(self, Verdict::Wait(_) | Verdict::AlreadySolved)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too-high"),
+            Verdict::TooLow => write!(f, "too-low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::Wait(delay) => write!(f, "wait-{}s", delay.as_secs()),
+            Verdict::AlreadySolved => write!(f, "already-solved"),
+        }
+    }
+}
+
+/// The delay in e.g. "You have 1m 5s left to wait".
+fn parse_wait(html: &str) -> Option<Duration> {
+    let (_, rest) = html.split_once("You have ")?;
+    let (delay, _) = rest.split_once(" left to wait")?;
+    let mut seconds = 0;
+    for amount in delay.split_whitespace() {
+        let unit = match amount.chars().last()? {
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+        seconds += amount[..amount.len() - 1].parse::<u64>().ok()? * unit;
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// One answer submitted for a part of a day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Answer,
+    pub verdict: Verdict,
+}
+
+impl Attempt {
+    /// Read back an attempt written with its `Display` implementation.
+    pub fn parse(line: &str) -> Result<Attempt> {
+        let fields = line.splitn(4, '\t').collect::<Vec<_>>();
+        let [day, part, verdict, answer] = fields[..] else {
+            return Err(Error::parse(line, "expected 4 fields separated by tabs"));
+        };
+        Ok(Attempt {
+            day: crate::parse::value(day)?,
+            part: Part::from_text(part).map_err(|message| Error::parse(part, message))?,
+            answer: Answer::parse(answer),
+            verdict: Verdict::parse(verdict)?,
+        })
+    }
+}
+
+impl Display for Attempt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}",
+            self.day,
+            self.part.number(),
+            self.verdict,
+            self.answer
+        )
+    }
+}
+
+/// The log of all the answers submitted for a year, one [`Attempt`] per line.
+#[derive(Debug, Default, PartialEq)]
+pub struct Submissions {
+    attempts: Vec<Attempt>,
+}
+
+impl Submissions {
+    /// Read the log at `path`, empty when there is no such file yet.
+    pub fn read<P: AsRef<Path>>(path: P) -> Result<Submissions> {
+        let path = path.as_ref();
+        if !path.is_file() {
+            return Ok(Submissions::default());
+        }
+        let lines = crate::read_lines(path)?;
+        let attempts =
+            crate::parse::lines(&lines, Attempt::parse).map_err(|error| error.in_file(path))?;
+        Ok(Submissions { attempts })
+    }
+
+    /// Add an attempt to the log at `path`.
+    pub fn record<P: AsRef<Path>>(&mut self, path: P, attempt: Attempt) -> Result<()> {
+        let path = path.as_ref();
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| writeln!(file, "{}", attempt))
+            .map_err(|source| Error::Io {
+                path: path.to_path_buf(),
+                source,
+            })?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// The verdict an answer would get, deduced from the previous attempts
+    /// without submitting it again.
+    pub fn known_verdict(&self, day: u8, part: Part, answer: &Answer) -> Option<Verdict> {
+        let mut verdict = None;
+        for attempt in self.attempts.iter() {
+            if attempt.day != day || attempt.part != part || !attempt.verdict.is_judged() {
+                continue;
+            }
+            if attempt.answer == *answer {
+                return Some(attempt.verdict);
+            }
+            verdict = match (attempt.verdict, &attempt.answer, answer) {
+                (Verdict::Correct, _, _) => Some(Verdict::Wrong),
+                (Verdict::TooHigh, Answer::Number(high), Answer::Number(number))
+                    if number > high =>
+                {
+                    Some(Verdict::TooHigh)
+                }
+                (Verdict::TooLow, Answer::Number(low), Answer::Number(number)) if number < low => {
+                    Some(Verdict::TooLow)
+                }
+                _ => continue,
+            };
+        }
+        verdict
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attempt(day: u8, part: Part, answer: i128, verdict: Verdict) -> Attempt {
+        Attempt {
+            day,
+            part,
+            answer: Answer::Number(answer),
+            verdict,
+        }
+    }
+
+    #[test]
+    fn test_verdict_from_response() {
+        let response = |text: &str| {
+            Verdict::from_response(&format!("<main><article><p>{}</p></article></main>", text))
+        };
+        assert_eq!(
+            response("That's the right answer!  You are one gold star closer."),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            response("That's not the right answer; your answer is too high."),
+            Some(Verdict::TooHigh)
+        );
+        assert_eq!(
+            response("That's not the right answer; your answer is too low."),
+            Some(Verdict::TooLow)
+        );
+        assert_eq!(
+            response("That's not the right answer.  If you're stuck, ..."),
+            Some(Verdict::Wrong)
+        );
+        assert_eq!(
+            response("You gave an answer too recently.  You have 1m 5s left to wait."),
+            Some(Verdict::Wait(Duration::from_secs(65)))
+        );
+        assert_eq!(
+            response("You don't seem to be solving the right level.  Did you already complete it?"),
+            Some(Verdict::AlreadySolved)
+        );
+        assert_eq!(response("Something else"), None);
+    }
+
+    #[test]
+    fn test_attempt_display_parse() {
+        let attempts = [
+            attempt(7, Part::One, 12345, Verdict::TooHigh),
+            attempt(17, Part::Two, -3, Verdict::Wait(Duration::from_secs(42))),
+            Attempt {
+                day: 17,
+                part: Part::One,
+                answer: Answer::from("4,3,7,1"),
+                verdict: Verdict::Correct,
+            },
+        ];
+        for attempt in attempts {
+            let line = attempt.to_string();
+            assert_eq!(Attempt::parse(&line).unwrap(), attempt);
+        }
+        assert_eq!(
+            attempt(7, Part::One, 12345, Verdict::TooHigh).to_string(),
+            "7\t1\ttoo-high\t12345"
+        );
+        assert!(Attempt::parse("7\t3\twrong\t1").is_err());
+        assert!(Attempt::parse("7\t1\tmaybe\t1").is_err());
+        assert!(Attempt::parse("7 1 wrong 1").is_err());
+    }
+
+    #[test]
+    fn test_known_verdict() {
+        let submissions = Submissions {
+            attempts: vec![
+                attempt(1, Part::One, 100, Verdict::TooHigh),
+                attempt(1, Part::One, 10, Verdict::TooLow),
+                attempt(1, Part::One, 50, Verdict::Wrong),
+                attempt(1, Part::One, 60, Verdict::Wait(Duration::from_secs(30))),
+                attempt(1, Part::Two, 7, Verdict::Correct),
+            ],
+        };
+        let known = |part, answer| submissions.known_verdict(1, part, &Answer::Number(answer));
+
+        assert_eq!(known(Part::One, 100), Some(Verdict::TooHigh));
+        assert_eq!(known(Part::One, 150), Some(Verdict::TooHigh));
+        assert_eq!(known(Part::One, 5), Some(Verdict::TooLow));
+        assert_eq!(known(Part::One, 50), Some(Verdict::Wrong));
+        assert_eq!(known(Part::One, 60), None);
+        assert_eq!(known(Part::One, 99), None);
+        assert_eq!(known(Part::Two, 7), Some(Verdict::Correct));
+        assert_eq!(known(Part::Two, 8), Some(Verdict::Wrong));
+        assert_eq!(
+            submissions.known_verdict(2, Part::One, &Answer::Number(100)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_record_read() {
+        let path = crate::test_dir("submissions").join(SUBMISSIONS_FILE);
+        let mut submissions = Submissions::read(&path).unwrap();
+        assert_eq!(submissions, Submissions::default());
+
+        submissions
+            .record(&path, attempt(3, Part::One, 10, Verdict::TooLow))
+            .unwrap();
+        submissions
+            .record(&path, attempt(3, Part::One, 20, Verdict::Correct))
+            .unwrap();
+        assert_eq!(submissions.attempts().len(), 2);
+        assert_eq!(Submissions::read(&path).unwrap(), submissions);
+    }
+}
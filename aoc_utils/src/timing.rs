@@ -0,0 +1,223 @@
+use crate::answer::Part;
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+/// Wall-time spent running a day.
+///
+/// The phases are only known when the day marks their end with [`parsed`] and
+/// [`part1_solved`]: whatever runs after part 1 is counted as part 2.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Timings {
+    pub parse: Option<Duration>,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+    pub total: Duration,
+}
+
+impl Timings {
+    /// The name and duration of each phase, the last one being the total.
+    pub fn phases(&self) -> [(&'static str, Option<Duration>); 4] {
+        [
+            ("parse", self.parse),
+            ("part 1", self.part1),
+            ("part 2", self.part2),
+            ("total", Some(self.total)),
+        ]
+    }
+
+    /// Forget the time of the part which was skipped, when only `part` was solved.
+    pub fn for_part(mut self, part: Option<Part>) -> Timings {
+        match part {
+            Some(Part::One) => self.part2 = None,
+            Some(Part::Two) => self.part1 = None,
+            None => {}
+        }
+        self
+    }
+}
+
+struct Stopwatch {
+    start: Instant,
+    last: Instant,
+    parse: Option<Duration>,
+    part1: Option<Duration>,
+}
+
+thread_local! {
+    static STOPWATCH: RefCell<Option<Stopwatch>> = const { RefCell::new(None) };
+}
+
+/// Run `f` and measure it, along with the phases it marks.
+///
+/// Measures may be nested, e.g. when a thread waiting on [`crate::parallel::map`]
+/// picks up another day: the marks then go to the innermost measure.
+pub fn measure<T, F: FnOnce() -> T>(f: F) -> (T, Timings) {
+    let now = Instant::now();
+    let outer = STOPWATCH.replace(Some(Stopwatch {
+        start: now,
+        last: now,
+        parse: None,
+        part1: None,
+    }));
+
+    let result = f();
+
+    let now = Instant::now();
+    let stopwatch = STOPWATCH
+        .replace(outer)
+        .expect("the stopwatch of a measure is restored when it ends");
+    let part2 = stopwatch.part1.map(|_| now - stopwatch.last);
+    let timings = Timings {
+        parse: stopwatch.parse,
+        part1: stopwatch.part1,
+        part2,
+        total: now - stopwatch.start,
+    };
+    (result, timings)
+}
+
+fn lap(record: fn(&mut Stopwatch, Duration)) {
+    STOPWATCH.with_borrow_mut(|stopwatch| {
+        if let Some(stopwatch) = stopwatch {
+            let now = Instant::now();
+            record(stopwatch, now - stopwatch.last);
+            stopwatch.last = now;
+        }
+    });
+}
+
+/// Mark the end of the parsing of the input of the day being measured.
+pub fn parsed() {
+    lap(|stopwatch, elapsed| stopwatch.parse = Some(elapsed));
+}
+
+/// Mark the end of part 1 of the day being measured.
+pub fn part1_solved() {
+    lap(|stopwatch, elapsed| stopwatch.part1 = Some(elapsed));
+}
+
+/// Minimum, median and maximum of repeated measures.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_durations<I: IntoIterator<Item = Duration>>(durations: I) -> Option<Stats> {
+        let mut durations = durations.into_iter().collect::<Vec<_>>();
+        durations.sort();
+        let n = durations.len();
+        if n == 0 {
+            return None;
+        }
+        let median = match n % 2 {
+            0 => (durations[n / 2 - 1] + durations[n / 2]) / 2,
+            _ => durations[n / 2],
+        };
+        Some(Stats {
+            min: durations[0],
+            median,
+            max: durations[n - 1],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread::sleep;
+
+    #[test]
+    fn test_measure_phases() {
+        let (result, timings) = measure(|| {
+            sleep(Duration::from_millis(2));
+            parsed();
+            sleep(Duration::from_millis(1));
+            part1_solved();
+            sleep(Duration::from_millis(1));
+            42
+        });
+        assert_eq!(result, 42);
+
+        let parse = timings.parse.unwrap();
+        let part1 = timings.part1.unwrap();
+        let part2 = timings.part2.unwrap();
+        assert!(parse >= Duration::from_millis(2));
+        assert!(part1 >= Duration::from_millis(1));
+        assert!(part2 >= Duration::from_millis(1));
+        assert!(parse + part1 + part2 <= timings.total);
+    }
+
+    #[test]
+    fn test_measure_unmarked() {
+        let ((), timings) = measure(|| sleep(Duration::from_millis(1)));
+        assert_eq!(timings.parse, None);
+        assert_eq!(timings.part1, None);
+        assert_eq!(timings.part2, None);
+        assert!(timings.total >= Duration::from_millis(1));
+
+        // Outside of a measure, marks are ignored
+        parsed();
+        part1_solved();
+    }
+
+    #[test]
+    fn test_for_part() {
+        let ms = Duration::from_millis;
+        let timings = Timings {
+            parse: Some(ms(1)),
+            part1: Some(ms(2)),
+            part2: Some(ms(3)),
+            total: ms(6),
+        };
+        assert_eq!(timings.for_part(None), timings);
+        assert_eq!(timings.for_part(Some(Part::One)).part2, None);
+        assert_eq!(timings.for_part(Some(Part::Two)).part1, None);
+        assert_eq!(timings.for_part(Some(Part::Two)).part2, Some(ms(3)));
+    }
+
+    #[test]
+    fn test_nested_measures() {
+        let (inner, outer) = measure(|| {
+            parsed();
+            let inner = crate::parallel::map((0..16).collect(), |n: u32| {
+                measure(|| {
+                    sleep(Duration::from_millis(1));
+                    parsed();
+                    n
+                })
+            });
+            part1_solved();
+            inner
+        });
+
+        assert_eq!(inner.len(), 16);
+        for (n, (result, timings)) in inner.into_iter().enumerate() {
+            assert_eq!(result as usize, n);
+            assert!(timings.parse.unwrap() >= Duration::from_millis(1));
+            assert_eq!(timings.part1, None);
+        }
+        assert!(outer.parse.unwrap() < outer.part1.unwrap());
+        assert!(outer.part2.is_some());
+    }
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+        assert_eq!(Stats::from_durations([]), None);
+        assert_eq!(
+            Stats::from_durations([ms(3), ms(1), ms(2)]),
+            Some(Stats {
+                min: ms(1),
+                median: ms(2),
+                max: ms(3)
+            })
+        );
+        assert_eq!(
+            Stats::from_durations([ms(4), ms(1)]).unwrap().median,
+            ms(2) + ms(1) / 2
+        );
+    }
+}
@@ -1,9 +1,23 @@
-pub fn execute() -> String {
-    let input = aoc_utils::read_lines("input/day1.txt");
-    let part1 = sum_lines(&input, false).unwrap();
-    let part2 = sum_lines(&input, true).unwrap();
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 
-    format!("{} {}", part1, part2)
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Input = Vec<String>;
+
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Ok(lines.to_vec())
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        sum_lines(input, false).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        sum_lines(input, true).map_or(Answer::Unsolved, Answer::from)
+    }
 }
 
 struct Digit<'a> {
@@ -112,15 +126,17 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "54927 54581");
+        let result = aoc_utils::solution::execute::<Day1>().unwrap();
+        assert_eq!(result.part1, 54927);
+        assert_eq!(result.part2, 54581);
     }
 
     #[test]
     fn test_sum_lines() {
-        let example1 = aoc_utils::read_lines("input/day1-example1.txt");
+        let example1 = aoc_utils::read_lines("input/day1-example1.txt").unwrap();
         assert_eq!(Some(142), sum_lines(&example1, false));
 
-        let example2 = aoc_utils::read_lines("input/day1-example2.txt");
+        let example2 = aoc_utils::read_lines("input/day1-example2.txt").unwrap();
         assert_eq!(Some(281), sum_lines(&example2, true));
     }
 }
@@ -1,15 +1,29 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use aoc_utils::search;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
+use std::collections::{HashMap, HashSet};
 use std::fmt::{Display, Formatter};
 
-pub fn execute() -> String {
-    let map = PipeMap::from_file("day10.txt");
-    let net = map.to_network();
-    let length = net.pipe_length(map.start().unwrap());
+pub struct Day10;
 
-    let part1 = length / 2;
-    let part2 = map.inner_size();
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = PipeMap;
 
-    format!("{} {}", part1, part2)
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Ok(PipeMap::from_lines(lines))
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        let net = map.to_network();
+        map.start().map_or(Answer::Unsolved, |start| {
+            (net.pipe_length(start) / 2).into()
+        })
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        map.inner_size().into()
+    }
 }
 
 struct Network {
@@ -55,22 +69,8 @@ impl Network {
     }
 
     fn reachable_from(&self, start: &Position) -> HashSet<Position> {
-        let mut reachable = HashSet::new();
-        let mut next_positions = VecDeque::from([start.clone()]);
-
-        while !next_positions.is_empty() {
-            let next = next_positions.pop_front().unwrap();
-            let neighbours = self.get(&next);
-
-            reachable.insert(next);
-
-            for neighbour in neighbours {
-                if !reachable.contains(&neighbour) {
-                    next_positions.push_back(neighbour.clone());
-                }
-            }
-        }
-        reachable
+        let paths = search::bfs([start.clone()], |position| self.get(position), |_| false);
+        paths.distances().keys().cloned().collect()
     }
 
     fn inner_region(&self, start: &Position) -> HashSet<Position> {
@@ -104,36 +104,23 @@ impl Network {
     }
 
     fn _outer_region(&self, max_x: usize, max_y: usize) -> HashSet<Position> {
-        let mut outer = HashSet::new();
-        let mut to_visit = VecDeque::from([Position(0, 0), Position(max_x, max_y)]);
-
-        while !to_visit.is_empty() {
-            use Direction::*;
-
-            let current = to_visit.pop_front().unwrap();
-
-            if self.connections.contains_key(&current) {
-                continue;
-            }
-
-            for direction in [North, South, East, West] {
-                if !current.can_step(&direction) {
-                    continue;
-                }
-                let next = current.to(&direction).unwrap();
-                if next.0 > max_x
-                    || next.1 > max_y
-                    || outer.contains(&next)
-                    || to_visit.contains(&next)
-                {
-                    continue;
-                }
-                to_visit.push_back(next);
-            }
+        use Direction::*;
 
-            outer.insert(current);
-        }
-        outer
+        let is_outer = |position: &Position| {
+            position.0 <= max_x && position.1 <= max_y && !self.connections.contains_key(position)
+        };
+        let starts = [Position(0, 0), Position(max_x, max_y)]
+            .into_iter()
+            .filter(is_outer);
+        let neighbours = |current: &Position| {
+            [North, South, East, West]
+                .iter()
+                .filter_map(|direction| current.to(direction).ok())
+                .filter(is_outer)
+                .collect::<Vec<_>>()
+        };
+        let paths = search::bfs(starts, neighbours, |_| false);
+        paths.distances().keys().cloned().collect()
     }
 
     fn expand(&self) -> Self {
@@ -164,15 +151,19 @@ fn get_direction(a: &Position, b: &Position) -> Result<Direction, &'static str>
     }
 }
 
-struct PipeMap {
+pub struct PipeMap {
     nodes: HashMap<Position, Pipe>,
 }
 
 impl PipeMap {
-    fn from_file(filename: &str) -> PipeMap {
-        let path = format!("input/{}", &filename);
-        let lines = aoc_utils::read_lines(&path);
+    #[cfg(test)]
+    fn from_file(filename: &str) -> aoc_utils::Result<PipeMap> {
+        Ok(PipeMap::from_lines(&aoc_utils::input::read_lines(
+            filename,
+        )?))
+    }
 
+    fn from_lines(lines: &[String]) -> PipeMap {
         let mut nodes = HashMap::<Position, Pipe>::new();
         for (i, line) in lines.iter().enumerate() {
             for (j, char) in line.chars().enumerate() {
@@ -326,28 +317,30 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "6942 297");
+        let result = aoc_utils::solution::execute::<Day10>().unwrap();
+        assert_eq!(result.part1, 6942);
+        assert_eq!(result.part2, 297);
     }
 
     #[test]
     fn test_inner_size() {
-        let map2 = PipeMap::from_file("day10-example2.txt");
+        let map2 = PipeMap::from_file("day10-example2.txt").unwrap();
         assert_eq!(map2.inner_size(), 1);
-        let map3 = PipeMap::from_file("day10-example3.txt");
+        let map3 = PipeMap::from_file("day10-example3.txt").unwrap();
         assert_eq!(map3.inner_size(), 1);
-        let map4 = PipeMap::from_file("day10-example4.txt");
+        let map4 = PipeMap::from_file("day10-example4.txt").unwrap();
         assert_eq!(map4.inner_size(), 4);
-        let map5 = PipeMap::from_file("day10-example5.txt");
+        let map5 = PipeMap::from_file("day10-example5.txt").unwrap();
         assert_eq!(map5.inner_size(), 4);
-        let map6 = PipeMap::from_file("day10-example6.txt");
+        let map6 = PipeMap::from_file("day10-example6.txt").unwrap();
         assert_eq!(map6.inner_size(), 8);
-        let map7 = PipeMap::from_file("day10-example7.txt");
+        let map7 = PipeMap::from_file("day10-example7.txt").unwrap();
         assert_eq!(map7.inner_size(), 10);
     }
 
     #[test]
     fn test_inner_region() {
-        let map2 = PipeMap::from_file("day10-example2.txt");
+        let map2 = PipeMap::from_file("day10-example2.txt").unwrap();
         let net2 = map2.to_network();
 
         let inner = net2.inner_region(map2.start().unwrap());
@@ -358,7 +351,7 @@ mod tests {
 
     #[test]
     fn test_expand_network() {
-        let map3 = PipeMap::from_file("day10-example3.txt");
+        let map3 = PipeMap::from_file("day10-example3.txt").unwrap();
         let net3 = map3.to_network().clean(map3.start().unwrap());
         assert_eq!(8, net3.connections.len());
         let exp3 = net3.expand();
@@ -368,7 +361,7 @@ mod tests {
 
     #[test]
     fn test_pipe_length() {
-        let map3 = PipeMap::from_file("day10-example3.txt");
+        let map3 = PipeMap::from_file("day10-example3.txt").unwrap();
         let net3 = map3.to_network();
         let length = net3.pipe_length(map3.start().unwrap());
         assert_eq!(8, length);
@@ -379,9 +372,9 @@ mod tests {
 
     #[test]
     fn test_clean() {
-        let map2 = PipeMap::from_file("day10-example2.txt");
+        let map2 = PipeMap::from_file("day10-example2.txt").unwrap();
         let net2 = map2.to_network();
-        let map3 = PipeMap::from_file("day10-example3.txt");
+        let map3 = PipeMap::from_file("day10-example3.txt").unwrap();
         let net3 = map3.to_network();
         let clean3 = net3.clean(map3.start().unwrap());
 
@@ -390,7 +383,9 @@ mod tests {
 
     #[test]
     fn test_map_to_network() {
-        let net1 = PipeMap::from_file("day10-example1.txt").to_network();
+        let net1 = PipeMap::from_file("day10-example1.txt")
+            .unwrap()
+            .to_network();
 
         assert_eq!(8, net1.connections.len());
 
@@ -407,11 +402,15 @@ mod tests {
             net1.get(&Position(2, 4)),
         );
 
-        let net2 = PipeMap::from_file("day10-example2.txt").to_network();
+        let net2 = PipeMap::from_file("day10-example2.txt")
+            .unwrap()
+            .to_network();
         assert_eq!(net1.connections.len(), net2.connections.len());
         assert_eq!(net1.connections, net2.connections);
 
-        let net3 = PipeMap::from_file("day10-example3.txt").to_network();
+        let net3 = PipeMap::from_file("day10-example3.txt")
+            .unwrap()
+            .to_network();
 
         assert!(net3.connections.len() >= net1.connections.len());
         for (pos, neighbours) in net1.connections.iter() {
@@ -441,7 +440,7 @@ mod tests {
 
     #[test]
     fn test_parse_pipe_map() {
-        let map1 = PipeMap::from_file("day10-example1.txt");
+        let map1 = PipeMap::from_file("day10-example1.txt").unwrap();
         assert_eq!(5 * 5, map1.nodes.len());
         assert!(matches!(map1.start(), None));
         assert!(matches!(map1.nodes[&Position(1, 1)], Pipe::None));
@@ -456,7 +455,7 @@ mod tests {
         assert!(matches!(map1.nodes[&Position(1, 5)], Pipe::None));
         assert!(matches!(map1.nodes[&Position(5, 5)], Pipe::None));
 
-        let map2 = PipeMap::from_file("day10-example2.txt");
+        let map2 = PipeMap::from_file("day10-example2.txt").unwrap();
         assert_eq!(5 * 5, map2.nodes.len());
         assert!(matches!(map2.start(), Some(Position(2, 2))));
         assert!(matches!(map2.nodes[&Position(1, 1)], Pipe::None));
@@ -464,7 +463,7 @@ mod tests {
         assert!(matches!(map2.nodes[&Position(4, 2)], Pipe::SouthWest));
         assert!(matches!(map2.nodes[&Position(2, 4)], Pipe::NorthEast));
 
-        let map3 = PipeMap::from_file("day10-example3.txt");
+        let map3 = PipeMap::from_file("day10-example3.txt").unwrap();
         assert_eq!(5 * 5, map3.nodes.len());
         assert!(matches!(map3.start(), Some(Position(2, 2))));
         assert!(matches!(map3.nodes[&Position(1, 1)], Pipe::EastWest));
@@ -1,21 +1,36 @@
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::cmp::max;
 use std::cmp::min;
 use std::collections::HashSet;
 
-pub fn execute() -> String {
-    let universe = Universe::from_file("day11.txt", 2);
-    let part1 = universe.sum_shortest_distance();
+pub struct Day11;
 
-    let universe_expanding = Universe::from_file("day11.txt", 1000000);
-    let part2 = universe_expanding.sum_shortest_distance();
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    /// The universe expanding twice, and a million times.
+    type Input = (Universe, Universe);
 
-    format!("{} {}", part1, part2)
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Ok((
+            Universe::from_lines(lines.to_vec(), 2),
+            Universe::from_lines(lines.to_vec(), 1000000),
+        ))
+    }
+
+    fn part1((universe, _): &Self::Input) -> Answer {
+        universe.sum_shortest_distance().into()
+    }
+
+    fn part2((_, universe_expanding): &Self::Input) -> Answer {
+        universe_expanding.sum_shortest_distance().into()
+    }
 }
 
 #[derive(PartialEq, Eq, Hash, Clone, Debug, PartialOrd, Ord)]
 struct Position(usize, usize);
 
-struct Universe {
+pub struct Universe {
     galaxies: Vec<Position>,
     empty_lines: HashSet<usize>,
     empty_columns: HashSet<usize>,
@@ -50,10 +65,10 @@ impl Universe {
             expansion,
         }
     }
-    fn from_file(filename: &str, expansion: usize) -> Universe {
-        let path = format!("input/{}", filename);
-        let lines = aoc_utils::read_lines(&path);
-        Universe::from_lines(lines, expansion)
+    #[cfg(test)]
+    fn from_file(filename: &str, expansion: usize) -> aoc_utils::Result<Universe> {
+        let lines = aoc_utils::input::read_lines(filename)?;
+        Ok(Universe::from_lines(lines, expansion))
     }
 
     fn distance(&self, a: &Position, b: &Position) -> usize {
@@ -108,7 +123,9 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "10422930 699909023130");
+        let result = aoc_utils::solution::execute::<Day11>().unwrap();
+        assert_eq!(result.part1, 10422930);
+        assert_eq!(result.part2, 699909023130);
     }
 
     #[test]
@@ -161,7 +178,7 @@ mod tests {
 
     #[test]
     fn test_parse_file() {
-        let universe = Universe::from_file("day11-test_input.txt", 2);
+        let universe = Universe::from_file("day11-test_input.txt", 2).unwrap();
         assert_eq!(9, universe.galaxies.len());
         assert!(universe.galaxies.contains(&Position(4, 1)));
         assert!(universe.galaxies.contains(&Position(8, 2)));
@@ -176,7 +193,7 @@ mod tests {
 
     #[test]
     fn test_distance() {
-        let universe = Universe::from_file("day11-test_input.txt", 2);
+        let universe = Universe::from_file("day11-test_input.txt", 2).unwrap();
         assert_eq!(15, universe.distance(&Position(4, 1), &Position(8, 9)));
         assert_eq!(17, universe.distance(&Position(1, 3), &Position(10, 7)));
         assert_eq!(5, universe.distance(&Position(1, 10), &Position(5, 10)));
@@ -185,7 +202,7 @@ mod tests {
 
     #[test]
     fn test_galaxy_pairs() {
-        let universe = Universe::from_file("day11-test_input.txt", 2);
+        let universe = Universe::from_file("day11-test_input.txt", 2).unwrap();
         let pairs_vec = universe.galaxy_pairs();
         let pairs_set: HashSet<(&Position, &Position)> =
             HashSet::from_iter(pairs_vec.iter().cloned());
@@ -203,15 +220,15 @@ mod tests {
 
     #[test]
     fn test_sum_shortest_distance() {
-        let universe = Universe::from_file("day11-test_input.txt", 2);
+        let universe = Universe::from_file("day11-test_input.txt", 2).unwrap();
         assert_eq!(374, universe.sum_shortest_distance());
     }
 
     #[test]
     fn test_sum_shortest_distance_with_expansion() {
-        let universe_expand_10 = Universe::from_file("day11-test_input.txt", 10);
+        let universe_expand_10 = Universe::from_file("day11-test_input.txt", 10).unwrap();
         assert_eq!(1030, universe_expand_10.sum_shortest_distance());
-        let universe_expand_100 = Universe::from_file("day11-test_input.txt", 100);
+        let universe_expand_100 = Universe::from_file("day11-test_input.txt", 100).unwrap();
         assert_eq!(8410, universe_expand_100.sum_shortest_distance());
     }
 }
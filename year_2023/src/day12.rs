@@ -1,20 +1,31 @@
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::collections::HashMap;
 
-pub fn execute() -> String {
-    let mine = aoc_utils::read_lines("input/day12.txt");
-
-    let part1 = mine
-        .iter()
-        .map(|line| SpringRow::from_line(line.clone()))
-        .map(|row| row.count_valid_arrangements())
-        .sum::<usize>();
-    let part2 = mine
-        .iter()
-        .map(|line| SpringRow::from_line(line.clone()).unfold(5))
-        .map(|row| row.count_valid_arrangements())
-        .sum::<usize>();
-
-    format!("{} {}", part1, part2)
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Vec<SpringRow>;
+
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        aoc_utils::parse::lines(lines, |line| SpringRow::from_line(line.to_string()))
+    }
+
+    fn part1(rows: &Self::Input) -> Answer {
+        rows.iter()
+            .map(|row| row.count_valid_arrangements())
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(rows: &Self::Input) -> Answer {
+        rows.iter()
+            .map(|row| row.unfold(5))
+            .map(|row| row.count_valid_arrangements())
+            .sum::<usize>()
+            .into()
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -23,33 +34,39 @@ enum Condition {
     Operational,
 }
 
-fn parse_condition(condition: char) -> Option<Condition> {
+fn parse_condition(condition: char) -> aoc_utils::Result<Option<Condition>> {
     match condition {
-        '#' => Some(Condition::Damaged),
-        '.' => Some(Condition::Operational),
-        '?' => None,
-        _ => panic!("invalid condition"),
+        '#' => Ok(Some(Condition::Damaged)),
+        '.' => Ok(Some(Condition::Operational)),
+        '?' => Ok(None),
+        _ => Err(aoc_utils::Error::parse(
+            &condition.to_string(),
+            "invalid condition",
+        )),
     }
 }
 
 #[derive(Clone, Debug)]
-struct SpringRow {
+pub struct SpringRow {
     condition: Vec<Option<Condition>>,
     checksum: Vec<usize>,
 }
 
 impl SpringRow {
-    fn from_line(line: String) -> SpringRow {
-        let (condition_text, checksum_text) = line.split_once(' ').unwrap();
-        let condition = condition_text.chars().map(parse_condition).collect();
+    fn from_line(line: String) -> aoc_utils::Result<SpringRow> {
+        let (condition_text, checksum_text) = aoc_utils::parse::split_once_or_err(&line, " ")?;
+        let condition = condition_text
+            .chars()
+            .map(parse_condition)
+            .collect::<aoc_utils::Result<_>>()?;
         let checksum = checksum_text
             .split(',')
-            .map(|n| -> usize { n.parse::<usize>().unwrap() })
-            .collect();
-        SpringRow {
+            .map(aoc_utils::parse::value::<usize>)
+            .collect::<aoc_utils::Result<_>>()?;
+        Ok(SpringRow {
             condition,
             checksum,
-        }
+        })
     }
 
     fn count_valid_arrangements(&self) -> usize {
@@ -177,11 +194,13 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "7204 1672318386674");
+        let result = aoc_utils::solution::execute::<Day12>().unwrap();
+        assert_eq!(result.part1, 7204);
+        assert_eq!(result.part2, 1672318386674);
     }
     #[test]
     fn test_parse_row() {
-        let row1 = SpringRow::from_line("???.### 1,1,3".to_string());
+        let row1 = SpringRow::from_line("???.### 1,1,3".to_string()).unwrap();
         assert_eq!(7, row1.condition.len());
         assert!(matches!(row1.condition[0], None));
         assert!(matches!(row1.condition[1], None));
@@ -202,10 +221,14 @@ mod tests {
     }
 
     fn check_consistent_line(line: &str) {
-        assert!(SpringRow::from_line(line.to_string()).is_consistent());
+        assert!(SpringRow::from_line(line.to_string())
+            .unwrap()
+            .is_consistent());
     }
     fn check_inconsistent_line(line: &str) {
-        assert!(!SpringRow::from_line(line.to_string()).is_consistent());
+        assert!(!SpringRow::from_line(line.to_string())
+            .unwrap()
+            .is_consistent());
     }
 
     #[test]
@@ -250,24 +273,26 @@ mod tests {
 
     #[test]
     fn test_example_all_consistent() {
-        let example = aoc_utils::read_lines("input/day12-example.txt");
+        let example = aoc_utils::read_lines("input/day12-example.txt").unwrap();
         assert!(example
             .iter()
-            .map(|line| SpringRow::from_line(line.to_string()))
+            .map(|line| SpringRow::from_line(line.to_string()).unwrap())
             .all(|row| row.is_consistent()));
     }
 
     #[test]
     fn test_mine_all_consistent() {
-        let mine = aoc_utils::read_lines("input/day12.txt");
+        let mine = aoc_utils::read_lines("input/day12.txt").unwrap();
         assert!(mine
             .iter()
-            .map(|line| SpringRow::from_line(line.to_string()))
+            .map(|line| SpringRow::from_line(line.to_string()).unwrap())
             .all(|row| row.is_consistent()));
     }
 
     fn test_valid_arangements(line: &str) -> usize {
-        SpringRow::from_line(line.to_string()).count_valid_arrangements()
+        SpringRow::from_line(line.to_string())
+            .unwrap()
+            .count_valid_arrangements()
     }
 
     #[test]
@@ -283,12 +308,12 @@ mod tests {
 
     #[test]
     fn test_example_valid_arrangements() {
-        let example = aoc_utils::read_lines("input/day12-example.txt");
+        let example = aoc_utils::read_lines("input/day12-example.txt").unwrap();
         assert_eq!(
             21,
             example
                 .iter()
-                .map(|line| SpringRow::from_line(line.clone()))
+                .map(|line| SpringRow::from_line(line.clone()).unwrap())
                 .map(|row| row.count_valid_arrangements())
                 .sum::<usize>()
         );
@@ -296,23 +321,25 @@ mod tests {
 
     #[test]
     fn test_unfold() {
-        let row1 = SpringRow::from_line("# 1".to_string()).unfold(5);
+        let row1 = SpringRow::from_line("# 1".to_string()).unwrap().unfold(5);
         assert_eq!(9, row1.condition.len());
         assert_eq!(5, row1.checksum.len());
 
-        let row2 = SpringRow::from_line("#?# 1,1".to_string()).unfold(5);
+        let row2 = SpringRow::from_line("#?# 1,1".to_string())
+            .unwrap()
+            .unfold(5);
         assert_eq!(19, row2.condition.len());
         assert_eq!(10, row2.checksum.len());
     }
 
     #[test]
     fn test_example_unfolded_valid_arrangements() {
-        let example = aoc_utils::read_lines("input/day12-example.txt");
+        let example = aoc_utils::read_lines("input/day12-example.txt").unwrap();
         assert_eq!(
             525152,
             example
                 .iter()
-                .map(|line| SpringRow::from_line(line.clone()).unfold(5))
+                .map(|line| SpringRow::from_line(line.clone()).unwrap().unfold(5))
                 .map(|row| row.count_valid_arrangements())
                 .sum::<usize>()
         );
@@ -1,25 +1,38 @@
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::fmt;
 
-pub fn execute() -> String {
-    let mut patterns: Vec<Pattern> = aoc_utils::read_lines("input/day13.txt")
-        .split(|line| line.len() == 0)
-        .map(|pattern_lines| Pattern::from_lines(pattern_lines.to_vec()))
-        .collect();
-
-    let part1 = patterns
-        .iter()
-        .map(|pattern| pattern.symmetry_score().unwrap())
-        .sum::<usize>();
-    let part2 = patterns
-        .iter_mut()
-        .map(|pattern| pattern.new_symmetry_score().unwrap())
-        .sum::<usize>();
-
-    format!("{} {}", part1, part2)
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<Pattern>;
+
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Ok(aoc_utils::parse::paragraphs(lines)
+            .map(|(_, pattern_lines)| Pattern::from_lines(pattern_lines.to_vec()))
+            .collect())
+    }
+
+    fn part1(patterns: &Self::Input) -> Answer {
+        patterns
+            .iter()
+            .map(|pattern| pattern.symmetry_score())
+            .sum::<Option<usize>>()
+            .map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(patterns: &Self::Input) -> Answer {
+        patterns
+            .iter()
+            .map(|pattern| pattern.clone().new_symmetry_score())
+            .sum::<Option<usize>>()
+            .map_or(Answer::Unsolved, Answer::from)
+    }
 }
 
 #[derive(Clone)]
-struct Pattern {
+pub struct Pattern {
     rows: Vec<Vec<bool>>,
 }
 
@@ -195,7 +208,9 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "28651 25450");
+        let result = aoc_utils::solution::execute::<Day13>().unwrap();
+        assert_eq!(result.part1, 28651);
+        assert_eq!(result.part2, 25450);
     }
 
     #[test]
@@ -1,91 +1,71 @@
-use std::collections::HashMap;
+use aoc_utils::cycle;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 
-pub fn execute() -> String {
-    let mut mine = Platform::from_lines(aoc_utils::read_lines("input/day14.txt"));
-    mine.slide_north();
-    let part1 = mine.load_north();
+pub struct Day14;
 
-    let mut mine_cycled = Platform::from_lines(aoc_utils::read_lines("input/day14.txt"));
-    mine_cycled.cycle_much(1000000000);
-    let part2 = mine_cycled.load_north();
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Platform;
 
-    format!("{} {}", part1, part2)
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Platform::from_lines(lines.to_vec())
+    }
+
+    fn part1(platform: &Self::Input) -> Answer {
+        let mut platform = platform.clone();
+        platform.slide_north();
+        platform.load_north().into()
+    }
+
+    fn part2(platform: &Self::Input) -> Answer {
+        let mut platform = platform.clone();
+        platform.cycle_much(1000000000);
+        platform.load_north().into()
+    }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 enum Shape {
     Sphere,
     Cube,
 }
 
-#[derive(Clone, Debug)]
-struct Platform {
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Platform {
     rows: Vec<Vec<Option<Shape>>>,
     width: usize,
 }
 impl Platform {
-    fn to_lines(&self) -> Vec<String> {
-        self.rows
-            .iter()
-            .map(|row| {
-                row.iter()
-                    .map(|shape| match shape {
-                        Some(Shape::Sphere) => 'O'.to_string(),
-                        Some(Shape::Cube) => '#'.to_string(),
-                        None => '.'.to_string(),
-                    })
-                    .collect::<Vec<_>>()
-                    .join("")
-            })
-            .collect()
-    }
-
-    fn from_lines(lines: Vec<String>) -> Platform {
-        let rows: Vec<Vec<Option<Shape>>> = lines
-            .iter()
-            .map(|line| {
-                line.chars()
-                    .map(|c| match c {
-                        'O' => Some(Shape::Sphere),
-                        '#' => Some(Shape::Cube),
-                        '.' => None,
-                        _ => unreachable!(),
-                    })
-                    .collect()
-            })
-            .collect();
-
-        let width = rows[0].len();
-        assert!(rows.iter().all(|row| row.len() == width));
-
-        Platform { rows, width }
+    fn from_lines(lines: Vec<String>) -> aoc_utils::Result<Platform> {
+        let width = lines.first().map_or(0, |line| line.len());
+        let rows = aoc_utils::parse::lines(&lines, |line| {
+            if line.len() != width {
+                return Err(aoc_utils::Error::parse(
+                    line,
+                    format!("expected {} tiles", width),
+                ));
+            }
+            line.chars()
+                .map(|c| match c {
+                    'O' => Ok(Some(Shape::Sphere)),
+                    '#' => Ok(Some(Shape::Cube)),
+                    '.' => Ok(None),
+                    _ => Err(aoc_utils::Error::parse(&c.to_string(), "unknown rock")),
+                })
+                .collect()
+        })?;
+
+        Ok(Platform { rows, width })
     }
 
     fn cycle_much(&mut self, loops: u32) {
-        let mut cache = HashMap::new();
-
-        let mut loops_remaining = loops;
-
-        let mut i: u32 = 0;
-        while i < 1000 {
-            let cache_key = self.to_lines().join("\n");
-            if cache.contains_key(&cache_key) {
-                let first_seen = cache.get(&cache_key).unwrap();
-                let loop_size = i - first_seen;
-                loops_remaining = loops_remaining % loop_size;
-                break;
-            }
-
-            self.cycle();
-            loops_remaining -= 1;
-
-            cache.insert(cache_key, i);
-            i += 1;
-        }
-
-        for _ in 0..loops_remaining {
-            self.cycle();
-        }
+        let step = |platform: &Platform| {
+            let mut next = platform.clone();
+            next.cycle();
+            next
+        };
+        *self = cycle::nth(self.clone(), step, loops as usize);
     }
 
     fn cycle(&mut self) {
@@ -212,6 +192,7 @@ fn _example() -> Platform {
         "#....###..".to_string(),
         "#OO..#....".to_string(),
     ])
+    .unwrap()
 }
 #[cfg(test)]
 mod tests {
@@ -219,12 +200,14 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "107430 96317");
+        let result = aoc_utils::solution::execute::<Day14>().unwrap();
+        assert_eq!(result.part1, 107430);
+        assert_eq!(result.part2, 96317);
     }
 
     #[test]
     fn test_from_lines() {
-        let empty = Platform::from_lines(vec!["".to_string()]);
+        let empty = Platform::from_lines(vec!["".to_string()]).unwrap();
         assert_eq!(1, empty.rows.len());
         assert_eq!(0, empty.rows[0].len());
 
@@ -233,6 +216,15 @@ mod tests {
         assert_eq!(10, example.rows[0].len());
     }
 
+    #[test]
+    fn test_parse_errors() {
+        let lines = |lines: &[&str]| lines.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let error = |lines| Platform::from_lines(lines).err().unwrap().to_string();
+
+        assert_eq!(error(lines(&["O#", "."])), "2: expected 2 tiles: `.`");
+        assert_eq!(error(lines(&["O#", ".x"])), "2: unknown rock: `x` in `.x`");
+    }
+
     #[test]
     fn test_slide_north() {
         let mut example = _example();
@@ -1,14 +1,39 @@
-use std::fs::read_to_string;
-pub fn execute() -> String {
-    let mine = read_sequence("input/day15.txt");
+use aoc_utils::parse;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 
-    let part1 = hash_sequence(&mine);
+pub struct Day15;
 
-    let mut lab = Lab::new();
-    mine.iter().for_each(|instruction| lab.execute(instruction));
-    let part2 = lab.score();
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    /// The steps of the initialization sequence, and the instruction of each.
+    type Input = (Vec<String>, Vec<Instruction>);
 
-    format!("{} {}", part1, part2)
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        let steps = lines
+            .iter()
+            .flat_map(|line| line.split(','))
+            .map(String::from)
+            .collect();
+        let instructions = parse::lines(lines, |line| {
+            line.split(',')
+                .map(Instruction::from_text)
+                .collect::<aoc_utils::Result<Vec<_>>>()
+        })?;
+        Ok((steps, instructions.into_iter().flatten().collect()))
+    }
+
+    fn part1((steps, _): &Self::Input) -> Answer {
+        hash_sequence(steps).into()
+    }
+
+    fn part2((_, instructions): &Self::Input) -> Answer {
+        let mut lab = Lab::new();
+        instructions
+            .iter()
+            .for_each(|instruction| lab.execute(instruction));
+        lab.score().into()
+    }
 }
 
 fn hash_sequence(sequence: &Vec<String>) -> u32 {
@@ -25,16 +50,8 @@ fn hash_step(step: &String) -> u8 {
     }
     result
 }
-fn read_sequence(filename: &str) -> Vec<String> {
-    read_to_string(filename)
-        .unwrap()
-        .split(',')
-        .map(|s| s.to_string())
-        .collect()
-}
-
 #[derive(Clone)]
-struct LabelledLens {
+pub struct LabelledLens {
     label: String,
     power: u8,
 }
@@ -47,6 +64,24 @@ impl LabelledLens {
     }
 }
 
+pub enum Instruction {
+    Remove(String),
+    Add(LabelledLens),
+}
+
+impl Instruction {
+    fn from_text(text: &str) -> aoc_utils::Result<Instruction> {
+        if let Some(label) = text.strip_suffix('-') {
+            return Ok(Instruction::Remove(label.to_string()));
+        }
+        let (label, power) = parse::split_once_or_err(text, "=")?;
+        Ok(Instruction::Add(LabelledLens::new(
+            label,
+            parse::value(power)?,
+        )))
+    }
+}
+
 #[derive(Clone)]
 struct LensBox {
     lenses: Vec<LabelledLens>,
@@ -96,14 +131,10 @@ impl Lab {
         }
     }
 
-    fn execute(&mut self, instruction: &String) {
-        if instruction.ends_with("-") {
-            self.remove(instruction.strip_suffix("-").unwrap().to_string());
-        } else {
-            let mut parts = instruction.split("=");
-            let label = parts.next().unwrap();
-            let power = parts.next().unwrap().to_string().parse::<u8>().unwrap();
-            self.add(LabelledLens::new(label, power));
+    fn execute(&mut self, instruction: &Instruction) {
+        match instruction {
+            Instruction::Remove(label) => self.remove(label.clone()),
+            Instruction::Add(lens) => self.add(lens.clone()),
         }
     }
 
@@ -132,7 +163,9 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "505459 228508");
+        let result = aoc_utils::solution::execute::<Day15>().unwrap();
+        assert_eq!(result.part1, 505459);
+        assert_eq!(result.part2, 228508);
     }
 
     #[test]
@@ -256,33 +289,33 @@ mod tests {
     fn test_execute() {
         let mut lab = Lab::new();
 
-        lab.execute(&"rn=1".to_string());
+        lab.execute(&Instruction::from_text("rn=1").unwrap());
         assert_eq!(1, lab.boxes[0].lenses.len());
         for i in 1..256 {
             assert_eq!(0, lab.boxes[i].lenses.len());
         }
 
-        lab.execute(&"cm-".to_string());
+        lab.execute(&Instruction::from_text("cm-").unwrap());
         assert_eq!(1, lab.boxes[0].lenses.len());
         for i in 1..256 {
             assert_eq!(0, lab.boxes[i].lenses.len());
         }
 
-        lab.execute(&"qp=3".to_string());
+        lab.execute(&Instruction::from_text("qp=3").unwrap());
         assert_eq!(1, lab.boxes[0].lenses.len());
         assert_eq!(1, lab.boxes[1].lenses.len());
         for i in 2..256 {
             assert_eq!(0, lab.boxes[i].lenses.len());
         }
 
-        lab.execute(&"cm=2".to_string());
+        lab.execute(&Instruction::from_text("cm=2").unwrap());
         assert_eq!(2, lab.boxes[0].lenses.len());
         assert_eq!(1, lab.boxes[1].lenses.len());
         for i in 2..256 {
             assert_eq!(0, lab.boxes[i].lenses.len());
         }
 
-        lab.execute(&"qp-".to_string());
+        lab.execute(&Instruction::from_text("qp-").unwrap());
         assert_eq!(2, lab.boxes[0].lenses.len());
         for i in 1..256 {
             assert_eq!(0, lab.boxes[i].lenses.len());
@@ -293,18 +326,29 @@ mod tests {
     fn test_example() {
         let mut lab = Lab::new();
 
-        lab.execute(&"rn=1".to_string());
-        lab.execute(&"cm-".to_string());
-        lab.execute(&"qp=3".to_string());
-        lab.execute(&"cm=2".to_string());
-        lab.execute(&"qp-".to_string());
-        lab.execute(&"pc=4".to_string());
-        lab.execute(&"ot=9".to_string());
-        lab.execute(&"ab=5".to_string());
-        lab.execute(&"pc-".to_string());
-        lab.execute(&"pc=6".to_string());
-        lab.execute(&"ot=7".to_string());
+        lab.execute(&Instruction::from_text("rn=1").unwrap());
+        lab.execute(&Instruction::from_text("cm-").unwrap());
+        lab.execute(&Instruction::from_text("qp=3").unwrap());
+        lab.execute(&Instruction::from_text("cm=2").unwrap());
+        lab.execute(&Instruction::from_text("qp-").unwrap());
+        lab.execute(&Instruction::from_text("pc=4").unwrap());
+        lab.execute(&Instruction::from_text("ot=9").unwrap());
+        lab.execute(&Instruction::from_text("ab=5").unwrap());
+        lab.execute(&Instruction::from_text("pc-").unwrap());
+        lab.execute(&Instruction::from_text("pc=6").unwrap());
+        lab.execute(&Instruction::from_text("ot=7").unwrap());
 
         assert_eq!(145, lab.score());
     }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |line: &str| Day15::parse(&[line.to_string()]).err().unwrap().to_string();
+
+        assert_eq!(error("rn=1,cm"), "1: missing `=`: `cm` in `rn=1,cm`");
+        assert_eq!(
+            error("rn=1,cm=x"),
+            "1: invalid digit found in string: `x` in `rn=1,cm=x`"
+        );
+    }
 }
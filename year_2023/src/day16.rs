@@ -1,19 +1,31 @@
+use aoc_utils::parallel;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::collections::HashSet;
 
 #[test]
 fn test_mine() {
-    execute();
+    aoc_utils::solution::execute::<Day16>().unwrap();
 }
 
-pub fn execute() -> String {
-    let mine = Contraption::from_lines(aoc_utils::read_lines("input/day16.txt"));
+pub struct Day16;
 
-    let part1 = mine.energized_count();
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input = Contraption;
 
-    let (_optimal_laser, optimal_count) = mine.optimize_energizing();
-    let part2 = optimal_count;
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Contraption::from_lines(lines.to_vec())
+    }
+
+    fn part1(contraption: &Self::Input) -> Answer {
+        contraption.energized_count().into()
+    }
 
-    format!("{} {}", part1, part2)
+    fn part2(contraption: &Self::Input) -> Answer {
+        let (_optimal_laser, optimal_count) = contraption.optimize_energizing();
+        optimal_count.into()
+    }
 }
 
 enum Apparatus {
@@ -25,15 +37,15 @@ enum Apparatus {
 }
 
 impl Apparatus {
-    fn from_char(c: char) -> Apparatus {
+    fn from_char(c: char) -> aoc_utils::Result<Apparatus> {
         use Apparatus::*;
         match c {
-            '.' => None,
-            '-' => SplitterHorizontal,
-            '|' => SplitterVertical,
-            '\\' => MirrorTopLeftBottomRight,
-            '/' => MirrorTopRightBottomLeft,
-            _ => unreachable!(),
+            '.' => Ok(None),
+            '-' => Ok(SplitterHorizontal),
+            '|' => Ok(SplitterVertical),
+            '\\' => Ok(MirrorTopLeftBottomRight),
+            '/' => Ok(MirrorTopRightBottomLeft),
+            _ => Err(aoc_utils::Error::parse(&c.to_string(), "unknown apparatus")),
         }
     }
 }
@@ -47,19 +59,26 @@ enum Direction {
 
 type Laser = (usize, usize, Direction);
 
-struct Contraption {
+pub struct Contraption {
     tiles: Vec<Vec<Apparatus>>,
 }
 
 impl Contraption {
-    fn from_lines(lines: Vec<String>) -> Contraption {
-        let tiles = lines
-            .iter()
-            .map(|line| line.chars().map(Apparatus::from_char).collect())
-            .collect();
-        let mut lasers = HashSet::new();
-        lasers.insert((0, 0, Direction::Right));
-        Contraption { tiles }
+    fn from_lines(lines: Vec<String>) -> aoc_utils::Result<Contraption> {
+        let width = match lines.first() {
+            Some(line) if !line.is_empty() => line.len(),
+            _ => return Err(aoc_utils::Error::parse("", "empty contraption")),
+        };
+        let tiles = aoc_utils::parse::lines(&lines, |line| {
+            if line.len() != width {
+                return Err(aoc_utils::Error::parse(
+                    line,
+                    format!("expected {} tiles", width),
+                ));
+            }
+            line.chars().map(Apparatus::from_char).collect()
+        })?;
+        Ok(Contraption { tiles })
     }
 
     fn optimize_energizing(&self) -> (Laser, usize) {
@@ -73,16 +92,18 @@ impl Contraption {
         let right: Vec<Laser> = (0..height).map(|i| (width - 1, i, Left)).collect();
 
         let all = top
-            .iter()
-            .chain(bottom.iter())
-            .chain(left.iter())
-            .chain(right.iter());
+            .into_iter()
+            .chain(bottom)
+            .chain(left)
+            .chain(right)
+            .collect();
 
-        all.map(|laser| {
+        parallel::map(all, |laser| {
             let lasers = HashSet::from([laser.clone()]);
             let result = self.resolve(lasers);
-            (laser.clone(), result)
+            (laser, result)
         })
+        .into_iter()
         .max_by_key(|(_laser, count)| *count)
         .unwrap()
     }
@@ -168,7 +189,9 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "7111 7831");
+        let result = aoc_utils::solution::execute::<Day16>().unwrap();
+        assert_eq!(result.part1, 7111);
+        assert_eq!(result.part2, 7831);
     }
 
     #[test]
@@ -179,7 +202,8 @@ mod tests {
             r"..|.".to_string(),
             r"/-/.".to_string(),
             r"\.-.".to_string(),
-        ]);
+        ])
+        .unwrap();
 
         assert_eq!(contraption.progress(&(0, 0, Right)), vec![(1, 0, Right)]);
         assert_eq!(contraption.progress(&(1, 0, Right)), vec![(1, 1, Down)]);
@@ -214,9 +238,25 @@ mod tests {
             r".-.-/..|..".to_string(),
             r".|....-|.\".to_string(),
             r"..//.|....".to_string(),
-        ]);
+        ])
+        .unwrap();
         assert_eq!(46, example.energized_count());
 
         assert_eq!(((3, 0, Direction::Down), 51), example.optimize_energizing());
     }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Contraption::from_lines(vec![r".|.".to_string(), r"\x.".to_string()]);
+        assert_eq!(
+            error.err().unwrap().to_string(),
+            r"2: unknown apparatus: `x` in `\x.`"
+        );
+        let error = Contraption::from_lines(vec![r".|.".to_string(), r"\.".to_string()]);
+        assert_eq!(
+            error.err().unwrap().to_string(),
+            r"2: expected 3 tiles: `\.`"
+        );
+        assert!(Contraption::from_lines(vec![]).is_err());
+    }
 }
@@ -1,16 +1,27 @@
+use aoc_utils::parse;
+use aoc_utils::search;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::cmp::min;
 use std::collections::HashMap;
 
-pub fn execute() -> String {
-    let example_city = City::from_lines(aoc_utils::read_lines("input/day17.txt"));
+pub struct Day17;
 
-    let mut nav1 = Navigator::new(1, 3);
-    let part1 = nav1.solve(&example_city);
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input = City;
 
-    let mut nav2 = Navigator::new(4, 10);
-    let part2 = nav2.solve(&example_city);
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        City::from_lines(lines.to_vec())
+    }
+
+    fn part1(city: &Self::Input) -> Answer {
+        Navigator::new(1, 3).solve(city).into()
+    }
 
-    format!("{} {}", part1, part2)
+    fn part2(city: &Self::Input) -> Answer {
+        Navigator::new(4, 10).solve(city).into()
+    }
 }
 
 #[derive(Hash, Eq, PartialEq, Clone, Debug)]
@@ -19,7 +30,7 @@ struct Coord {
     y: u32,
 }
 
-struct City {
+pub struct City {
     heat_loss: HashMap<Coord, u8>,
     width: u32,
     height: u32,
@@ -27,43 +38,47 @@ struct City {
 }
 
 impl City {
-    fn from_lines(lines: Vec<String>) -> City {
-        let heat_loss = HashMap::from_iter(
-            lines
-                .iter()
-                .enumerate()
-                .map(|(y, line)| {
-                    line.chars()
-                        .enumerate()
-                        .map(|(x, c)| {
-                            (
-                                Coord {
-                                    x: x as u32,
-                                    y: y as u32,
-                                },
-                                c.to_string().parse::<u8>().unwrap(),
-                            )
-                        })
-                        .collect::<Vec<_>>()
+    fn from_lines(lines: Vec<String>) -> aoc_utils::Result<City> {
+        let width = lines.first().map_or(0, |line| line.len());
+        let rows = parse::lines(&lines, |line| {
+            if line.len() != width {
+                return Err(aoc_utils::Error::parse(
+                    line,
+                    format!("expected {} blocks", width),
+                ));
+            }
+            line.chars()
+                .map(|c| {
+                    c.to_digit(10)
+                        .map(|digit| digit as u8)
+                        .ok_or_else(|| aoc_utils::Error::parse(&c.to_string(), "not a digit"))
                 })
-                .fold(Vec::new(), |mut result, mut next| {
-                    result.append(&mut next);
-                    result
-                }),
-        );
+                .collect::<aoc_utils::Result<Vec<u8>>>()
+        })?;
+        let heat_loss = HashMap::from_iter(rows.into_iter().enumerate().flat_map(|(y, row)| {
+            row.into_iter().enumerate().map(move |(x, loss)| {
+                (
+                    Coord {
+                        x: x as u32,
+                        y: y as u32,
+                    },
+                    loss,
+                )
+            })
+        }));
 
         let factory = heat_loss
             .keys()
             .max_by_key(|coord| (coord.x, coord.y))
             .cloned()
-            .unwrap();
+            .ok_or_else(|| aoc_utils::Error::parse("", "empty city"))?;
 
-        City {
+        Ok(City {
             heat_loss,
             width: factory.x + 1,
             height: factory.y + 1,
             factory,
-        }
+        })
     }
 }
 
@@ -97,78 +112,33 @@ struct NavPoint {
 }
 
 struct Navigator {
-    nav_points: Vec<NavPoint>,
-    costs: HashMap<NavPoint, u32>,
     crucible_min_steps: u32,
     crucible_max_steps: u32,
 }
 
 impl Navigator {
     fn new(crucible_min_steps: u32, crucible_max_steps: u32) -> Navigator {
-        let mut nav_points = Vec::new();
-        let mut costs = HashMap::new();
-
-        use Direction::*;
-
-        let start_east = NavPoint {
-            coord: Coord { x: 0, y: 0 },
-            direction: Horizontal,
-        };
-        nav_points.push(start_east.clone());
-        costs.insert(start_east, 0);
-
-        let start_south = NavPoint {
-            coord: Coord { x: 0, y: 0 },
-            direction: Vertical,
-        };
-        nav_points.push(start_south.clone());
-        costs.insert(start_south, 0);
-
         Navigator {
-            nav_points,
-            costs,
             crucible_min_steps,
             crucible_max_steps,
         }
     }
 
-    fn solve(&mut self, city: &City) -> u32 {
-        while self.nav_points.len() > 0 {
-            self.progress_all(city);
-        }
+    /// The least heat loss from the top left block to the factory, the
+    /// crucible leaving it either east or south.
+    fn solve(&self, city: &City) -> u32 {
+        use Direction::*;
 
-        self.costs
-            .iter()
-            .filter_map(|(&ref nav, &cost)| {
-                if nav.coord == city.factory {
-                    Some(cost)
-                } else {
-                    None
-                }
-            })
-            .min()
-            .unwrap()
-    }
-    fn progress_all(&mut self, city: &City) {
-        let mut new_nav_points = vec![];
-        for point in self.nav_points.iter() {
-            let cost = self.costs[&point];
-            let new_points = self.progress(city, &point);
-            for (new_point, added_cost) in new_points {
-                let new_cost = cost + added_cost;
-                if self
-                    .costs
-                    .get(&new_point)
-                    .is_none_or(|&previous_cost| previous_cost > new_cost)
-                {
-                    self.costs.insert(new_point.clone(), new_cost);
-                    if new_point.coord != city.factory {
-                        new_nav_points.push(new_point);
-                    }
-                }
-            }
-        }
-        self.nav_points = new_nav_points;
+        let starts = [Horizontal, Vertical].map(|direction| NavPoint {
+            coord: Coord { x: 0, y: 0 },
+            direction,
+        });
+        let paths = search::dijkstra(
+            starts,
+            |point| self.progress(city, point),
+            |point| point.coord == city.factory,
+        );
+        paths.goal().map(|(_, cost)| cost).unwrap()
     }
 
     fn progress(&self, city: &City, point: &NavPoint) -> Vec<(NavPoint, u32)> {
@@ -235,12 +205,14 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "698 825");
+        let result = aoc_utils::solution::execute::<Day17>().unwrap();
+        assert_eq!(result.part1, 698);
+        assert_eq!(result.part2, 825);
     }
 
     #[test]
     fn test_from_lines() {
-        let example = City::from_lines(_example());
+        let example = City::from_lines(_example()).unwrap();
         assert_eq!(example.heat_loss[&Coord { x: 0, y: 0 }], 2);
         assert_eq!(example.heat_loss[&Coord { x: 1, y: 0 }], 4);
         assert_eq!(example.heat_loss[&Coord { x: 2, y: 0 }], 1);
@@ -256,7 +228,7 @@ mod tests {
     fn test_progress() {
         use Direction::*;
 
-        let example_city = City::from_lines(_example());
+        let example_city = City::from_lines(_example()).unwrap();
         let nav = Navigator::new(1, 3);
 
         let p1 = NavPoint {
@@ -297,39 +269,20 @@ mod tests {
     }
 
     #[test]
-    fn test_progress_all() {
-        let example_city = City::from_lines(_example());
-        let mut nav = Navigator::new(1, 3);
-
-        nav.progress_all(&example_city);
-
-        assert_eq!(nav.nav_points.len(), 6);
-        assert_eq!(
-            HashMap::from([
-                (Coord { x: 0, y: 1 }, 3),
-                (Coord { x: 0, y: 2 }, 6),
-                (Coord { x: 0, y: 3 }, 9),
-                (Coord { x: 1, y: 0 }, 4),
-                (Coord { x: 2, y: 0 }, 5),
-                (Coord { x: 3, y: 0 }, 8),
-            ]),
-            nav.nav_points
-                .iter()
-                .map(|point| (point.coord.clone(), *nav.costs.get(point).unwrap()))
-                .collect::<HashMap<_, _>>(),
-        );
+    fn test_solve() {
+        let example_city = City::from_lines(_example()).unwrap();
 
-        assert_eq!(nav.costs.len(), 8);
+        assert_eq!(102, Navigator::new(1, 3).solve(&example_city));
+        assert_eq!(94, Navigator::new(4, 10).solve(&example_city));
     }
 
     #[test]
-    fn test_solve() {
-        let example_city = City::from_lines(_example());
-
-        let mut nav1 = Navigator::new(1, 3);
-        assert_eq!(102, nav1.solve(&example_city));
+    fn test_parse_errors() {
+        let lines = |lines: &[&str]| lines.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let error = |lines| City::from_lines(lines).err().unwrap().to_string();
 
-        let mut nav2 = Navigator::new(4, 10);
-        assert_eq!(94, nav2.solve(&example_city));
+        assert_eq!(error(lines(&["12", "3"])), "2: expected 2 blocks: `3`");
+        assert_eq!(error(lines(&["12", "3x"])), "2: not a digit: `x` in `3x`");
+        assert_eq!(error(lines(&[])), "empty city: ``");
     }
 }
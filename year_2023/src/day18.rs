@@ -1,13 +1,29 @@
+use aoc_utils::parse;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::ops::Index;
 
-pub fn execute() -> String {
-    let canvas1 = Canvas::from_lines_part1(aoc_utils::read_lines("input/day18.txt"));
-    let part1 = canvas1.to_area();
+pub struct Day18;
 
-    let canvas2 = Canvas::from_lines_part2(aoc_utils::read_lines("input/day18.txt"));
-    let part2 = canvas2.to_area();
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    /// The canvas read as in part 1, and as in part 2.
+    type Input = (Canvas, Canvas);
 
-    format!("{} {}", part1, part2)
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Ok((
+            Canvas::from_lines_part1(lines.to_vec())?,
+            Canvas::from_lines_part2(lines.to_vec())?,
+        ))
+    }
+
+    fn part1((canvas, _): &Self::Input) -> Answer {
+        canvas.to_area().into()
+    }
+
+    fn part2((_, canvas): &Self::Input) -> Answer {
+        canvas.to_area().into()
+    }
 }
 
 #[derive(Eq, PartialEq, Debug)]
@@ -34,60 +50,62 @@ struct Line {
     end: Coord,
 }
 
-struct Canvas {
+pub struct Canvas {
     instructions: Vec<Instruction>,
 }
 
 impl Canvas {
-    fn from_lines_part1(lines: Vec<String>) -> Canvas {
-        let instructions = lines
-            .iter()
-            .map(|line| {
-                let (dir_str, rest) = line.split_once(" ").unwrap();
-                let (distance_str, _color_str) = rest.split_once(" ").unwrap();
-
-                let direction = match dir_str {
-                    "U" => Direction::Up,
-                    "D" => Direction::Down,
-                    "L" => Direction::Left,
-                    "R" => Direction::Right,
-                    _ => unreachable!(),
-                };
-                let distance = distance_str.parse::<i64>().unwrap();
-
-                Instruction {
-                    direction,
-                    distance,
-                }
+    fn from_lines_part1(lines: Vec<String>) -> aoc_utils::Result<Canvas> {
+        let instructions = parse::lines(&lines, |line| {
+            let (dir_str, rest) = parse::split_once_or_err(line, " ")?;
+            let (distance_str, _color_str) = parse::split_once_or_err(rest, " ")?;
+
+            let direction = match dir_str {
+                "U" => Direction::Up,
+                "D" => Direction::Down,
+                "L" => Direction::Left,
+                "R" => Direction::Right,
+                _ => return Err(aoc_utils::Error::parse(dir_str, "unknown direction")),
+            };
+            let distance = parse::value::<i64>(distance_str)?;
+
+            Ok(Instruction {
+                direction,
+                distance,
             })
-            .collect();
-        Canvas { instructions }
+        })?;
+        Ok(Canvas { instructions })
     }
 
-    fn from_lines_part2(lines: Vec<String>) -> Canvas {
-        let instructions = lines
-            .iter()
-            .map(|line| {
-                let (_, instruction_str) = line.rsplit_once(" ").unwrap();
-                let distance_str = &instruction_str[2..7];
-                let dir_str = &instruction_str[7..8];
-
-                let direction = match dir_str {
-                    "0" => Direction::Right,
-                    "1" => Direction::Down,
-                    "2" => Direction::Left,
-                    "3" => Direction::Up,
-                    _ => unreachable!(),
-                };
-                let distance = i64::from_str_radix(distance_str, 16).unwrap();
-
-                Instruction {
-                    direction,
-                    distance,
-                }
+    fn from_lines_part2(lines: Vec<String>) -> aoc_utils::Result<Canvas> {
+        let instructions = parse::lines(&lines, |line| {
+            let (_, instruction_str) = parse::split_once_or_err(line, " ")
+                .and_then(|(_, rest)| parse::split_once_or_err(rest, " "))?;
+            let (Some(distance_str), Some(dir_str)) =
+                (instruction_str.get(2..7), instruction_str.get(7..8))
+            else {
+                return Err(aoc_utils::Error::parse(
+                    instruction_str,
+                    "expected `(#xxxxxx)`",
+                ));
+            };
+
+            let direction = match dir_str {
+                "0" => Direction::Right,
+                "1" => Direction::Down,
+                "2" => Direction::Left,
+                "3" => Direction::Up,
+                _ => return Err(aoc_utils::Error::parse(dir_str, "unknown direction")),
+            };
+            let distance = i64::from_str_radix(distance_str, 16)
+                .map_err(|error| aoc_utils::Error::parse(distance_str, error))?;
+
+            Ok(Instruction {
+                direction,
+                distance,
             })
-            .collect();
-        Canvas { instructions }
+        })?;
+        Ok(Canvas { instructions })
     }
 
     fn to_lines(&self) -> Vec<Line> {
@@ -182,14 +200,16 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "62573 54662804037719");
+        let result = aoc_utils::solution::execute::<Day18>().unwrap();
+        assert_eq!(result.part1, 62573);
+        assert_eq!(result.part2, 54662804037719);
     }
 
     #[test]
     fn test_from_lines() {
         use Direction::*;
 
-        let example = Canvas::from_lines_part1(_example());
+        let example = Canvas::from_lines_part1(_example()).unwrap();
 
         assert_eq!(example.instructions.len(), 14);
 
@@ -206,7 +226,7 @@ mod tests {
 
     #[test]
     fn test_to_lines() {
-        let example = Canvas::from_lines_part1(_example());
+        let example = Canvas::from_lines_part1(_example()).unwrap();
         let lines = example.to_lines();
 
         assert_eq!(lines.len(), 14);
@@ -223,16 +243,16 @@ mod tests {
 
     #[test]
     fn test_to_area() {
-        let part1 = Canvas::from_lines_part1(_example());
+        let part1 = Canvas::from_lines_part1(_example()).unwrap();
         assert_eq!(62, part1.to_area());
 
-        let part2 = Canvas::from_lines_part2(_example());
+        let part2 = Canvas::from_lines_part2(_example()).unwrap();
         assert_eq!(952408144115, part2.to_area());
     }
 
     #[test]
     fn test_dominant_turns() {
-        let part1 = Canvas::from_lines_part1(_example());
+        let part1 = Canvas::from_lines_part1(_example()).unwrap();
 
         assert_eq!((Direction::Right, 4), part1.dominant_turns())
     }
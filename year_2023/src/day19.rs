@@ -1,41 +1,48 @@
+use aoc_utils::intervals::RangeSet;
+use aoc_utils::parse;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::collections::HashMap;
 use std::fmt::Debug;
 
-pub fn execute() -> String {
-    let mine = TriageCenter::from_lines(aoc_utils::read_lines("input/day19.txt"));
+pub struct Day19;
 
-    let part1 = mine.process();
-    let part2 = mine.count_combinations();
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    type Input = TriageCenter;
 
-    format!("{} {}", part1, part2)
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        TriageCenter::from_lines(lines.to_vec())
+    }
+
+    fn part1(center: &Self::Input) -> Answer {
+        center.process().into()
+    }
+
+    fn part2(center: &Self::Input) -> Answer {
+        center.count_combinations().into()
+    }
 }
 
-struct TriageCenter {
+pub struct TriageCenter {
     parts: Vec<Part>,
     workflows: HashMap<String, Workflow>,
 }
 impl TriageCenter {
-    fn from_lines(lines: Vec<String>) -> TriageCenter {
-        let mut workflow_strs = Vec::new();
-        let mut parts_strs = Vec::new();
-
-        let mut separator_found = false;
-        lines.iter().for_each(|line| {
-            if line.is_empty() {
-                separator_found = true;
-            } else if separator_found {
-                parts_strs.push(line);
-            } else {
-                workflow_strs.push(line)
-            }
-        });
-        let workflows = workflow_strs.into_iter().map(Workflow::from_line).collect();
-        let parts = parts_strs
+    fn from_lines(lines: Vec<String>) -> aoc_utils::Result<TriageCenter> {
+        let separator = lines
+            .iter()
+            .position(|line| line.is_empty())
+            .unwrap_or(lines.len());
+        let workflow_strs = &lines[..separator];
+        let parts_strs = lines.get(separator + 1..).unwrap_or(&[]);
+
+        let workflows = parse::lines(workflow_strs, Workflow::from_line)?
             .into_iter()
-            .map(|line| Part::from_line(line))
             .collect();
+        let parts = parse::lines_at(parts_strs, separator + 1, Part::from_line)?;
 
-        TriageCenter { workflows, parts }
+        Ok(TriageCenter { workflows, parts })
     }
 
     fn process(&self) -> i32 {
@@ -73,49 +80,26 @@ impl TriageCenter {
         all_routes
             .iter()
             .map(|route| {
-                let mut mins = HashMap::from([(X, 0), (M, 0), (A, 0), (S, 0)]);
-                let mut maxs = HashMap::from([(X, 4000), (M, 4000), (A, 4000), (S, 4000)]);
-
-                fn setmin(mins: &mut HashMap<Category, i64>, cat: &Category, val: i64) {
-                    if mins[&cat] < val {
-                        mins.insert(cat.clone(), val);
-                    }
-                }
-                fn setmax(maxs: &mut HashMap<Category, i64>, cat: &Category, val: i64) {
-                    if maxs[&cat] > val {
-                        maxs.insert(cat.clone(), val);
-                    }
-                }
+                let mut ratings =
+                    HashMap::from([X, M, A, S].map(|category| (category, RangeSet::from(1..4001))));
 
                 for branch in route {
-                    match branch {
-                        Branch::True(condition) => match condition.test {
-                            Test::MoreThan => {
-                                setmin(&mut mins, &condition.category, condition.threshold as i64)
-                            }
-                            Test::LessThan => setmax(
-                                &mut maxs,
-                                &condition.category,
-                                condition.threshold as i64 - 1,
-                            ),
-                        },
-                        Branch::False(condition) => match condition.test {
-                            Test::MoreThan => {
-                                setmax(&mut maxs, &condition.category, condition.threshold as i64)
-                            }
-                            Test::LessThan => setmin(
-                                &mut mins,
-                                &condition.category,
-                                condition.threshold as i64 - 1,
-                            ),
-                        },
-                    }
+                    let (condition, passed) = match branch {
+                        Branch::True(condition) => (condition, true),
+                        Branch::False(condition) => (condition, false),
+                    };
+                    let threshold = condition.threshold as i64;
+                    let allowed = match (&condition.test, passed) {
+                        (Test::MoreThan, true) => threshold + 1..4001,
+                        (Test::MoreThan, false) => 1..threshold + 1,
+                        (Test::LessThan, true) => 1..threshold,
+                        (Test::LessThan, false) => threshold..4001,
+                    };
+                    let values = ratings.get_mut(&condition.category).unwrap();
+                    *values = values.intersection(&RangeSet::from(allowed));
                 }
 
-                vec![X, M, A, S]
-                    .iter()
-                    .map(|cat| maxs[&cat] - mins[&cat])
-                    .product::<i64>()
+                ratings.values().map(RangeSet::len).product::<i64>()
             })
             .sum()
     }
@@ -170,23 +154,22 @@ struct Part {
     ratings: HashMap<Category, i32>,
 }
 impl Part {
-    fn from_line(line: &String) -> Part {
-        let ratings = line
-            .strip_prefix("{")
-            .unwrap()
-            .strip_suffix("}")
-            .unwrap()
+    fn from_line(line: &str) -> aoc_utils::Result<Part> {
+        let Some(ratings_str) = line.strip_prefix("{").and_then(|s| s.strip_suffix("}")) else {
+            return Err(aoc_utils::Error::parse(line, "expected `{...}`"));
+        };
+        let ratings = ratings_str
             .split(",")
             .map(|rating_str| {
-                let (category_str, value_str) = rating_str.split_once("=").unwrap();
-                (
-                    Category::from_string(category_str),
-                    value_str.parse::<i32>().unwrap(),
-                )
+                let (category_str, value_str) = parse::split_once_or_err(rating_str, "=")?;
+                Ok((
+                    Category::from_string(category_str)?,
+                    parse::value::<i32>(value_str)?,
+                ))
             })
-            .collect::<HashMap<Category, i32>>();
+            .collect::<aoc_utils::Result<HashMap<Category, i32>>>()?;
 
-        Part { ratings }
+        Ok(Part { ratings })
     }
 }
 
@@ -195,16 +178,17 @@ struct Workflow {
 }
 
 impl Workflow {
-    fn from_line(line: &String) -> (String, Workflow) {
-        let (name, rest) = line.split_once("{").unwrap();
-        let rules = rest
-            .strip_suffix("}")
-            .unwrap()
+    fn from_line(line: &str) -> aoc_utils::Result<(String, Workflow)> {
+        let (name, rest) = parse::split_once_or_err(line, "{")?;
+        let Some(rules_str) = rest.strip_suffix("}") else {
+            return Err(aoc_utils::Error::parse(rest, "missing `}`"));
+        };
+        let rules = rules_str
             .split(",")
             .map(Rule::from_string)
-            .collect();
+            .collect::<aoc_utils::Result<_>>()?;
 
-        (name.to_string(), Workflow { rules })
+        Ok((name.to_string(), Workflow { rules }))
     }
 
     fn decide(&self, part: &Part) -> Decision {
@@ -225,20 +209,19 @@ struct Rule {
     decision: Decision,
 }
 impl Rule {
-    fn from_string(s: &str) -> Rule {
-        if s.contains(":") {
-            let (condition_str, decision_str) = s.split_once(":").unwrap();
-            let condition = Some(Condition::from_string(condition_str));
+    fn from_string(s: &str) -> aoc_utils::Result<Rule> {
+        if let Some((condition_str, decision_str)) = s.split_once(":") {
+            let condition = Some(Condition::from_string(condition_str)?);
             let decision = Decision::from_string(decision_str);
-            Rule {
+            Ok(Rule {
                 condition,
                 decision,
-            }
+            })
         } else {
-            Rule {
+            Ok(Rule {
                 condition: None,
                 decision: Decision::from_string(s),
-            }
+            })
         }
     }
 
@@ -263,16 +246,21 @@ struct Condition {
     threshold: i32,
 }
 impl Condition {
-    fn from_string(s: &str) -> Condition {
-        let category = Category::from_string(&s[0..1]);
-        let test = Test::from_string(&s[1..2]);
-        let threshold = s[2..].parse::<i32>().unwrap();
-
-        Condition {
+    fn from_string(s: &str) -> aoc_utils::Result<Condition> {
+        let (Some(category_str), Some(test_str), Some(threshold_str)) =
+            (s.get(0..1), s.get(1..2), s.get(2..))
+        else {
+            return Err(aoc_utils::Error::parse(s, "invalid condition"));
+        };
+        let category = Category::from_string(category_str)?;
+        let test = Test::from_string(test_str)?;
+        let threshold = parse::value::<i32>(threshold_str)?;
+
+        Ok(Condition {
             category,
             test,
             threshold,
-        }
+        })
     }
 
     fn matches(&self, part: &Part) -> bool {
@@ -304,13 +292,13 @@ enum Category {
     S,
 }
 impl Category {
-    fn from_string(s: &str) -> Category {
+    fn from_string(s: &str) -> aoc_utils::Result<Category> {
         match s {
-            "x" => Category::X,
-            "m" => Category::M,
-            "a" => Category::A,
-            "s" => Category::S,
-            _ => unreachable!(),
+            "x" => Ok(Category::X),
+            "m" => Ok(Category::M),
+            "a" => Ok(Category::A),
+            "s" => Ok(Category::S),
+            _ => Err(aoc_utils::Error::parse(s, "unknown category")),
         }
     }
 
@@ -331,11 +319,11 @@ enum Test {
 }
 
 impl Test {
-    fn from_string(s: &str) -> Test {
+    fn from_string(s: &str) -> aoc_utils::Result<Test> {
         match s {
-            ">" => Test::MoreThan,
-            "<" => Test::LessThan,
-            _ => unreachable!(),
+            ">" => Ok(Test::MoreThan),
+            "<" => Ok(Test::LessThan),
+            _ => Err(aoc_utils::Error::parse(s, "unknown test")),
         }
     }
 
@@ -370,12 +358,14 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "376008 124078207789312");
+        let result = aoc_utils::solution::execute::<Day19>().unwrap();
+        assert_eq!(result.part1, 376008);
+        assert_eq!(result.part2, 124078207789312);
     }
 
     #[test]
     fn test_example() {
-        let example = TriageCenter::from_lines(_example());
+        let example = TriageCenter::from_lines(_example()).unwrap();
         assert_eq!(19114, example.process());
 
         assert_eq!(167409079868000, example.count_combinations());
@@ -383,7 +373,7 @@ mod tests {
 
     #[test]
     fn test_process_part() {
-        let example = TriageCenter::from_lines(_example());
+        let example = TriageCenter::from_lines(_example()).unwrap();
 
         assert!(matches!(
             example.process_part(&_test_part(787, 2655, 1222, 2876)),
@@ -409,7 +399,9 @@ mod tests {
 
     #[test]
     fn test_workflow_decide() {
-        let wf = Workflow::from_line(&"px{a<2006:qkq,m>2090:A,rfg}".to_string()).1;
+        let wf = Workflow::from_line(&"px{a<2006:qkq,m>2090:A,rfg}".to_string())
+            .unwrap()
+            .1;
 
         assert!(matches!(
             wf.decide(&_test_part(0, 0, 1000, 0)),
@@ -427,7 +419,7 @@ mod tests {
 
     #[test]
     fn test_rule_decide() {
-        let rule1 = Rule::from_string("x<10:qkq");
+        let rule1 = Rule::from_string("x<10:qkq").unwrap();
         assert!(matches!(rule1.decide(&_test_part(20, 0, 0, 0)), None));
         assert!(matches!(rule1.decide(&_test_part(10, 0, 0, 0)), None));
         assert!(matches!(
@@ -435,7 +427,7 @@ mod tests {
             Some(Decision::Redirect(next)) if next == "qkq",
         ));
 
-        let rule2 = Rule::from_string("m>10:A");
+        let rule2 = Rule::from_string("m>10:A").unwrap();
         assert!(matches!(rule2.decide(&_test_part(0, 2, 0, 0)), None));
         assert!(matches!(rule2.decide(&_test_part(0, 10, 0, 0)), None));
         assert!(matches!(
@@ -443,7 +435,7 @@ mod tests {
             Some(Decision::Accept)
         ));
 
-        let rule3 = Rule::from_string("rfg");
+        let rule3 = Rule::from_string("rfg").unwrap();
         assert!(matches!(
                 rule3.decide(&_test_part(0, 0, 0, 0)),
                 Some(Decision::Redirect(next)) if next == "rfg"));
@@ -451,7 +443,7 @@ mod tests {
                 rule3.decide(&_test_part(10,10, 10, 10)),
                 Some(Decision::Redirect(next)) if next == "rfg"));
 
-        let rule4 = Rule::from_string("R");
+        let rule4 = Rule::from_string("R").unwrap();
         assert!(matches!(
             rule4.decide(&_test_part(0, 0, 0, 0)),
             Some(Decision::Reject)
@@ -464,7 +456,7 @@ mod tests {
 
     #[test]
     fn test_condition_matches() {
-        let c1 = Condition::from_string("a<1234");
+        let c1 = Condition::from_string("a<1234").unwrap();
         for i in [-10, 0, 1233, 1234, 1235] {
             for j in [-10, 0, 1233, 1234, 1235] {
                 let part = _test_part(j, j, i, j);
@@ -472,7 +464,7 @@ mod tests {
             }
         }
 
-        let c2 = Condition::from_string("a>1234");
+        let c2 = Condition::from_string("a>1234").unwrap();
         for i in [-10, 0, 1233, 1234, 1235] {
             for j in [-10, 0, 1233, 1234, 1235] {
                 let part = _test_part(j, j, i, j);
@@ -482,7 +474,8 @@ mod tests {
 
         let part = _test_part(2, 3, 4, 5);
 
-        let condition = |ct, val| Condition::from_string(&format!("{ct}{val}").to_string());
+        let condition =
+            |ct, val| Condition::from_string(&format!("{ct}{val}").to_string()).unwrap();
 
         for i in -1..7 {
             assert_eq!(condition("x>", i).matches(&part), 2 > i);
@@ -510,7 +503,7 @@ mod tests {
 
     #[test]
     fn test_from_lines() {
-        let example = TriageCenter::from_lines(_example());
+        let example = TriageCenter::from_lines(_example()).unwrap();
 
         assert_eq!(11, example.workflows.len());
 
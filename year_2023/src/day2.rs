@@ -1,23 +1,35 @@
+use aoc_utils::parse;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::cmp::max;
 
-pub fn execute() -> String {
-    let input = aoc_utils::read_lines("input/day2.txt");
-    let bag_content = CubeHand {
-        red: 12,
-        green: 13,
-        blue: 14,
-    };
+pub struct Day2;
 
-    let part1 = sum_possibles(&bag_content, &input);
-    let part2 = sum_powers(&input);
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input = Vec<Game>;
 
-    format!("{} {}", part1, part2)
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        parse::lines(lines, Game::from_text)
+    }
+
+    fn part1(games: &Self::Input) -> Answer {
+        let bag_content = CubeHand {
+            red: 12,
+            green: 13,
+            blue: 14,
+        };
+        sum_possibles(&bag_content, games).into()
+    }
+
+    fn part2(games: &Self::Input) -> Answer {
+        sum_powers(games).into()
+    }
 }
 
-fn sum_possibles(bag: &CubeHand, games: &Vec<String>) -> u32 {
+fn sum_possibles(bag: &CubeHand, games: &Vec<Game>) -> u32 {
     let mut total: u32 = 0;
-    for line in games {
-        let game = Game::from_text(line.as_str());
+    for game in games {
         if game.is_possible(bag) {
             total += game.id;
         }
@@ -25,33 +37,33 @@ fn sum_possibles(bag: &CubeHand, games: &Vec<String>) -> u32 {
     return total;
 }
 
-fn sum_powers(games: &Vec<String>) -> u32 {
+fn sum_powers(games: &Vec<Game>) -> u32 {
     let mut total: u32 = 0;
-    for line in games {
-        let game = Game::from_text(line.as_str());
+    for game in games {
         total += game.min_hand().power();
     }
     return total;
 }
 
-struct Game {
+pub struct Game {
     id: u32,
     hands: Vec<CubeHand>,
 }
 
 impl Game {
-    fn from_text(text: &str) -> Game {
-        let (game_id, hands_text) = text.split_once(": ").unwrap();
-        assert!(game_id.starts_with("Game "));
-
-        let id = game_id[5..].parse::<u32>().unwrap();
+    fn from_text(text: &str) -> aoc_utils::Result<Game> {
+        let (game_id, hands_text) = parse::split_once_or_err(text, ": ")?;
+        let Some(id_text) = game_id.strip_prefix("Game ") else {
+            return Err(aoc_utils::Error::parse(game_id, "expected `Game <id>`"));
+        };
+        let id = parse::value::<u32>(id_text)?;
 
         let mut hands = Vec::new();
         for hand_text in hands_text.split("; ") {
-            hands.push(CubeHand::from_text(hand_text));
+            hands.push(CubeHand::from_text(hand_text)?);
         }
 
-        return Game { id, hands };
+        return Ok(Game { id, hands });
     }
 
     fn is_possible(&self, bag_content: &CubeHand) -> bool {
@@ -82,22 +94,21 @@ struct CubeHand {
 }
 
 impl CubeHand {
-    fn from_text(text: &str) -> CubeHand {
+    fn from_text(text: &str) -> aoc_utils::Result<CubeHand> {
         let mut red: u32 = 0;
         let mut green: u32 = 0;
         let mut blue: u32 = 0;
 
         let colors = text.split(", ");
         for color in colors {
-            let parsed = color.split_once(" ");
-            match parsed.unwrap() {
-                (n, "red") => red = n.parse::<u32>().unwrap(),
-                (n, "green") => green = n.parse::<u32>().unwrap(),
-                (n, "blue") => blue = n.parse::<u32>().unwrap(),
-                _ => println!("Couldn't parse hand '{}'", color),
+            match parse::split_once_or_err(color, " ")? {
+                (n, "red") => red = parse::value(n)?,
+                (n, "green") => green = parse::value(n)?,
+                (n, "blue") => blue = parse::value(n)?,
+                _ => return Err(aoc_utils::Error::parse(color, "unknown color")),
             }
         }
-        return CubeHand { red, green, blue };
+        return Ok(CubeHand { red, green, blue });
     }
 
     fn contains(&self, other: &CubeHand) -> bool {
@@ -115,7 +126,9 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "2317 74804");
+        let result = aoc_utils::solution::execute::<Day2>().unwrap();
+        assert_eq!(result.part1, 2317);
+        assert_eq!(result.part2, 74804);
     }
 
     #[test]
@@ -126,13 +139,13 @@ mod tests {
             blue: 14,
         };
 
-        let example = aoc_utils::read_lines("input/day2-example.txt");
+        let example = aoc_utils::input::parse_lines("day2-example.txt", Game::from_text).unwrap();
         assert_eq!(8, sum_possibles(&bag_content, &example));
     }
 
     #[test]
     fn test_sum_powers() {
-        let example = aoc_utils::read_lines("input/day2-example.txt");
+        let example = aoc_utils::input::parse_lines("day2-example.txt", Game::from_text).unwrap();
         assert_eq!(2286, sum_powers(&example));
     }
 }
@@ -1,20 +1,38 @@
+use aoc_utils::number_theory::lcm_all;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
+use std::cell::RefCell;
 use std::collections::{HashMap, VecDeque};
 
-pub fn execute() -> String {
-    let mut mine = Desert::from_lines(aoc_utils::read_lines("input/day20.txt"));
-    let (mut seen_low, mut seen_high) = mine.button_press(&mut HashMap::new());
-    for _ in 1..1000 {
-        (seen_low, seen_high) = mine.button_press(&mut HashMap::new());
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    /// A desert for each part, as pressing the button changes its state.
+    type Input = (RefCell<Desert>, RefCell<Desert>);
+
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Ok((
+            RefCell::new(Desert::from_lines(lines.to_vec())?),
+            RefCell::new(Desert::from_lines(lines.to_vec())?),
+        ))
     }
-    let part1 = seen_low * seen_high;
 
-    let mut desert = Desert::from_lines(aoc_utils::read_lines("input/day20.txt"));
-    let part2 = desert.find_min_button_presses_for_rx();
+    fn part1((desert, _): &Self::Input) -> Answer {
+        let mut desert = desert.borrow_mut();
+        let (mut seen_low, mut seen_high) = desert.button_press(&mut HashMap::new());
+        for _ in 1..1000 {
+            (seen_low, seen_high) = desert.button_press(&mut HashMap::new());
+        }
+        (seen_low * seen_high).into()
+    }
 
-    format!("{} {}", part1, part2)
+    fn part2((_, desert): &Self::Input) -> Answer {
+        desert.borrow_mut().find_min_button_presses_for_rx().into()
+    }
 }
 
-struct Desert {
+pub struct Desert {
     modules: Vec<Box<dyn Module>>,
     pulses: VecDeque<Pulse>,
     pulses_seen_low: u32,
@@ -28,12 +46,12 @@ struct Pulse {
 }
 
 impl Desert {
-    fn from_lines(lines: Vec<String>) -> Desert {
+    fn from_lines(lines: Vec<String>) -> aoc_utils::Result<Desert> {
         let mut inputs: HashMap<String, Vec<String>> = HashMap::new();
-        let mut modules: Vec<Box<dyn Module>> = lines
-            .iter()
-            .map(|line| -> Box<dyn Module> {
-                let (module_str, destinations_str) = line.split_once(" -> ").unwrap();
+        let mut modules: Vec<Box<dyn Module>> =
+            aoc_utils::parse::lines(&lines, |line| -> aoc_utils::Result<Box<dyn Module>> {
+                let (module_str, destinations_str) =
+                    aoc_utils::parse::split_once_or_err(line, " -> ")?;
                 let destinations = destinations_str
                     .split(", ")
                     .map(String::from)
@@ -53,13 +71,12 @@ impl Desert {
                         .push(name.clone());
                 }
 
-                match type_str {
+                Ok(match type_str {
                     "%" => Box::new(FlipFlop::new(name.clone(), destinations)),
                     "&" => Box::new(Conjunction::new(name.clone(), destinations)),
                     _ => Box::new(Broadcast::new(name.clone(), destinations)),
-                }
-            })
-            .collect();
+                })
+            })?;
 
         let empty: Vec<String> = vec![];
         for module in modules.iter_mut() {
@@ -67,12 +84,12 @@ impl Desert {
             module.reset_inputs(inputs.get(name).unwrap_or(&empty).clone());
         }
 
-        Desert {
+        Ok(Desert {
             modules,
             pulses: VecDeque::new(),
             pulses_seen_low: 0,
             pulses_seen_high: 0,
-        }
+        })
     }
 
     fn get_module_mut(&mut self, name: &String) -> Option<&mut Box<dyn Module>> {
@@ -152,29 +169,7 @@ impl Desert {
             }
         }
 
-        let prime_factors = first_high_pulse
-            .values()
-            .map(|v| aoc_utils::prime_factors(&(*v as u32)))
-            .collect::<Vec<_>>();
-        let mut common_factors = vec![];
-        for factor in prime_factors[0].keys() {
-            if let Some(min_common) = prime_factors
-                .iter()
-                .map(|factors| factors.get(factor).unwrap_or(&0))
-                .min()
-            {
-                common_factors.push((factor, *min_common));
-            }
-        }
-        let divider = common_factors
-            .iter()
-            .filter_map(|(&factor, repeats)| (*repeats > 0).then_some(factor * repeats))
-            .product::<u32>();
-        let cycles = first_high_pulse
-            .values()
-            .map(|cycle| cycle / (divider as usize));
-
-        cycles.product()
+        lcm_all(first_high_pulse.values().copied())
     }
 }
 
@@ -305,7 +300,9 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "861743850 247023644760071");
+        let result = aoc_utils::solution::execute::<Day20>().unwrap();
+        assert_eq!(result.part1, 861743850);
+        assert_eq!(result.part2, 247023644760071);
     }
 
     #[test]
@@ -316,7 +313,8 @@ mod tests {
             "%b -> c".to_string(),
             "%c -> inv".to_string(),
             "&inv -> a".to_string(),
-        ]);
+        ])
+        .unwrap();
 
         assert_eq!(5, example1.modules.len());
         assert_eq!(
@@ -357,7 +355,8 @@ mod tests {
             "%b -> c".to_string(),
             "%c -> inv".to_string(),
             "&inv -> a".to_string(),
-        ]);
+        ])
+        .unwrap();
 
         let (mut seen_low, mut seen_high) = example1.button_press(&mut HashMap::new());
         assert_eq!(8, seen_low);
@@ -375,7 +374,8 @@ mod tests {
             "&inv -> b".to_string(),
             "%b -> con".to_string(),
             "&con -> output".to_string(),
-        ]);
+        ])
+        .unwrap();
         let (mut seen_low, mut seen_high) = example2.button_press(&mut HashMap::new());
         for _ in 1..1000 {
             (seen_low, seen_high) = example2.button_press(&mut HashMap::new());
@@ -1,15 +1,28 @@
+use aoc_utils::search;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::collections::{HashMap, HashSet};
 
-pub fn execute() -> String {
-    let garden = GardenPatch::from_lines(aoc_utils::read_lines("input/day21.txt"));
+pub struct Day21;
 
-    let part1 = garden.count_part_1(64);
-    let part2 = garden.count_part_2(26501365);
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    type Input = GardenPatch;
 
-    format!("{} {}", part1, part2)
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        GardenPatch::from_lines(lines.to_vec())
+    }
+
+    fn part1(garden: &Self::Input) -> Answer {
+        garden.count_part_1(64).into()
+    }
+
+    fn part2(garden: &Self::Input) -> Answer {
+        garden.count_part_2(26501365).into()
+    }
 }
 
-struct GardenPatch {
+pub struct GardenPatch {
     plots: HashSet<Coordinates>,
     start: Coordinates,
     side: i64,
@@ -19,15 +32,17 @@ struct GardenPatch {
 struct Coordinates(i64, i64);
 
 impl GardenPatch {
-    fn from_lines(lines: Vec<String>) -> GardenPatch {
+    fn from_lines(lines: Vec<String>) -> aoc_utils::Result<GardenPatch> {
         let side_len = lines.len() as i64;
-        assert!(lines.iter().all(|l| l.len() == side_len as usize));
 
         let mut plots = HashSet::new();
-        let mut start = Coordinates(0, 0);
+        let mut start = None;
 
         for (y, line) in lines.iter().enumerate() {
-            assert_eq!(line.len(), side_len as usize);
+            if line.len() != side_len as usize {
+                let message = format!("expected {} tiles", side_len);
+                return Err(aoc_utils::Error::parse(line, message).at_line(y + 1, line));
+            }
             for (x, c) in line.chars().enumerate() {
                 let coords = Coordinates(x as i64, y as i64);
                 match c {
@@ -35,19 +50,23 @@ impl GardenPatch {
                         plots.insert(coords);
                     }
                     'S' => {
-                        start = coords.clone();
+                        start = Some(coords.clone());
                         plots.insert(coords);
                     }
                     '#' => {}
-                    _ => unreachable!(),
+                    _ => {
+                        return Err(aoc_utils::Error::parse(&c.to_string(), "unknown tile")
+                            .at_line(y + 1, line))
+                    }
                 }
             }
         }
-        GardenPatch {
+        let start = start.ok_or_else(|| aoc_utils::Error::parse("", "missing start `S`"))?;
+        Ok(GardenPatch {
             plots,
             start,
             side: side_len,
-        }
+        })
     }
 
     fn count_part_1(&self, max_steps: i64) -> usize {
@@ -174,13 +193,11 @@ fn _explore_direction(
 #[derive(Clone)]
 struct PatchNavigator {
     distances: HashMap<Coordinates, i64>,
-    frontline: HashSet<Coordinates>,
 }
 impl PatchNavigator {
     fn new(start: Coordinates) -> PatchNavigator {
         PatchNavigator {
-            distances: HashMap::from([(start.clone(), 0)]),
-            frontline: HashSet::from([start]),
+            distances: HashMap::from([(start, 0)]),
         }
     }
 
@@ -199,7 +216,6 @@ impl PatchNavigator {
             })
             .collect::<HashMap<_, _>>();
 
-        self.frontline = start_points.keys().cloned().collect();
         self.distances = start_points;
 
         !self.distances.is_empty()
@@ -219,7 +235,6 @@ impl PatchNavigator {
             })
             .collect::<HashMap<_, _>>();
 
-        self.frontline = start_points.keys().cloned().collect();
         self.distances = start_points;
 
         !self.distances.is_empty()
@@ -239,7 +254,6 @@ impl PatchNavigator {
             })
             .collect::<HashMap<_, _>>();
 
-        self.frontline = start_points.keys().cloned().collect();
         self.distances = start_points;
 
         !self.distances.is_empty()
@@ -259,7 +273,6 @@ impl PatchNavigator {
             })
             .collect::<HashMap<_, _>>();
 
-        self.frontline = start_points.keys().cloned().collect();
         self.distances = start_points;
 
         !self.distances.is_empty()
@@ -282,50 +295,41 @@ impl PatchNavigator {
         (even, odd)
     }
 
+    /// Extend the distances from the current ones, which are the starts of the
+    /// search, to every plot reached in at most `max_steps`.
     fn navigate(&mut self, garden: &GardenPatch, max_steps: Option<i64>) {
-        while !self.frontline.is_empty() {
-            let min_dist = self
-                .frontline
-                .iter()
-                .map(|position| self._distance(position))
-                .reduce(i64::min)
-                .unwrap();
-
-            let frontline = self
-                .frontline
-                .iter()
-                .filter(|&position| self._distance(position) == min_dist)
-                .cloned()
-                .collect::<Vec<_>>();
-
-            for position in frontline.iter() {
-                self.frontline.remove(&position);
-
-                let distance = self._distance(&position) + 1;
-                if max_steps.is_some_and(|max_steps| distance > max_steps) {
-                    continue;
-                }
-
-                for next_pos in [
-                    Coordinates(position.0 + 1, position.1),
-                    Coordinates(position.0 - 1, position.1),
-                    Coordinates(position.0, position.1 + 1),
-                    Coordinates(position.0, position.1 - 1),
-                ] {
-                    if garden.plots.contains(&next_pos) {
-                        if !self.distances.contains_key(&next_pos) {
-                            self.distances.insert(next_pos.clone(), distance);
-                        } else if distance < self._distance(&next_pos) {
-                            self.distances.insert(next_pos.clone(), distance);
-                        } else {
-                            continue;
-                        }
-
-                        self.frontline.insert(next_pos);
-                    }
-                }
-            }
+        // The starts are reached at different distances: they all follow a
+        // virtual origin, `None`, at these distances
+        let paths = search::dijkstra(
+            [None],
+            |node: &Option<Coordinates>| match node {
+                None => self
+                    .distances
+                    .iter()
+                    .map(|(start, &distance)| (Some(start.clone()), distance))
+                    .collect::<Vec<_>>(),
+                Some(Coordinates(x, y)) => [(x + 1, *y), (x - 1, *y), (*x, y + 1), (*x, y - 1)]
+                    .into_iter()
+                    .map(|(x, y)| Coordinates(x, y))
+                    .filter(|next| garden.plots.contains(next))
+                    .map(|next| (Some(next), 1))
+                    .collect(),
+            },
+            |_| false,
+        );
+
+        let mut distances = paths
+            .distances()
+            .iter()
+            .filter(|&(_, &distance)| max_steps.is_none_or(|max_steps| distance <= max_steps))
+            .filter_map(|(node, &distance)| Some((node.clone()?, distance)))
+            .collect::<HashMap<_, _>>();
+        // The starts further than `max_steps` are kept, they tell how far the
+        // next patches are
+        for (start, distance) in self.distances.drain() {
+            distances.entry(start).or_insert(distance);
         }
+        self.distances = distances;
     }
 
     fn _distance(&self, position: &Coordinates) -> i64 {
@@ -339,19 +343,31 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "3847 637537341306357");
+        let result = aoc_utils::solution::execute::<Day21>().unwrap();
+        assert_eq!(result.part1, 3847);
+        assert_eq!(result.part2, 637537341306357);
     }
 
     #[test]
     fn test_from_lines() {
         let lines = _example();
 
-        let example = GardenPatch::from_lines(lines);
+        let example = GardenPatch::from_lines(lines).unwrap();
 
         assert_eq!(81, example.plots.len());
         assert_eq!(Coordinates(5, 5), example.start);
     }
 
+    #[test]
+    fn test_parse_errors() {
+        let lines = |lines: &[&str]| lines.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let error = |lines| GardenPatch::from_lines(lines).err().unwrap().to_string();
+
+        assert_eq!(error(lines(&["S#", "."])), "2: expected 2 tiles: `.`");
+        assert_eq!(error(lines(&["S#", ".x"])), "2: unknown tile: `x` in `.x`");
+        assert_eq!(error(lines(&[".#", ".."])), "missing start `S`: ``");
+    }
+
     fn _example() -> Vec<String> {
         vec![
             "...........".to_string(),
@@ -370,7 +386,7 @@ mod tests {
 
     #[test]
     fn test_example() {
-        let garden = GardenPatch::from_lines(_example());
+        let garden = GardenPatch::from_lines(_example()).unwrap();
 
         assert_eq!(16, garden.count_part_1(6));
 
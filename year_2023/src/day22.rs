@@ -1,16 +1,28 @@
+use aoc_utils::parse;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::collections::{HashMap, HashSet, VecDeque};
 
-pub fn execute() -> String {
-    let mut mine = BrickYard::from_lines(aoc_utils::read_lines("input/day22.txt"));
-    mine.drop();
-    let disintegratable = mine.disintegratable_bricks();
+pub struct Day22;
 
-    let chain_reactions = mine.chain_reactions();
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    /// The brick yard once all the bricks have dropped.
+    type Input = BrickYard;
 
-    let part1 = disintegratable.len();
-    let part2 = chain_reactions.values().sum::<usize>();
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        let mut yard = BrickYard::from_lines(lines.to_vec())?;
+        yard.drop();
+        Ok(yard)
+    }
+
+    fn part1(yard: &Self::Input) -> Answer {
+        yard.disintegratable_bricks().len().into()
+    }
 
-    format!("{} {}", part1, part2)
+    fn part2(yard: &Self::Input) -> Answer {
+        yard.chain_reactions().values().sum::<usize>().into()
+    }
 }
 
 type Dimension = u16;
@@ -23,16 +35,15 @@ struct Coordinates {
 }
 
 impl Coordinates {
-    fn from_line(s: String) -> Coordinates {
-        let coords: Vec<_> = s
+    fn from_line(s: String) -> aoc_utils::Result<Coordinates> {
+        let coords = s
             .split(",")
-            .map(|c| str::parse::<Dimension>(c).unwrap())
-            .collect();
-        Coordinates {
-            x: coords[0],
-            y: coords[1],
-            z: coords[2],
-        }
+            .map(parse::value::<Dimension>)
+            .collect::<aoc_utils::Result<Vec<_>>>()?;
+        let [x, y, z] = coords[..] else {
+            return Err(aoc_utils::Error::parse(&s, "expected 3 coordinates"));
+        };
+        Ok(Coordinates { x, y, z })
     }
 }
 
@@ -63,25 +74,25 @@ impl Brick {
             blocks,
         }
     }
-    fn from_line(id: BrickID, s: &String) -> Brick {
-        let (start_str, end_str) = s.split_once("~").unwrap();
-        let start = Coordinates::from_line(start_str.to_string());
-        let end = Coordinates::from_line(end_str.to_string());
-        Brick::new(id, start, end)
+    fn from_line(id: BrickID, s: &str) -> aoc_utils::Result<Brick> {
+        let (start_str, end_str) = parse::split_once_or_err(s, "~")?;
+        let start = Coordinates::from_line(start_str.to_string())?;
+        let end = Coordinates::from_line(end_str.to_string())?;
+        Ok(Brick::new(id, start, end))
     }
 }
 
-struct BrickYard {
+pub struct BrickYard {
     bricks: Vec<Brick>,
     blocks: HashSet<Coordinates>,
 }
 impl BrickYard {
-    fn from_lines(lines: Vec<String>) -> BrickYard {
-        let mut bricks = lines
-            .iter()
-            .enumerate()
-            .map(|(id, line)| Brick::from_line(id as BrickID, line))
-            .collect::<Vec<_>>();
+    fn from_lines(lines: Vec<String>) -> aoc_utils::Result<BrickYard> {
+        let mut id = 0;
+        let mut bricks = parse::lines(&lines, |line| {
+            id += 1;
+            Brick::from_line(id - 1, line)
+        })?;
         bricks.sort_by_key(|brick| brick.start.z);
 
         let mut blocks = HashSet::new();
@@ -89,7 +100,7 @@ impl BrickYard {
             blocks.extend(brick.blocks.iter().cloned());
         }
 
-        BrickYard { bricks, blocks }
+        Ok(BrickYard { bricks, blocks })
     }
 
     fn drop(&mut self) {
@@ -235,7 +246,9 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "389 70609");
+        let result = aoc_utils::solution::execute::<Day22>().unwrap();
+        assert_eq!(result.part1, 389);
+        assert_eq!(result.part2, 70609);
     }
 
     fn _example() -> Vec<String> {
@@ -252,7 +265,7 @@ mod tests {
 
     #[test]
     fn test_brick_from_line() {
-        let example1 = Brick::from_line(0, &String::from("1,0,1~1,2,1"));
+        let example1 = Brick::from_line(0, &String::from("1,0,1~1,2,1")).unwrap();
         assert_eq!(0, example1.id);
         assert_eq!(1, example1.start.x);
         assert_eq!(0, example1.start.y);
@@ -266,7 +279,7 @@ mod tests {
         assert!(example1.blocks.contains(&example1.end));
         assert!(example1.blocks.contains(&Coordinates { x: 1, y: 1, z: 1 }));
 
-        let example2 = Brick::from_line(1, &String::from("0,0,2~2,0,2"));
+        let example2 = Brick::from_line(1, &String::from("0,0,2~2,0,2")).unwrap();
         assert_eq!(1, example2.id);
         assert_eq!(0, example2.start.x);
         assert_eq!(0, example2.start.y);
@@ -280,7 +293,7 @@ mod tests {
         assert!(example2.blocks.contains(&example2.end));
         assert!(example2.blocks.contains(&Coordinates { x: 1, y: 0, z: 2 }));
 
-        let example2 = Brick::from_line(2, &String::from("1,1,8~1,1,9"));
+        let example2 = Brick::from_line(2, &String::from("1,1,8~1,1,9")).unwrap();
         assert_eq!(2, example2.id);
         assert_eq!(1, example2.start.x);
         assert_eq!(1, example2.start.y);
@@ -296,7 +309,7 @@ mod tests {
 
     #[test]
     fn test_from_lines() {
-        let example = BrickYard::from_lines(_example());
+        let example = BrickYard::from_lines(_example()).unwrap();
         assert_eq!(7, example.bricks.len());
         assert_eq!(20, example.blocks.len());
         assert_eq!(Coordinates { x: 1, y: 0, z: 1 }, example.bricks[0].start);
@@ -305,7 +318,8 @@ mod tests {
             "0,0,2~2,0,2".to_string(),
             "0,2,3~2,2,3".to_string(),
             "1,0,1~1,2,1".to_string(),
-        ]);
+        ])
+        .unwrap();
         assert_eq!(2, unordered.bricks[0].id);
         assert_eq!(0, unordered.bricks[1].id);
         assert_eq!(1, unordered.bricks[2].id);
@@ -313,7 +327,7 @@ mod tests {
 
     #[test]
     fn test_drop() {
-        let mut example = BrickYard::from_lines(_example());
+        let mut example = BrickYard::from_lines(_example()).unwrap();
         example.drop();
 
         assert!(example
@@ -346,7 +360,7 @@ mod tests {
 
     #[test]
     fn test_destroy_one() {
-        let mut example = BrickYard::from_lines(_example());
+        let mut example = BrickYard::from_lines(_example()).unwrap();
         example.drop();
         let destroyables = example.disintegratable_bricks();
 
@@ -355,7 +369,7 @@ mod tests {
 
     #[test]
     fn test_chain_reactions() {
-        let mut example = BrickYard::from_lines(_example());
+        let mut example = BrickYard::from_lines(_example()).unwrap();
         example.drop();
 
         let chain_reactions = example.chain_reactions();
@@ -1,13 +1,28 @@
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::collections::{HashMap, HashSet, VecDeque};
 
-pub fn execute() -> String {
-    let mine_slippery = Map::from_lines(aoc_utils::read_lines("input/day23.txt"), true);
-    let part1 = mine_slippery.find_longest_route();
+pub struct Day23;
 
-    let mine_sticky = Map::from_lines(aoc_utils::read_lines("input/day23.txt"), false);
-    let part2 = mine_sticky.find_longest_route();
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    /// The map with slippery slopes, and without.
+    type Input = (Map, Map);
 
-    format!("{} {}", part1, part2)
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Ok((
+            Map::from_lines(lines.to_vec(), true)?,
+            Map::from_lines(lines.to_vec(), false)?,
+        ))
+    }
+
+    fn part1((slippery, _): &Self::Input) -> Answer {
+        slippery.find_longest_route().into()
+    }
+
+    fn part2((_, sticky): &Self::Input) -> Answer {
+        sticky.find_longest_route().into()
+    }
 }
 
 #[derive(Clone, Eq, PartialEq, Debug)]
@@ -25,82 +40,60 @@ enum Tile {
     Slope(Direction),
 }
 
-struct Map {
+pub struct Map {
     tiles: Vec<Vec<Tile>>,
     start: Coordinates,
     end: Coordinates,
 }
 
 impl Map {
-    fn from_lines(lines: Vec<String>, with_slopes: bool) -> Map {
-        let tiles: Vec<Vec<Tile>> = lines
-            .iter()
-            .map(|line| {
-                line.chars()
-                    .map(|c| match c {
-                        '#' => Tile::Forest,
-                        '.' => Tile::Path,
-                        '<' => {
-                            if with_slopes {
-                                Tile::Slope(Direction::Left)
-                            } else {
-                                Tile::Path
-                            }
-                        }
-                        '>' => {
-                            if with_slopes {
-                                Tile::Slope(Direction::Right)
-                            } else {
-                                Tile::Path
-                            }
-                        }
-                        '^' => {
-                            if with_slopes {
-                                Tile::Slope(Direction::Up)
-                            } else {
-                                Tile::Path
-                            }
-                        }
-                        'v' => {
-                            if with_slopes {
-                                Tile::Slope(Direction::Down)
-                            } else {
-                                Tile::Path
-                            }
-                        }
-                        _ => unreachable!(),
-                    })
-                    .collect()
-            })
-            .collect();
-
-        assert!(tiles.iter().all(|row| row.len() == tiles.len()));
-
-        let start_index = tiles
-            .first()
-            .unwrap()
-            .iter()
-            .position(|tile| *tile == Tile::Path)
-            .unwrap();
+    fn from_lines(lines: Vec<String>, with_slopes: bool) -> aoc_utils::Result<Map> {
+        let slope = |direction| match with_slopes {
+            true => Tile::Slope(direction),
+            false => Tile::Path,
+        };
+        let size = lines.len();
+        let tiles: Vec<Vec<Tile>> = aoc_utils::parse::lines(&lines, |line| {
+            if line.len() != size {
+                return Err(aoc_utils::Error::parse(
+                    line,
+                    format!("expected {} tiles", size),
+                ));
+            }
+            line.chars()
+                .map(|c| match c {
+                    '#' => Ok(Tile::Forest),
+                    '.' => Ok(Tile::Path),
+                    '<' => Ok(slope(Direction::Left)),
+                    '>' => Ok(slope(Direction::Right)),
+                    '^' => Ok(slope(Direction::Up)),
+                    'v' => Ok(slope(Direction::Down)),
+                    _ => Err(aoc_utils::Error::parse(&c.to_string(), "unknown tile")),
+                })
+                .collect()
+        })?;
+
+        let path_index = |y: usize, what: &str| {
+            let line = lines.get(y).map_or("", String::as_str);
+            tiles
+                .get(y)
+                .and_then(|row| row.iter().position(|tile| *tile == Tile::Path))
+                .ok_or_else(|| {
+                    aoc_utils::Error::parse(line, format!("missing {} path", what))
+                        .at_line(y + 1, line)
+                })
+        };
 
         let start = Coordinates {
-            x: start_index,
+            x: path_index(0, "start")?,
             y: 0,
         };
-
-        let end_index = tiles
-            .last()
-            .unwrap()
-            .iter()
-            .position(|tile| *tile == Tile::Path)
-            .unwrap();
-
         let end = Coordinates {
-            x: end_index,
-            y: tiles.len() - 1,
+            x: path_index(size.saturating_sub(1), "end")?,
+            y: size.saturating_sub(1),
         };
 
-        Map { tiles, start, end }
+        Ok(Map { tiles, start, end })
     }
 
     fn get(&self, position: &Coordinates) -> &Tile {
@@ -306,12 +299,14 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "2394 6554");
+        let result = aoc_utils::solution::execute::<Day23>().unwrap();
+        assert_eq!(result.part1, 2394);
+        assert_eq!(result.part2, 6554);
     }
 
     #[test]
     fn test_from_lines() {
-        let example = Map::from_lines(example(), true);
+        let example = Map::from_lines(example(), true).unwrap();
         assert_eq!(example.tiles.len(), 23);
         assert_eq!(example.tiles[0].len(), 23);
         assert_eq!(example.start, Coordinates { x: 1, y: 0 });
@@ -331,13 +326,24 @@ mod tests {
 
     #[test]
     fn test_find_longest_route() {
-        let example_slippery = Map::from_lines(example(), true);
+        let example_slippery = Map::from_lines(example(), true).unwrap();
         assert_eq!(94, example_slippery.find_longest_route());
 
-        let example_sticky = Map::from_lines(example(), false);
+        let example_sticky = Map::from_lines(example(), false).unwrap();
         assert_eq!(154, example_sticky.find_longest_route());
     }
 
+    #[test]
+    fn test_parse_errors() {
+        let lines = |lines: &[&str]| lines.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let error = |lines| Map::from_lines(lines, true).err().unwrap().to_string();
+
+        assert_eq!(error(lines(&["#.", "."])), "2: expected 2 tiles: `.`");
+        assert_eq!(error(lines(&["#.", ".x"])), "2: unknown tile: `x` in `.x`");
+        assert_eq!(error(lines(&["##", ".#"])), "1: missing start path: `##`");
+        assert_eq!(error(lines(&["#.", "##"])), "2: missing end path: `##`");
+    }
+
     fn example() -> Vec<String> {
         vec![
             "#.#####################".to_string(),
@@ -1,16 +1,33 @@
-pub fn execute() -> String {
-    let storm = HailStorm::from_lines(aoc_utils::read_lines("input/day24.txt"));
-    let intersections = storm.valid_intersects_xy(200000000000000.0, 400000000000000.0);
+use aoc_utils::num::Rational;
+use aoc_utils::number_theory::gcd;
+use aoc_utils::parse;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 
-    let part1 = intersections.len();
+pub struct Day24;
 
-    let throw_position = storm.find_throw_position();
-    let part2 = throw_position.x + throw_position.y + throw_position.z;
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    type Input = HailStorm;
 
-    format!("{} {}", part1, part2)
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        HailStorm::from_lines(lines.to_vec())
+    }
+
+    fn part1(storm: &Self::Input) -> Answer {
+        storm
+            .valid_intersects_xy(200000000000000, 400000000000000)
+            .len()
+            .into()
+    }
+
+    fn part2(storm: &Self::Input) -> Answer {
+        let throw_position = storm.find_throw_position();
+        (throw_position.x + throw_position.y + throw_position.z).into()
+    }
 }
 
-type Coordinate = f64;
+type Coordinate = i64;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 struct Vector {
@@ -19,17 +36,6 @@ struct Vector {
     z: Coordinate,
 }
 
-impl Vector {
-    fn from_string(s: &str) -> Self {
-        let (x_str, rest) = s.split_once(',').unwrap();
-        let (y_str, z_str) = rest.split_once(',').unwrap();
-        let x = x_str.trim().parse().unwrap();
-        let y = y_str.trim().parse().unwrap();
-        let z = z_str.trim().parse().unwrap();
-        Vector { x, y, z }
-    }
-}
-
 #[derive(Debug, Clone, Copy, PartialEq)]
 struct Stone {
     position: Vector,
@@ -37,66 +43,129 @@ struct Stone {
 }
 
 impl Stone {
-    fn from_line(line: String) -> Stone {
-        let (position_str, velocity_str) = line.split_once('@').unwrap();
-        let position = Vector::from_string(position_str.trim());
-        let velocity = Vector::from_string(velocity_str.trim());
-        Stone { position, velocity }
+    fn from_line(line: String) -> aoc_utils::Result<Stone> {
+        let [px, py, pz, vx, vy, vz] = parse::ints_exact(&line)?;
+        Ok(Stone {
+            position: Vector {
+                x: px,
+                y: py,
+                z: pz,
+            },
+            velocity: Vector {
+                x: vx,
+                y: vy,
+                z: vz,
+            },
+        })
     }
 
-    fn intersects_xy(&self, other: &Stone) -> Option<Vector> {
-        let den = self.velocity.x * other.velocity.y - self.velocity.y * other.velocity.x;
+    fn intersects_xy(&self, other: &Stone) -> Option<[Rational; 2]> {
+        let [p1, v1, p2, v2] = [self.position, self.velocity, other.position, other.velocity]
+            .map(|vector| [vector.x as i128, vector.y as i128]);
+        let den = v1[0] * v2[1] - v1[1] * v2[0];
 
-        if den == 0.0 {
+        if den == 0 {
             return None;
         }
 
-        let num1 = other.velocity.x * (self.position.y - other.position.y)
-            + other.velocity.y * (other.position.x - self.position.x);
-        let num2 = self.velocity.x * (self.position.y - other.position.y)
-            + self.velocity.y * (other.position.x - self.position.x);
+        let num1 = v2[0] * (p1[1] - p2[1]) + v2[1] * (p2[0] - p1[0]);
+        let num2 = v1[0] * (p1[1] - p2[1]) + v1[1] * (p2[0] - p1[0]);
 
-        if num1 * den < 0.0 || num2 * den < 0.0 {
+        if num1 * den < 0 || num2 * den < 0 {
             return None;
         }
 
-        let x = self.position.x + self.velocity.x * num1 / den;
-        let y = self.position.y + self.velocity.y * num1 / den;
-
-        let z = 0.0;
+        let time = Rational::new(num1, den);
+        let x = Rational::from(p1[0]) + Rational::from(v1[0]) * time;
+        let y = Rational::from(p1[1]) + Rational::from(v1[1]) * time;
 
-        Some(Vector { x, y, z })
+        Some([x, y])
     }
 }
 
-struct HailStorm {
+pub struct HailStorm {
     stones: Vec<Stone>,
 }
 impl HailStorm {
-    fn from_lines(lines: Vec<String>) -> HailStorm {
-        let stones = lines.into_iter().map(|l| Stone::from_line(l)).collect();
-        HailStorm { stones }
+    fn from_lines(lines: Vec<String>) -> aoc_utils::Result<HailStorm> {
+        let stones = parse::lines(&lines, |l| Stone::from_line(l.to_string()))?;
+        Ok(HailStorm { stones })
     }
 
-    fn valid_intersects_xy(&self, min: Coordinate, max: Coordinate) -> Vec<Vector> {
+    fn valid_intersects_xy(&self, min: Coordinate, max: Coordinate) -> Vec<[Rational; 2]> {
+        let (min, max) = (Rational::from(min as i128), Rational::from(max as i128));
         let mut result = Vec::new();
         for (i, a) in self.stones.iter().enumerate() {
             for b in self.stones[i + 1..].iter() {
-                let intersect = a.intersects_xy(b);
-                if intersect.is_some_and(|intersect| {
-                    intersect.x >= min
-                        && intersect.x <= max
-                        && intersect.y >= min
-                        && intersect.y <= max
-                }) {
-                    result.push(intersect.unwrap());
+                if let Some(intersect) = a.intersects_xy(b) {
+                    if intersect.iter().all(|c| (min..=max).contains(c)) {
+                        result.push(intersect);
+                    }
                 }
             }
         }
         result
     }
 
+    /// Where to throw a rock from to hit all the stones, which is exactly
+    /// computed with integers.
+    ///
+    /// Seen from the first stone, the rock goes through the origin: its path
+    /// lies in the plane holding the origin and the path of each other stone,
+    /// so its direction is the intersection of two such planes. The times at
+    /// which two stones meet that line then give the whole throw.
     fn find_throw_position(&self) -> Vector {
+        let (first, others) = self.stones.split_first().expect("No stones.");
+        let (origin, drift) = (exact(&first.position), exact(&first.velocity));
+        let relative = others
+            .iter()
+            .map(|stone| {
+                let position = sub(exact(&stone.position), origin);
+                let velocity = sub(exact(&stone.velocity), drift);
+                (position, velocity)
+            })
+            .collect::<Vec<_>>();
+
+        let normals = relative
+            .iter()
+            .map(|&(position, velocity)| cross(position, velocity))
+            .collect::<Vec<_>>();
+        let direction = normals
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &a)| normals[i + 1..].iter().map(move |&b| cross(a, b)))
+            .find(|&direction| direction != [0; 3])
+            .map(reduce)
+            .expect("No solution found.");
+
+        let mut hits = relative.iter().zip(others).filter_map(|(&(p, v), stone)| {
+            let (a, b) = (cross(p, direction), cross(v, direction));
+            let k = (0..3).find(|&k| b[k] != 0)?;
+            assert_eq!(a[k] % b[k], 0, "Stones should be hit at whole times.");
+            let time = -a[k] / b[k];
+            let hit = add(exact(&stone.position), scale(exact(&stone.velocity), time));
+            Some((time, hit))
+        });
+        let (t1, hit1) = hits.next().expect("No solution found.");
+        let (t2, hit2) = hits
+            .find(|&(time, _)| time != t1)
+            .expect("No solution found.");
+
+        let velocity = sub(hit2, hit1).map(|c| {
+            assert_eq!(c % (t2 - t1), 0, "The throw should have a whole velocity.");
+            c / (t2 - t1)
+        });
+        let [x, y, z] = sub(hit1, scale(velocity, t1));
+        Vector {
+            x: x as Coordinate,
+            y: y as Coordinate,
+            z: z as Coordinate,
+        }
+    }
+
+    /// The same as [`HailStorm::find_throw_position`], solved by z3 to check it.
+    #[cfg(all(test, feature = "z3"))]
+    fn find_throw_position_z3(&self) -> Vector {
         use z3::ast::Ast;
         use z3::*;
 
@@ -122,12 +191,12 @@ impl HailStorm {
 
             let stone = &self.stones[i];
 
-            let x_n = ast::Int::from_i64(&context, stone.position.x as i64);
-            let y_n = ast::Int::from_i64(&context, stone.position.y as i64);
-            let z_n = ast::Int::from_i64(&context, stone.position.z as i64);
-            let u_n = ast::Int::from_i64(&context, stone.velocity.x as i64);
-            let v_n = ast::Int::from_i64(&context, stone.velocity.y as i64);
-            let w_n = ast::Int::from_i64(&context, stone.velocity.z as i64);
+            let x_n = ast::Int::from_i64(&context, stone.position.x);
+            let y_n = ast::Int::from_i64(&context, stone.position.y);
+            let z_n = ast::Int::from_i64(&context, stone.position.z);
+            let u_n = ast::Int::from_i64(&context, stone.velocity.x);
+            let v_n = ast::Int::from_i64(&context, stone.velocity.y);
+            let w_n = ast::Int::from_i64(&context, stone.velocity.z);
 
             let eq_x = &x_0 + &u_0 * &t_n - x_n - u_n * &t_n;
             let eq_y = &y_0 + &v_0 * &t_n - y_n - v_n * &t_n;
@@ -141,9 +210,9 @@ impl HailStorm {
         if matches!(solver.check(), SatResult::Sat) {
             if let Some(model) = solver.get_model() {
                 return Vector {
-                    x: model.get_const_interp(&x_0).unwrap().as_i64().unwrap() as Coordinate,
-                    y: model.get_const_interp(&y_0).unwrap().as_i64().unwrap() as Coordinate,
-                    z: model.get_const_interp(&z_0).unwrap().as_i64().unwrap() as Coordinate,
+                    x: model.get_const_interp(&x_0).unwrap().as_i64().unwrap(),
+                    y: model.get_const_interp(&y_0).unwrap().as_i64().unwrap(),
+                    z: model.get_const_interp(&z_0).unwrap().as_i64().unwrap(),
                 };
             }
         }
@@ -152,136 +221,138 @@ impl HailStorm {
     }
 }
 
+type Exact = [i128; 3];
+
+fn exact(vector: &Vector) -> Exact {
+    [vector.x as i128, vector.y as i128, vector.z as i128]
+}
+
+fn add(a: Exact, b: Exact) -> Exact {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn sub(a: Exact, b: Exact) -> Exact {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn scale(a: Exact, factor: i128) -> Exact {
+    a.map(|c| c * factor)
+}
+
+fn cross(a: Exact, b: Exact) -> Exact {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// The shortest vector with integer coordinates in the same direction.
+fn reduce(a: Exact) -> Exact {
+    let divisor = a.iter().fold(0, |d, &c| gcd(d, c.abs()));
+    a.map(|c| c / divisor)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "15889 801386475216902");
+        let result = aoc_utils::solution::execute::<Day24>().unwrap();
+        assert_eq!(result.part1, 15889);
+        assert_eq!(result.part2, 801386475216902);
     }
 
     #[test]
     fn test_stone_from_line() {
-        let ex1 = Stone::from_line("9, 13, 0 @ -2,  1, -2".to_string());
-        assert_eq!(
-            Vector {
-                x: 9.0,
-                y: 13.0,
-                z: 0.0
-            },
-            ex1.position
-        );
-        assert_eq!(
-            Vector {
-                x: -2.0,
-                y: 1.0,
-                z: -2.0
-            },
-            ex1.velocity
-        );
+        let ex1 = Stone::from_line("9, 13, 0 @ -2,  1, -2".to_string()).unwrap();
+        assert_eq!(Vector { x: 9, y: 13, z: 0 }, ex1.position);
+        assert_eq!(Vector { x: -2, y: 1, z: -2 }, ex1.velocity);
     }
 
     #[test]
     fn test_intersect_xy() {
-        let example = HailStorm::from_lines(_example());
-
-        fn check_xy(ex: Coordinate, ey: Coordinate, intersect: Vector) {
-            assert!(
-                Coordinate::abs(intersect.x - ex) < 0.000000001,
-                "X mismatch: {} vs {}",
-                ex,
-                intersect.x
-            );
-            assert!(
-                Coordinate::abs(intersect.y - ey) < 0.000000001,
-                "Y mismatch: {} vs {}",
-                ey,
-                intersect.y
-            );
-        }
+        let example = HailStorm::from_lines(_example()).unwrap();
+        let intersect = |i: usize, j: usize| example.stones[i].intersects_xy(&example.stones[j]);
+        let at = |x: (i128, i128), y: (i128, i128)| {
+            Some([Rational::new(x.0, x.1), Rational::new(y.0, y.1)])
+        };
+
+        assert_eq!(intersect(0, 1), at((43, 3), (46, 3)));
+        assert_eq!(intersect(0, 2), at((35, 3), (50, 3)));
+        assert_eq!(intersect(0, 3), at((31, 5), (97, 5)));
+        assert_eq!(intersect(0, 4), None);
+
+        assert_eq!(intersect(1, 2), None);
+        assert_eq!(intersect(1, 3), at((-6, 1), (-5, 1)));
+        assert_eq!(intersect(1, 4), None);
+
+        assert_eq!(intersect(2, 3), at((-2, 1), (3, 1)));
+        assert_eq!(intersect(2, 4), None);
 
-        let intersect_0_1 = example.stones[0].intersects_xy(&example.stones[1]);
-        check_xy(14.0 + 1.0 / 3.0, 15.0 + 1.0 / 3.0, intersect_0_1.unwrap());
-        let intersect_0_2 = example.stones[0].intersects_xy(&example.stones[2]);
-        check_xy(11.0 + 2.0 / 3.0, 16.0 + 2.0 / 3.0, intersect_0_2.unwrap());
-        let intersect_0_3 = example.stones[0].intersects_xy(&example.stones[3]);
-        check_xy(6.2, 19.4, intersect_0_3.unwrap());
-        let intersect_0_4 = example.stones[0].intersects_xy(&example.stones[4]);
-        assert_eq!(intersect_0_4, None);
-
-        let intersect_1_2 = example.stones[1].intersects_xy(&example.stones[2]);
-        assert_eq!(intersect_1_2, None);
-        let intersect_1_3 = example.stones[1].intersects_xy(&example.stones[3]);
-        check_xy(-6.0, -5.0, intersect_1_3.unwrap());
-        let intersect_1_4 = example.stones[1].intersects_xy(&example.stones[4]);
-        assert_eq!(intersect_1_4, None);
-
-        let intersect_2_3 = example.stones[2].intersects_xy(&example.stones[3]);
-        check_xy(-2.0, 3.0, intersect_2_3.unwrap());
-        let intersect_2_4 = example.stones[2].intersects_xy(&example.stones[4]);
-        assert_eq!(intersect_2_4, None);
-
-        let intersect_3_4 = example.stones[3].intersects_xy(&example.stones[4]);
-        assert_eq!(intersect_3_4, None);
+        assert_eq!(intersect(3, 4), None);
     }
 
     #[test]
     fn test_hailstorm_from_line() {
         let lines = _example();
-        let hailstorm = HailStorm::from_lines(lines);
+        let hailstorm = HailStorm::from_lines(lines).unwrap();
 
         assert_eq!(5, hailstorm.stones.len());
 
         assert_eq!(
             Vector {
-                x: 19.0,
-                y: 13.0,
-                z: 30.0,
+                x: 19,
+                y: 13,
+                z: 30,
             },
             hailstorm.stones[0].position
         );
-        assert_eq!(
-            Vector {
-                x: -2.0,
-                y: 1.0,
-                z: -2.0
-            },
-            hailstorm.stones[0].velocity
-        );
+        assert_eq!(Vector { x: -2, y: 1, z: -2 }, hailstorm.stones[0].velocity);
 
         assert_eq!(
             Vector {
-                x: 20.0,
-                y: 19.0,
-                z: 15.0,
+                x: 20,
+                y: 19,
+                z: 15,
             },
             hailstorm.stones[4].position
         );
-        assert_eq!(
-            Vector {
-                x: 1.0,
-                y: -5.0,
-                z: -3.0
-            },
-            hailstorm.stones[4].velocity
-        );
+        assert_eq!(Vector { x: 1, y: -5, z: -3 }, hailstorm.stones[4].velocity);
     }
 
     #[test]
     fn test_valid_intersects_xy() {
-        let example = HailStorm::from_lines(_example());
-        let intersections = example.valid_intersects_xy(7.0, 27.0);
+        let example = HailStorm::from_lines(_example()).unwrap();
+        let intersections = example.valid_intersects_xy(7, 27);
         assert_eq!(intersections.len(), 2);
     }
 
     #[test]
     fn test_find_throw_position() {
-        let example = HailStorm::from_lines(_example());
+        let example = HailStorm::from_lines(_example()).unwrap();
         let position = example.find_throw_position();
-        assert_eq!(position.x, 24.0);
-        assert_eq!(position.y, 13.0);
-        assert_eq!(position.z, 10.0);
+        assert_eq!(position.x, 24);
+        assert_eq!(position.y, 13);
+        assert_eq!(position.z, 10);
+    }
+
+    #[test]
+    #[cfg(feature = "z3")]
+    fn test_find_throw_position_z3() {
+        let example = HailStorm::from_lines(_example()).unwrap();
+        assert_eq!(
+            example.find_throw_position_z3(),
+            example.find_throw_position()
+        );
+    }
+
+    #[test]
+    fn test_cross() {
+        assert_eq!(cross([1, 0, 0], [0, 1, 0]), [0, 0, 1]);
+        assert_eq!(cross([2, 3, 4], [2, 3, 4]), [0, 0, 0]);
+        assert_eq!(reduce([-4, 6, 0]), [-2, 3, 0]);
     }
 
     fn _example() -> Vec<String> {
@@ -1,142 +1,52 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use aoc_utils::graph::Graph;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day25.txt");
-    let network = Graph::from_lines(data);
+pub struct Day25;
 
-    let part1 = network.find_min_cut_solution();
-    let part2 = 456;
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    type Input = Graph;
 
-    format!("{} {}", part1, part2)
-}
-
-#[derive(Debug, Clone)]
-struct Graph {
-    v_index: HashMap<String, usize>,
-    v_name: Vec<String>,
-    edge: Vec<HashMap<usize, i32>>,
-}
-
-impl Graph {
-    fn from_lines(lines: Vec<String>) -> Self {
-        let mut pairs = vec![];
-        let mut components = HashSet::new();
-
-        for line in lines {
-            let (source, targets_str) = line.split_once(": ").unwrap();
-            components.insert(source.to_string());
-            let targets = targets_str.split(" ");
-            for target in targets {
-                pairs.push((source.to_string(), target.to_string()));
-                components.insert(target.to_string());
-            }
-        }
-
-        let v_names = Vec::from_iter(components.into_iter());
-        let vertices = HashMap::from_iter(v_names.iter().enumerate().map(|(i, c)| (c.clone(), i)));
-
-        let mut edges = vec![HashMap::new(); vertices.len()];
-        for (a, b) in pairs.iter() {
-            let i_a = vertices[a];
-            let i_b = vertices[b];
-            *edges[i_a].entry(i_b).or_default() = 1;
-            *edges[i_b].entry(i_a).or_default() = 1;
-        }
-
-        Graph {
-            v_index: vertices,
-            v_name: v_names,
-            edge: edges,
-        }
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        from_lines(lines.to_vec())
     }
 
-    fn dfs(&self, start: usize, to: usize, seen: &mut Vec<usize>, visited: &mut Vec<bool>) -> bool {
-        seen.push(start);
-        visited[start] = true;
-        if start == to {
-            return true;
-        }
-
-        let next_edges = &self.edge[start];
-        for (&next, &capacity) in next_edges.iter() {
-            if capacity > 0
-                && !visited[next]
-                && !seen.contains(&next)
-                && self.dfs(next, to, seen, visited)
-            {
-                return true;
-            }
-        }
-
-        seen.pop();
-        false
+    fn part1(network: &Self::Input) -> Answer {
+        find_min_cut_solution(network).into()
     }
 
-    fn reachable(&self, start: usize) -> HashSet<usize> {
-        let mut visited = HashSet::new();
-        visited.insert(start);
-
-        let mut to_visit = VecDeque::new();
-        to_visit.push_back(start);
-
-        while !to_visit.is_empty() {
-            let current = to_visit.pop_front().unwrap();
-            let edges = &self.edge[current];
-            for (&next, &capacity) in edges {
-                if capacity > 0 && visited.insert(next) {
-                    to_visit.push_back(next);
-                }
-            }
-        }
-
-        visited
+    fn part2(_network: &Self::Input) -> Answer {
+        456.into()
     }
+}
 
-    fn max_flow(&mut self, from: usize, to: usize) -> usize {
-        let mut count = 0;
-        let mut seen = vec![];
-        let mut visited = vec![false; self.v_index.len()];
-        while self.dfs(from, to, &mut seen, &mut visited) {
-            for i in 0..seen.len() - 1 {
-                let a = seen[i];
-                let b = seen[i + 1];
-                self.inc_capacity(a, b, -1);
-                self.inc_capacity(b, a, 1);
-            }
-
-            seen.clear();
-            visited = vec![false; self.v_index.len()];
-            count += 1;
+fn from_lines(lines: Vec<String>) -> aoc_utils::Result<Graph> {
+    let mut graph = Graph::new();
+    for (i, line) in lines.iter().enumerate() {
+        let (source, targets) = aoc_utils::parse::split_once_or_err(line, ": ")
+            .map_err(|error| error.at_line(i + 1, line))?;
+        for target in targets.split(" ") {
+            graph.add_undirected_edge(source, target);
         }
-        count
     }
+    Ok(graph)
+}
 
-    fn find_min_cut_solution(&self) -> usize {
-        for i in 0..self.v_index.len() {
-            for j in i + 1..self.v_index.len() {
-                let mut residual = self.clone();
-                let max_flow = residual.max_flow(i, j);
-
-                if max_flow == 3 {
-                    let g1 = residual.reachable(i).len();
-                    let g2 = residual.v_name.len() - g1;
+fn find_min_cut_solution(network: &Graph) -> usize {
+    for i in 0..network.len() {
+        for j in i + 1..network.len() {
+            let cut = network.min_cut(i, j);
+            if cut.flow == 3 {
+                let g1 = cut.source_side.len();
+                let g2 = network.len() - g1;
 
-                    return g1 * g2;
-                }
+                return g1 * g2;
             }
         }
-
-        panic!("did not find a solution!");
     }
 
-    fn inc_capacity(&mut self, from: usize, to: usize, value: i32) {
-        let current = self.edge[from].entry(to).or_default();
-        *current += value;
-
-        if *current == 0 {
-            self.edge[from].remove(&to);
-        }
-    }
+    panic!("did not find a solution!");
 }
 
 #[cfg(test)]
@@ -144,61 +54,36 @@ mod tests {
     use super::*;
     use std::collections::HashSet;
 
-    impl Graph {
-        fn num_edges(&self) -> usize {
-            self.edge
-                .iter()
-                .map(|v| v.values().filter(|&v| *v >= 1).count())
-                .sum::<usize>()
-        }
-    }
-
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "603368 456");
+        let result = aoc_utils::solution::execute::<Day25>().unwrap();
+        assert_eq!(result.part1, 603368);
+        assert_eq!(result.part2, 456);
     }
 
     #[test]
     fn test_from_lines() {
-        let network = Graph::from_lines(example());
-        let v = |vertice: &str| -> &usize { &network.v_index[vertice] };
+        let network = from_lines(example()).unwrap();
+        let v = |vertex: &str| -> usize { network.index(vertex).unwrap() };
 
-        assert_eq!(network.v_index.len(), 15);
-        assert_eq!(network.num_edges(), 66);
+        assert_eq!(network.len(), 15);
+        assert_eq!(network.edge_count(), 66);
 
-        assert_ne!(network.edge[*v("cmg")][v("bvb")], 0);
-        assert_ne!(network.edge[*v("bvb")][v("cmg")], 0);
-    }
-
-    #[test]
-    fn test_dfs() {
-        let network = Graph::from_lines(example());
-        let v = |vertice: &str| -> usize { network.v_index[vertice] };
-
-        let mut seen = vec![];
-        let mut visited = vec![false; network.v_index.len()];
-        assert!(network.dfs(v("jqt"), v("jqt"), &mut seen, &mut visited));
-        assert_eq!(seen.len(), 1);
-        assert_eq!(seen, vec![v("jqt")]);
-
-        let mut seen = vec![];
-        let mut visited = vec![false; network.v_index.len()];
-        assert!(network.dfs(v("jqt"), v("xhk"), &mut seen, &mut visited));
-        assert_eq!(*seen.first().unwrap(), v("jqt"));
-        assert_eq!(*seen.last().unwrap(), v("xhk"));
+        assert!(network.has_edge(v("cmg"), v("bvb")));
+        assert!(network.has_edge(v("bvb"), v("cmg")));
     }
 
     #[test]
     fn test_max_flow() {
-        let network = Graph::from_lines(example());
-        let v = |vertex: &str| -> usize { network.v_index[vertex] };
+        let network = from_lines(example()).unwrap();
+        let v = |vertex: &str| -> usize { network.index(vertex).unwrap() };
 
         for v1 in [
             "cmg", "frs", "lhk", "lsr", "nvd", "pzl", "qnr", "rsh", "rzs",
         ] {
             for v2 in ["bvb", "hfx", "jqt", "ntq", "rhn", "xhk"] {
-                let flow1 = network.clone().max_flow(v(v1), v(v2));
-                let flow2 = network.clone().max_flow(v(v2), v(v1));
+                let flow1 = network.max_flow(v(v1), v(v2));
+                let flow2 = network.max_flow(v(v2), v(v1));
                 assert_eq!(
                     flow1, flow2,
                     "{0} -> {1}: {2} || {1} -> {0}: {3}",
@@ -206,31 +91,29 @@ mod tests {
                 );
                 assert_eq!(flow1, 3, "{} -> {}: {}", v1, v2, flow1);
                 assert_eq!(flow2, 3, "{} -> {}: {}", v1, v2, flow2);
-                println!("{0} -> {1}: {2} || {1} -> {0}: {3}", v1, v2, flow1, flow2);
             }
         }
     }
 
     #[test]
-    fn test_reachable() {
-        let network = Graph::from_lines(example());
-        let v = |vertex: &str| -> usize { network.v_index[vertex] };
+    fn test_min_cut() {
+        let network = from_lines(example()).unwrap();
+        let v = |vertex: &str| -> usize { network.index(vertex).unwrap() };
 
         for v1 in [
             "cmg", "frs", "lhk", "lsr", "nvd", "pzl", "qnr", "rsh", "rzs",
         ] {
             for v2 in ["bvb", "hfx", "jqt", "ntq", "rhn", "xhk"] {
-                let mut g1 = network.clone();
-                g1.max_flow(v(v1), v(v2));
-                let s1 = g1.reachable(v(v1));
+                let s1 = network.min_cut(v(v1), v(v2)).source_side;
                 assert_eq!(s1.len(), 9);
+                assert!(s1.contains(&v(v1)) && !s1.contains(&v(v2)));
 
-                let mut g2 = network.clone();
-                g2.max_flow(v(v2), v(v1));
-                let s2 = g2.reachable(v(v2));
+                let s2 = network.min_cut(v(v2), v(v1)).source_side;
                 assert_eq!(s2.len(), 6);
             }
         }
+
+        assert_eq!(find_min_cut_solution(&network), 54);
     }
 
     #[test]
@@ -259,7 +142,7 @@ mod tests {
 
     #[test]
     fn test_my_assumptions() {
-        let data = aoc_utils::read_lines("input/day25.txt");
+        let data = aoc_utils::read_lines("input/day25.txt").unwrap();
 
         let my_pairs = pairs_from_lines(data);
         let my_uniq_pairs = HashSet::<(String, String)>::from_iter(my_pairs.clone().into_iter());
@@ -295,6 +178,6 @@ mod tests {
     }
 
     fn example() -> Vec<String> {
-        aoc_utils::read_lines("input/day25-example.txt")
+        aoc_utils::read_lines("input/day25-example.txt").unwrap()
     }
 }
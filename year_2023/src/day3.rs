@@ -1,14 +1,25 @@
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::collections::HashMap;
 use std::ops::Index;
 
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day3.txt");
-    let my_map = CharMap::from_text(&data);
+pub struct Day3;
 
-    let part1 = sum_part_numbers(&my_map);
-    let part2 = sum_gear_ratios(&my_map);
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input = CharMap;
 
-    format!("{} {}", part1, part2)
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Ok(CharMap::from_text(&lines.to_vec()))
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        sum_part_numbers(map).into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        sum_gear_ratios(map).into()
+    }
 }
 
 fn sum_part_numbers(map: &CharMap) -> u32 {
@@ -26,7 +37,7 @@ fn sum_gear_ratios(map: &CharMap) -> u32 {
         .sum();
 }
 
-struct CharMap {
+pub struct CharMap {
     chars: Vec<String>,
 }
 
@@ -174,12 +185,14 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "507214 72553319");
+        let result = aoc_utils::solution::execute::<Day3>().unwrap();
+        assert_eq!(result.part1, 507214);
+        assert_eq!(result.part2, 72553319);
     }
 
     #[test]
     fn test_sum_part_numbers() {
-        let example = aoc_utils::read_lines("input/day3-example.txt");
+        let example = aoc_utils::read_lines("input/day3-example.txt").unwrap();
         let example_map = CharMap::from_text(&example);
 
         assert_eq!(4361, sum_part_numbers(&example_map))
@@ -187,7 +200,7 @@ mod tests {
 
     #[test]
     fn test_sum_gear_ratios() {
-        let example = aoc_utils::read_lines("input/day3-example.txt");
+        let example = aoc_utils::read_lines("input/day3-example.txt").unwrap();
         let example_map = CharMap::from_text(&example);
 
         assert_eq!(467835, sum_gear_ratios(&example_map));
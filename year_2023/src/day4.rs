@@ -1,12 +1,25 @@
+use aoc_utils::parse;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::collections::HashSet;
 
-pub fn execute() -> String {
-    let cards = Card::from_file("day4.txt");
+pub struct Day4;
 
-    let part1 = simple_wins(&cards);
-    let part2 = correct_wins(&cards);
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input = Vec<Card>;
 
-    format!("{} {}", part1, part2)
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        parse::lines(lines, Card::from_text)
+    }
+
+    fn part1(cards: &Self::Input) -> Answer {
+        simple_wins(cards).into()
+    }
+
+    fn part2(cards: &Self::Input) -> Answer {
+        correct_wins(cards).into()
+    }
 }
 
 fn simple_wins(cards: &Vec<Card>) -> u32 {
@@ -30,38 +43,33 @@ fn correct_wins(cards: &Vec<Card>) -> u32 {
 }
 
 #[derive(Debug)]
-struct Card {
+pub struct Card {
     winners: HashSet<u32>,
     numbers: HashSet<u32>,
 }
 
 impl Card {
-    fn from_file(filename: &str) -> Vec<Card> {
-        let path = format!("input/{}", &filename);
-        let lines = aoc_utils::read_lines(&path);
-        return Vec::from_iter(lines.iter().map(|line| Card::from_text(&line)));
+    #[cfg(test)]
+    fn from_file(filename: &str) -> aoc_utils::Result<Vec<Card>> {
+        aoc_utils::input::parse_lines(filename, Card::from_text)
     }
 
-    fn from_text(text: &str) -> Card {
-        let (_card_text, lists_text) = text.split_once(": ").unwrap();
-        let (winners_text, numbers_text) = lists_text.split_once(" | ").unwrap();
-
-        let winners = HashSet::from_iter(
-            winners_text
-                .trim()
-                .split_whitespace()
-                .map(str::parse::<u32>)
-                .map(Result::unwrap),
-        );
-        let numbers = HashSet::from_iter(
-            numbers_text
-                .trim()
-                .split_whitespace()
-                .map(str::parse::<u32>)
-                .map(Result::unwrap),
-        );
-
-        return Card { winners, numbers };
+    fn from_text(text: &str) -> aoc_utils::Result<Card> {
+        let (_card_text, lists_text) = parse::split_once_or_err(text, ": ")?;
+        let (winners_text, numbers_text) = parse::split_once_or_err(lists_text, " | ")?;
+
+        let winners = winners_text
+            .trim()
+            .split_whitespace()
+            .map(parse::value::<u32>)
+            .collect::<aoc_utils::Result<_>>()?;
+        let numbers = numbers_text
+            .trim()
+            .split_whitespace()
+            .map(parse::value::<u32>)
+            .collect::<aoc_utils::Result<_>>()?;
+
+        return Ok(Card { winners, numbers });
     }
 
     fn simple_score(&self) -> u32 {
@@ -84,11 +92,13 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "21959 5132675");
+        let result = aoc_utils::solution::execute::<Day4>().unwrap();
+        assert_eq!(result.part1, 21959);
+        assert_eq!(result.part2, 5132675);
     }
     #[test]
     fn test_simple_wins() {
-        let example_cards = Card::from_file("day4-example.txt");
+        let example_cards = Card::from_file("day4-example.txt").unwrap();
 
         assert_eq!(13, simple_wins(&example_cards));
         assert_eq!(30, correct_wins(&example_cards));
@@ -96,7 +106,7 @@ mod tests {
 
     #[test]
     fn test_correct_wins() {
-        let example_cards = Card::from_file("day4-example.txt");
+        let example_cards = Card::from_file("day4-example.txt").unwrap();
         assert_eq!(30, correct_wins(&example_cards));
     }
 }
@@ -1,45 +1,58 @@
+use aoc_utils::intervals::{RangeMap, RangeSet};
+use aoc_utils::parse;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::cmp::min;
-use std::collections::HashSet;
-use std::iter::zip;
 use std::ops::Index;
 
-pub fn execute() -> String {
-    let almanac = Almanac::from_text("day5.txt");
+pub struct Day5;
 
-    let part1 = almanac.lowest_location_1();
-    let part2 = almanac.lowest_location_2();
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input = Almanac;
 
-    format!("{} {}", part1, part2)
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Almanac::from_lines(lines.to_vec())
+    }
+
+    fn part1(almanac: &Self::Input) -> Answer {
+        almanac.lowest_location_1().into()
+    }
+
+    fn part2(almanac: &Self::Input) -> Answer {
+        almanac.lowest_location_2().into()
+    }
 }
 
-struct Almanac {
+pub struct Almanac {
     seeds: Vec<usize>,
     mappings: Vec<Mapping>,
 }
 impl Almanac {
-    fn from_text(filename: &str) -> Almanac {
-        let path = format!("input/{}", &filename);
-        let data = aoc_utils::read_lines(&path);
+    fn from_lines(data: Vec<String>) -> aoc_utils::Result<Almanac> {
         let mut blocks = data.split(|line| line.trim().is_empty());
 
         let seeds_block = blocks.next().unwrap();
         debug_assert_eq!(1, seeds_block.len());
         let seeds_line = seeds_block.index(0);
-        let seeds_text = seeds_line.split_once(":").unwrap().1.trim();
-
-        let mut seeds = Vec::new();
-        for seed_text in seeds_text.split_whitespace() {
-            let seed = seed_text.parse::<usize>().unwrap();
-            seeds.push(seed);
-        }
+        let seeds = parse::split_once_or_err(seeds_line, ":")
+            .and_then(|(_, seeds_text)| {
+                seeds_text
+                    .split_whitespace()
+                    .map(parse::value::<usize>)
+                    .collect()
+            })
+            .map_err(|error| error.at_line(1, seeds_line))?;
 
         let mut mappings = Vec::new();
+        let mut offset = seeds_block.len() + 1;
         for block in blocks {
-            let mapping = Mapping::from_text(block);
+            let mapping = Mapping::from_text(block, offset)?;
             assert!(mapping.is_bijection());
             mappings.push(mapping);
+            offset += block.len() + 1;
         }
-        return Almanac { seeds, mappings };
+        return Ok(Almanac { seeds, mappings });
     }
 
     fn lowest_location_1(&self) -> usize {
@@ -52,18 +65,18 @@ impl Almanac {
     }
 
     fn lowest_location_2(&self) -> usize {
-        let mut edges = HashSet::new();
+        let mut values = self
+            .seeds
+            .chunks(2)
+            .map(|pair| pair[0]..pair[0] + pair[1])
+            .collect::<RangeSet<_>>();
 
         for mapping in &self.mappings {
-            edges.extend(mapping.src_edges());
-            edges = HashSet::from_iter(edges.iter().map(|&edge| mapping.forward(edge)));
+            values = mapping.ranges().map_set(&values);
         }
 
-        let &min_location = edges
-            .iter()
-            .filter(|&&loc| self.is_location_for_seed(loc))
-            .min()
-            .unwrap();
+        let min_location = values.min().unwrap();
+        debug_assert!(self.is_seed(self.get_seed(min_location)));
         return min_location;
     }
 
@@ -85,10 +98,6 @@ impl Almanac {
         return value;
     }
 
-    fn is_location_for_seed(&self, location: usize) -> bool {
-        return self.is_seed(self.get_seed(location));
-    }
-
     fn is_seed(&self, seed: usize) -> bool {
         let mut index = 0;
         while index + 1 < self.seeds.len() {
@@ -109,120 +118,50 @@ struct Mapping {
     entries: Vec<MappingEntry>,
 }
 impl Mapping {
-    fn from_text(text: &[impl AsRef<str>]) -> Mapping {
-        let mut lines = text.into_iter();
-
-        let _name_line = lines.next().unwrap();
+    /// Parse the block of lines `text`, found after `offset` lines of the input.
+    fn from_text(text: &[impl AsRef<str>], offset: usize) -> aoc_utils::Result<Mapping> {
+        let _name_line = &text[0];
+        let entries = parse::lines_at(&text[1..], offset + 1, MappingEntry::from_text)?;
 
-        let mut entries = Vec::new();
-        for line in lines {
-            entries.push(MappingEntry::from_text(line.as_ref()));
-        }
-
-        return Mapping { entries };
+        return Ok(Mapping { entries });
     }
 
-    fn src_edges(&self) -> HashSet<usize> {
-        let mut result = HashSet::new();
+    fn ranges(&self) -> RangeMap<usize> {
+        let mut ranges = RangeMap::new();
         for entry in &self.entries {
-            result.insert(entry.src);
-            result.insert(entry.src + entry.width - 1);
+            ranges.insert(entry.src..entry.src + entry.width, entry.dst);
         }
-        return result;
+        return ranges;
     }
 
     fn forward(&self, origin: usize) -> usize {
-        for entry in &self.entries {
-            let mapped = entry.forward(origin);
-            if mapped.is_some() {
-                return mapped.unwrap();
-            }
-        }
-        return origin;
+        return self.ranges().map(origin);
     }
     fn backward(&self, origin: usize) -> usize {
-        for entry in &self.entries {
-            let mapped = entry.backward(origin);
-            if mapped.is_some() {
-                return mapped.unwrap();
-            }
-        }
-        return origin;
+        return self.ranges().inverse().map(origin);
     }
 
     fn is_bijection(&self) -> bool {
-        let sources = Vec::from_iter(self.entries.iter().map(|entry| (entry.src, entry.width)));
-        let sources_compressed = compress(sources);
-
-        let dests = Vec::from_iter(self.entries.iter().map(|entry| (entry.dst, entry.width)));
-        let dests_compressed = compress(dests);
-
-        for pair in zip(sources_compressed.iter(), dests_compressed.iter()) {
-            if pair.0 != pair.1 {
-                return false;
-            }
-        }
-
-        return true;
+        let ranges = self.ranges();
+        return ranges.sources() == ranges.destinations();
     }
 }
 
-fn compress(entries: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
-    if entries.len() < 2 {
-        return entries;
-    }
-
-    let mut sorted = entries.clone();
-    sorted.sort();
-
-    let mut sorted_iter = sorted.iter().peekable();
-    let (mut start, mut width) = sorted_iter.next().unwrap();
-
-    let mut result = Vec::new();
-    while let Some(entry) = sorted_iter.next() {
-        if entry.0 == start + width {
-            width += entry.1;
-        } else {
-            result.push((start, width));
-            start = entry.0;
-            width = entry.1;
-        }
-        if sorted_iter.peek().is_none() {
-            result.push((start, width));
-        }
-    }
-
-    return result;
-}
 struct MappingEntry {
     src: usize,
     dst: usize,
     width: usize,
 }
 impl MappingEntry {
-    fn from_text(text: &str) -> MappingEntry {
-        let mut parts = text.splitn(3, " ");
-        let dst = parts.next().unwrap().parse::<usize>().unwrap();
-        let src = parts.next().unwrap().parse::<usize>().unwrap();
-        let width = parts.next().unwrap().parse::<usize>().unwrap();
-        MappingEntry { src, dst, width }
-    }
-
-    fn forward(&self, origin: usize) -> Option<usize> {
-        let end_src = self.src + self.width - 1;
-        if self.src <= origin && origin <= end_src {
-            return Some(origin - self.src + self.dst);
-        }
-        return None;
-    }
-
-    fn backward(&self, origin: usize) -> Option<usize> {
-        let end_dst = self.dst + self.width - 1;
-        if self.dst <= origin && origin <= end_dst {
-            return Some(origin - self.dst + self.src);
-        }
-
-        return None;
+    fn from_text(text: &str) -> aoc_utils::Result<MappingEntry> {
+        let values = text
+            .split(" ")
+            .map(parse::value::<usize>)
+            .collect::<aoc_utils::Result<Vec<_>>>()?;
+        let [dst, src, width] = values[..] else {
+            return Err(aoc_utils::Error::parse(text, "expected 3 numbers"));
+        };
+        Ok(MappingEntry { src, dst, width })
     }
 }
 
@@ -232,12 +171,14 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "313045984 20283860");
+        let result = aoc_utils::solution::execute::<Day5>().unwrap();
+        assert_eq!(result.part1, 313045984);
+        assert_eq!(result.part2, 20283860);
     }
 
     #[test]
     fn test_almanac_example() {
-        let example = Almanac::from_text("day5-example.txt");
+        let example = aoc_utils::input::parse("day5-example.txt", Almanac::from_lines).unwrap();
         assert_eq!(7, example.mappings.len());
         assert_eq!(4, example.seeds.len());
         assert_eq!(2, example.mappings[0].entries.len());
@@ -275,7 +216,7 @@ mod tests {
         text.push("50 98 2");
         text.push("52 50 48");
 
-        let mapping = Mapping::from_text(&text);
+        let mapping = Mapping::from_text(&text, 0).unwrap();
         assert_eq!(2, mapping.entries.len());
 
         for i in 0..50 - 1 {
@@ -290,58 +231,17 @@ mod tests {
         for i in 100..200 {
             assert_eq!(i, mapping.forward(i));
         }
-    }
-
-    #[test]
-    fn test_compress() {
-        type Range = Vec<(usize, usize)>;
-
-        assert_eq!(Range::from([]), compress(Range::from([])));
-        assert_eq!(Range::from([(0, 1)]), compress(Range::from([(0, 1)])));
-        assert_eq!(
-            Range::from([(0, 5)]),
-            compress(Range::from([(0, 2), (2, 3)]))
-        );
-        assert_eq!(
-            Range::from([(0, 2), (3, 3)]),
-            compress(Range::from([(0, 2), (3, 3)]))
-        );
-        assert_eq!(
-            Range::from([(0, 5)]),
-            compress(Range::from([(0, 2), (2, 1), (3, 2)]))
-        );
-        assert_eq!(
-            Range::from([(0, 5)]),
-            compress(Range::from([(0, 2), (3, 2), (2, 1)]))
-        );
-        assert_eq!(
-            Range::from([(0, 5)]),
-            compress(Range::from([(3, 2), (0, 2), (2, 1)]))
-        );
+        for i in 0..200 {
+            assert_eq!(i, mapping.backward(mapping.forward(i)));
+        }
+        assert!(mapping.is_bijection());
     }
 
     #[test]
     fn test_mapping_entry() {
-        let entry = MappingEntry::from_text("0 15 37");
+        let entry = MappingEntry::from_text("0 15 37").unwrap();
         assert_eq!(0usize, entry.dst);
         assert_eq!(15usize, entry.src);
         assert_eq!(37usize, entry.width);
-
-        for i in 0..15 - 1 {
-            assert_eq!(None, entry.forward(i));
-        }
-        for i in 15..15 + 37 {
-            assert_eq!(Some(i - 15 + 0), entry.forward(i));
-        }
-        for i in 15 + 37..100 {
-            assert_eq!(None, entry.forward(i));
-        }
-
-        for i in 0..37 - 1 {
-            assert_eq!(Some(i + 15 - 0), entry.backward(i));
-        }
-        for i in 37..100 {
-            assert_eq!(None, entry.backward(i));
-        }
     }
 }
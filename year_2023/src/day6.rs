@@ -1,14 +1,30 @@
+use aoc_utils::parse;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::iter::zip;
 
-pub fn execute() -> String {
-    let races = parse_races("day6.txt");
-    let part1 = optimize_races(&races);
+pub struct Day6;
 
-    let race = Race::from_file("day6.txt");
-    let (min, max) = race.optimize();
-    let part2 = max - min + 1;
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    /// The races, and the single race of part 2 ignoring the spaces.
+    type Input = (Vec<Race>, Race);
 
-    format!("{} {}", part1, part2)
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Ok((
+            parse_races(lines.to_vec())?,
+            Race::from_lines(lines.to_vec())?,
+        ))
+    }
+
+    fn part1((races, _race): &Self::Input) -> Answer {
+        optimize_races(races).into()
+    }
+
+    fn part2((_races, race): &Self::Input) -> Answer {
+        let (min, max) = race.optimize();
+        (max - min + 1).into()
+    }
 }
 
 fn optimize_races(races: &Vec<Race>) -> u64 {
@@ -18,31 +34,23 @@ fn optimize_races(races: &Vec<Race>) -> u64 {
         .map(|(min, max)| max - min + 1)
         .product();
 }
-fn parse_races(filename: &str) -> Vec<Race> {
-    let path = format!("input/{}", &filename);
-    let lines = aoc_utils::read_lines(&path);
-
-    let times_line = lines[0].clone();
-    let dists_line = lines[1].clone();
-
-    let (_times_header, times_str) = times_line.split_once(":").unwrap();
-    let (_dists_header, dists_str) = dists_line.split_once(":").unwrap();
-
-    let times = times_str
-        .trim()
-        .split_whitespace()
-        .map(str::parse::<u64>)
-        .map(Result::unwrap);
-    let dists = dists_str
-        .trim()
-        .split_whitespace()
-        .map(str::parse::<u64>)
-        .map(Result::unwrap);
-
-    return Vec::from_iter(zip(times, dists).map(|(time, dist)| Race { time, dist }));
+fn parse_races(lines: Vec<String>) -> aoc_utils::Result<Vec<Race>> {
+    let values = parse::lines(&lines[..2], |line| {
+        let (_header, values_str) = parse::split_once_or_err(line, ":")?;
+        values_str
+            .trim()
+            .split_whitespace()
+            .map(parse::value::<u64>)
+            .collect::<aoc_utils::Result<Vec<_>>>()
+    })?;
+    let (times, dists) = (&values[0], &values[1]);
+
+    return Ok(Vec::from_iter(
+        zip(times, dists).map(|(&time, &dist)| Race { time, dist }),
+    ));
 }
 
-struct Race {
+pub struct Race {
     time: u64,
     dist: u64,
 }
@@ -68,20 +76,16 @@ impl Race {
         return (min, max);
     }
 
-    fn from_file(filename: &str) -> Race {
-        let path = format!("input/{}", &filename);
-        let lines = aoc_utils::read_lines(&path);
-
-        let time_line = lines[0].clone();
-        let dist_line = lines[1].clone();
-
-        let (_time_header, time_str) = time_line.split_once(":").unwrap();
-        let (_dist_header, dist_str) = dist_line.split_once(":").unwrap();
-
-        let time = time_str.replace(" ", "").parse::<u64>().unwrap();
-        let dist = dist_str.replace(" ", "").parse::<u64>().unwrap();
+    fn from_lines(lines: Vec<String>) -> aoc_utils::Result<Race> {
+        let values = parse::lines(&lines[..2], |line| {
+            let (_header, value_str) = parse::split_once_or_err(line, ":")?;
+            parse::value::<u64>(&value_str.replace(" ", ""))
+        })?;
 
-        return Race { time, dist };
+        return Ok(Race {
+            time: values[0],
+            dist: values[1],
+        });
     }
 }
 
@@ -96,15 +100,17 @@ mod tests {
     use super::*;
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "633080 20048741");
+        let result = aoc_utils::solution::execute::<Day6>().unwrap();
+        assert_eq!(result.part1, 633080);
+        assert_eq!(result.part2, 20048741);
     }
 
     #[test]
     fn test_optimize_races() {
-        let races = parse_races("day6-example.txt");
+        let races = aoc_utils::input::parse("day6-example.txt", parse_races).unwrap();
         assert_eq!(288, optimize_races(&races));
 
-        let race = Race::from_file("day6-example.txt");
+        let race = aoc_utils::input::parse("day6-example.txt", Race::from_lines).unwrap();
         let (min, max) = race.optimize();
         assert_eq!(14, min);
         assert_eq!(71516, max);
@@ -113,7 +119,7 @@ mod tests {
 
     #[test]
     fn test_parse_races() {
-        let races = parse_races("day6-example.txt");
+        let races = aoc_utils::input::parse("day6-example.txt", parse_races).unwrap();
         assert_eq!(3, races.len());
 
         assert_eq!(7, races[0].time);
@@ -126,7 +132,7 @@ mod tests {
 
     #[test]
     fn test_parse_race_v2() {
-        let race = Race::from_file("day6-example.txt");
+        let race = aoc_utils::input::parse("day6-example.txt", Race::from_lines).unwrap();
         assert_eq!(71530, race.time);
         assert_eq!(940200, race.dist);
     }
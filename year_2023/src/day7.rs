@@ -1,16 +1,31 @@
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::ops::Index;
 use std::slice::Iter;
 
-pub fn execute() -> String {
-    let hands = Hand::from_file("day7.txt", false);
-    let part1 = score(hands);
+pub struct Day7;
 
-    let hands_with_jokers = Hand::from_file("day7.txt", true);
-    let part2 = score(hands_with_jokers);
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    /// The hands, without and with jokers.
+    type Input = (Vec<Hand>, Vec<Hand>);
 
-    format!("{} {}", part1, part2)
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Ok((
+            aoc_utils::parse::lines(lines, |line| Hand::from_text(line, false))?,
+            aoc_utils::parse::lines(lines, |line| Hand::from_text(line, true))?,
+        ))
+    }
+
+    fn part1((hands, _): &Self::Input) -> Answer {
+        score(hands.clone()).into()
+    }
+
+    fn part2((_, hands_with_jokers): &Self::Input) -> Answer {
+        score(hands_with_jokers.clone()).into()
+    }
 }
 
 type Score = u64;
@@ -24,32 +39,31 @@ fn score(mut hands: Vec<Hand>) -> Score {
         .sum()
 }
 
-#[derive(Debug, Eq)]
-struct Hand {
+#[derive(Debug, Clone, Eq)]
+pub struct Hand {
     cards: Vec<Card>,
     bid: Score,
     jokers: bool,
 }
 impl Hand {
-    fn from_file(filename: &str, jokers: bool) -> Vec<Self> {
-        let path = format!("input/{}", &filename);
-        aoc_utils::read_lines(&path)
-            .iter()
-            .map(|line| Hand::from_text(line, jokers))
-            .collect()
+    #[cfg(test)]
+    fn from_file(filename: &str, jokers: bool) -> aoc_utils::Result<Vec<Self>> {
+        aoc_utils::input::parse_lines(filename, |line| Hand::from_text(line, jokers))
     }
 
-    fn from_text(hand: &str, jokers: bool) -> Self {
-        let cards_text = &hand[0..5];
+    fn from_text(hand: &str, jokers: bool) -> aoc_utils::Result<Self> {
+        let (cards_text, bid_text) = hand.split_at_checked(5).unwrap_or((hand, ""));
         let cards = cards_text
             .chars()
             .map(|card| card_from_text(card, jokers))
-            .collect();
+            .collect::<aoc_utils::Result<Vec<_>>>()?;
+        if cards.len() != 5 {
+            return Err(aoc_utils::Error::parse(cards_text, "expected 5 cards"));
+        }
 
-        let bid_text = &hand[5..].trim();
-        let bid = bid_text.parse::<Score>().unwrap_or(0);
+        let bid = bid_text.trim().parse::<Score>().unwrap_or(0);
 
-        Hand { cards, bid, jokers }
+        Ok(Hand { cards, bid, jokers })
     }
 
     fn get_type(&self) -> HandType {
@@ -59,7 +73,7 @@ impl Hand {
             counts.insert(card_type, count);
         }
 
-        let joker = card_from_text('J', self.jokers);
+        let joker = card_from_text('J', self.jokers).unwrap();
 
         let mut counts_of_counts: Vec<usize> = counts
             .iter()
@@ -175,8 +189,8 @@ fn all_cards(jokers: bool) -> Iter<'static, Card> {
     }
 }
 
-fn card_from_text(card: char, jokers: bool) -> Card {
-    match card {
+fn card_from_text(card: char, jokers: bool) -> aoc_utils::Result<Card> {
+    let value = match card {
         '2' => 2,
         '3' => 3,
         '4' => 4,
@@ -197,9 +211,10 @@ fn card_from_text(card: char, jokers: bool) -> Card {
         'K' => 13,
         'A' => 14,
         _ => {
-            panic!("Not a card: {card}")
+            return Err(aoc_utils::Error::parse(&card.to_string(), "not a card"));
         }
-    }
+    };
+    Ok(value)
 }
 
 #[cfg(test)]
@@ -208,21 +223,23 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "253910319 254083736");
+        let result = aoc_utils::solution::execute::<Day7>().unwrap();
+        assert_eq!(result.part1, 253910319);
+        assert_eq!(result.part2, 254083736);
     }
 
     #[test]
     fn test_score() {
-        let hands = Hand::from_file("day7-example.txt", false);
+        let hands = Hand::from_file("day7-example.txt", false).unwrap();
         assert_eq!(6440, score(hands));
 
-        let hands_with_jokers = Hand::from_file("day7-example.txt", true);
+        let hands_with_jokers = Hand::from_file("day7-example.txt", true).unwrap();
         assert_eq!(5905, score(hands_with_jokers));
     }
 
     #[test]
     fn test_hands() {
-        let mut hands = Hand::from_file("day7-example.txt", false);
+        let mut hands = Hand::from_file("day7-example.txt", false).unwrap();
         assert_eq!(5, hands.len());
 
         hands.sort();
@@ -237,13 +254,13 @@ mod tests {
                 4 => "QQQJA",
                 _ => "Fail",
             };
-            assert_eq!(hand, &Hand::from_text(hand_text, false));
+            assert_eq!(hand, &Hand::from_text(hand_text, false).unwrap());
         }
     }
 
     #[test]
     fn test_hand() {
-        let hand1 = Hand::from_text("32T3K", false);
+        let hand1 = Hand::from_text("32T3K", false).unwrap();
         assert_eq!(5, hand1.cards.len());
         assert_eq!(hand1[0], 3);
         assert_eq!(hand1[1], 2);
@@ -255,17 +272,17 @@ mod tests {
 
         assert!(matches!(hand1.get_type(), HandType::OnePair));
 
-        let hand2 = Hand::from_text("T55J5", false);
+        let hand2 = Hand::from_text("T55J5", false).unwrap();
         assert!(matches!(hand2.get_type(), HandType::ThreeOfAKind));
 
-        let hand3 = Hand::from_text("KK677", false);
+        let hand3 = Hand::from_text("KK677", false).unwrap();
         assert!(matches!(hand3.get_type(), HandType::TwoPairs));
 
-        let hand4 = Hand::from_text("KTJJT 220", false);
+        let hand4 = Hand::from_text("KTJJT 220", false).unwrap();
         assert!(matches!(hand4.get_type(), HandType::TwoPairs));
         assert_eq!(220, hand4.bid);
 
-        let hand5 = Hand::from_text("QQQJA 48", false);
+        let hand5 = Hand::from_text("QQQJA 48", false).unwrap();
         assert!(matches!(hand5.get_type(), HandType::ThreeOfAKind));
         assert_eq!(48, hand5.bid);
 
@@ -288,31 +305,31 @@ mod tests {
 
     #[test]
     fn test_hand_with_jokers() {
-        let hand1 = Hand::from_text("32T3K", true);
+        let hand1 = Hand::from_text("32T3K", true).unwrap();
         assert!(matches!(hand1.get_type(), HandType::OnePair));
 
-        let hand2 = Hand::from_text("T55J5", true);
+        let hand2 = Hand::from_text("T55J5", true).unwrap();
         assert!(matches!(hand2.get_type(), HandType::FourOfAKind));
 
-        let hand3 = Hand::from_text("KK677", true);
+        let hand3 = Hand::from_text("KK677", true).unwrap();
         assert!(matches!(hand3.get_type(), HandType::TwoPairs));
 
-        let hand4 = Hand::from_text("KTJJT", true);
+        let hand4 = Hand::from_text("KTJJT", true).unwrap();
         assert!(matches!(hand4.get_type(), HandType::FourOfAKind));
 
-        let hand5 = Hand::from_text("QQQJA", true);
+        let hand5 = Hand::from_text("QQQJA", true).unwrap();
         assert!(matches!(hand5.get_type(), HandType::FourOfAKind));
 
-        let hand6a = Hand::from_text("J2AAA", true);
+        let hand6a = Hand::from_text("J2AAA", true).unwrap();
         assert!(matches!(hand6a.get_type(), HandType::FourOfAKind));
 
-        let hand6b = Hand::from_text("2JAAA", true);
+        let hand6b = Hand::from_text("2JAAA", true).unwrap();
         assert!(matches!(hand6b.get_type(), HandType::FourOfAKind));
 
         assert!(hand6a < hand6b);
 
         fn hand_type(hand_text: &str) -> HandType {
-            let hand = Hand::from_text(hand_text, true);
+            let hand = Hand::from_text(hand_text, true).unwrap();
             hand.get_type()
         }
 
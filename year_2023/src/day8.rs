@@ -1,11 +1,26 @@
+use aoc_utils::number_theory::lcm_all;
+use aoc_utils::parse;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::collections::HashMap;
 
-pub fn execute() -> String {
-    let map = Map::from_file("day8.txt");
-    let part1 = camel_steps(&map);
-    let part2 = ghost_steps(&map);
+pub struct Day8;
 
-    format!("{} {}", part1, part2)
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Input = Map;
+
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Map::from_lines(lines.to_vec())
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        camel_steps(map).into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        ghost_steps(map).into()
+    }
 }
 
 fn camel_steps(map: &Map) -> usize {
@@ -17,25 +32,10 @@ fn ghost_steps(map: &Map) -> u64 {
         .nodes
         .keys()
         .filter(|&k| k.ends_with("A"))
-        .map(|start| ghost_navigator(map, start.clone()).count() as u32)
+        .map(|start| ghost_navigator(map, start.clone()).count() as u64)
         .collect();
 
-    let prime_factors = periods.iter().map(aoc_utils::prime_factors);
-
-    let mut max_exponents = HashMap::new();
-    for factors in prime_factors {
-        for (number, exponent) in factors {
-            let max_exponent = max_exponents.entry(number).or_insert(0);
-            if exponent > *max_exponent {
-                *max_exponent = exponent;
-            }
-        }
-    }
-
-    max_exponents
-        .iter()
-        .map(|(&n, &exponent)| n.pow(exponent) as u64)
-        .product()
+    lcm_all(periods)
 }
 fn ghost_navigator(map: &Map, start: String) -> Navigator {
     let nav = map.navigator(start, None);
@@ -77,27 +77,28 @@ impl<'a> Iterator for Navigator<'a> {
     }
 }
 
-struct Map {
+pub struct Map {
     instructions: Vec<char>,
     nodes: HashMap<String, Node>,
 }
 
 impl Map {
-    fn from_file(filename: &str) -> Map {
-        let path = format!("input/{filename}");
-        let lines = aoc_utils::read_lines(&path);
+    #[cfg(test)]
+    fn from_file(filename: &str) -> aoc_utils::Result<Map> {
+        aoc_utils::input::parse(filename, Map::from_lines)
+    }
 
+    fn from_lines(lines: Vec<String>) -> aoc_utils::Result<Map> {
         let instructions = Vec::<char>::from_iter(lines[0].chars());
         let mut nodes = HashMap::<String, Node>::new();
-        for line in &lines[2..] {
-            let node = Node::from_line(line);
+        for node in parse::lines_at(&lines[2..], 2, Node::from_line)? {
             nodes.insert(node.name.clone(), node);
         }
 
-        Map {
+        Ok(Map {
             instructions,
             nodes,
-        }
+        })
     }
 
     fn navigator(&self, start: String, end: Option<String>) -> Navigator {
@@ -116,18 +117,15 @@ struct Node {
     right: String,
 }
 impl Node {
-    fn from_line(line: &str) -> Node {
-        let (name, directions) = line.split_once(" = ").unwrap();
-        let (left, right) = directions
-            .trim()
-            .trim_matches(|c| "()".contains(c))
-            .split_once(", ")
-            .unwrap();
-        Node {
+    fn from_line(line: &str) -> aoc_utils::Result<Node> {
+        let (name, directions) = parse::split_once_or_err(line, " = ")?;
+        let (left, right) =
+            parse::split_once_or_err(directions.trim().trim_matches(|c| "()".contains(c)), ", ")?;
+        Ok(Node {
             name: name.into(),
             left: left.into(),
             right: right.into(),
-        }
+        })
     }
 }
 
@@ -137,33 +135,35 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "19199 13663968099527");
+        let result = aoc_utils::solution::execute::<Day8>().unwrap();
+        assert_eq!(result.part1, 19199);
+        assert_eq!(result.part2, 13663968099527);
     }
 
     #[test]
     fn test_camel_steps() {
-        let map1 = Map::from_file("day8-example1.txt");
+        let map1 = Map::from_file("day8-example1.txt").unwrap();
         assert_eq!(2, camel_steps(&map1));
 
-        let map2 = Map::from_file("day8-example2.txt");
+        let map2 = Map::from_file("day8-example2.txt").unwrap();
         assert_eq!(6, camel_steps(&map2));
     }
 
     #[test]
     fn test_ghost_steps() {
-        let map3 = Map::from_file("day8-example3.txt");
+        let map3 = Map::from_file("day8-example3.txt").unwrap();
         assert_eq!(6, ghost_steps(&map3));
     }
 
     #[test]
     fn test_ghost_navigator() {
-        let map3 = Map::from_file("day8-example3.txt");
+        let map3 = Map::from_file("day8-example3.txt").unwrap();
         assert_eq!(2, ghost_navigator(&map3, "11A".into()).count());
         assert_eq!(3, ghost_navigator(&map3, "22A".into()).count());
     }
     #[test]
     fn test_navigator() {
-        let map1 = Map::from_file("day8-example1.txt");
+        let map1 = Map::from_file("day8-example1.txt").unwrap();
         let mut nav1 = map1.navigator("AAA".into(), Some("ZZZ".into()));
         assert_eq!(0, nav1.position);
         assert_eq!(Some(String::from("CCC")), nav1.next());
@@ -172,7 +172,7 @@ mod tests {
         assert_eq!(2, nav1.position);
         assert_eq!(None, nav1.next());
 
-        let map1 = Map::from_file("day8-example2.txt");
+        let map1 = Map::from_file("day8-example2.txt").unwrap();
         let mut nav2 = map1.navigator("AAA".into(), Some("ZZZ".into()));
         assert_eq!(0, nav2.position);
         assert_eq!(Some(String::from("BBB")), nav2.next());
@@ -192,14 +192,14 @@ mod tests {
 
     #[test]
     fn test_parse_map() {
-        let map1 = Map::from_file("day8-example1.txt");
+        let map1 = Map::from_file("day8-example1.txt").unwrap();
 
         assert_eq!(vec!['R', 'L'], map1.instructions);
         assert_eq!(7, map1.nodes.len());
         assert_eq!("ZZZ", map1.nodes["CCC"].left);
         assert_eq!("GGG", map1.nodes["CCC"].right);
 
-        let map2 = Map::from_file("day8-example2.txt");
+        let map2 = Map::from_file("day8-example2.txt").unwrap();
 
         assert_eq!(vec!['L', 'L', 'R'], map2.instructions);
         assert_eq!(3, map2.nodes.len());
@@ -209,12 +209,12 @@ mod tests {
 
     #[test]
     fn test_node() {
-        let a = Node::from_line("AAA = (BBB, CCC)");
+        let a = Node::from_line("AAA = (BBB, CCC)").unwrap();
         assert_eq!("AAA", a.name);
         assert_eq!("BBB", a.left);
         assert_eq!("CCC", a.right);
 
-        let a = Node::from_line("BBB = (DDD, EEE)");
+        let a = Node::from_line("BBB = (DDD, EEE)").unwrap();
         assert_eq!("BBB", a.name);
         assert_eq!("DDD", a.left);
         assert_eq!("EEE", a.right);
@@ -1,11 +1,24 @@
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::collections::VecDeque;
 
-pub fn execute() -> String {
-    let readings = Reading::from_file("day9.txt");
+pub struct Day9;
 
-    let (part1, part2) = extrapolate_all(readings);
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input = Vec<Reading>;
 
-    format!("{} {}", part1, part2)
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        aoc_utils::parse::lines(lines, Reading::from_line)
+    }
+
+    fn part1(readings: &Self::Input) -> Answer {
+        extrapolate_all(readings.clone()).0.into()
+    }
+
+    fn part2(readings: &Self::Input) -> Answer {
+        extrapolate_all(readings.clone()).1.into()
+    }
 }
 
 fn extrapolate_all(mut readings: Vec<Reading>) -> (Value, Value) {
@@ -23,30 +36,24 @@ fn extrapolate_all(mut readings: Vec<Reading>) -> (Value, Value) {
 
 type Value = i32;
 type Values = VecDeque<Value>;
-struct Reading {
+#[derive(Clone)]
+pub struct Reading {
     values: Values,
 }
 
 impl Reading {
-    fn from_file(filename: &str) -> Vec<Reading> {
-        let path = format!("input/{}", &filename);
-        let lines = aoc_utils::read_lines(&path);
-
-        lines
-            .iter()
-            .map(String::as_str)
-            .map(Reading::from_line)
-            .collect()
+    #[cfg(test)]
+    fn from_file(filename: &str) -> aoc_utils::Result<Vec<Reading>> {
+        aoc_utils::input::parse_lines(filename, Reading::from_line)
     }
 
-    fn from_line(line: &str) -> Reading {
+    fn from_line(line: &str) -> aoc_utils::Result<Reading> {
         let values = line
             .trim()
             .split_whitespace()
-            .map(str::parse::<Value>)
-            .map(Result::unwrap)
-            .collect();
-        Reading { values }
+            .map(aoc_utils::parse::value::<Value>)
+            .collect::<aoc_utils::Result<_>>()?;
+        Ok(Reading { values })
     }
 
     fn first(&self) -> Value {
@@ -105,25 +112,27 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "1772145754 867");
+        let result = aoc_utils::solution::execute::<Day9>().unwrap();
+        assert_eq!(result.part1, 1772145754);
+        assert_eq!(result.part2, 867);
     }
 
     #[test]
     fn test_extrapolate_all() {
-        let readings = Reading::from_file("day9-example.txt");
+        let readings = Reading::from_file("day9-example.txt").unwrap();
         assert_eq!((114, 2), extrapolate_all(readings));
     }
 
     #[test]
     fn test_extrapolate() {
-        let mut reading1 = Reading::from_line("0 3 6 9 12 15");
+        let mut reading1 = Reading::from_line("0 3 6 9 12 15").unwrap();
         reading1.extrapolate();
 
         assert_eq!(8, reading1.values.len());
         assert_eq!(18, reading1.values[7]);
         assert_eq!(-3, reading1.values[0]);
 
-        let mut reading2 = Reading::from_line("10 13 16 21 30 45");
+        let mut reading2 = Reading::from_line("10 13 16 21 30 45").unwrap();
         reading2.extrapolate();
 
         assert_eq!(8, reading2.values.len());
@@ -133,35 +142,35 @@ mod tests {
 
     #[test]
     fn test_derive_fully() {
-        let reading1 = Reading::from_line("0 3 6 9 12 15");
+        let reading1 = Reading::from_line("0 3 6 9 12 15").unwrap();
         assert_eq!(2, reading1.derive_fully().len());
 
-        let reading2 = Reading::from_line("1 3 6 10 15 21");
+        let reading2 = Reading::from_line("1 3 6 10 15 21").unwrap();
         assert_eq!(3, reading2.derive_fully().len());
 
-        let reading3 = Reading::from_line("10 13 16 21 30 45");
+        let reading3 = Reading::from_line("10 13 16 21 30 45").unwrap();
         assert_eq!(4, reading3.derive_fully().len());
     }
 
     #[test]
     fn test_is_zeros() {
-        let reading1 = Reading::from_line("0 3 6 9 12 15");
+        let reading1 = Reading::from_line("0 3 6 9 12 15").unwrap();
         assert_eq!(false, reading1.is_zeros());
-        let reading2 = Reading::from_line("0 0 0");
+        let reading2 = Reading::from_line("0 0 0").unwrap();
         assert_eq!(true, reading2.is_zeros());
-        let reading2 = Reading::from_line("");
+        let reading2 = Reading::from_line("").unwrap();
         assert_eq!(true, reading2.is_zeros());
     }
 
     #[test]
     fn test_derive_reading() {
-        let reading1 = Reading::from_line("0 3 6 9 12 15");
+        let reading1 = Reading::from_line("0 3 6 9 12 15").unwrap();
         let derivative1_1 = reading1.derive();
         assert_eq!(Values::from([3; 5]), derivative1_1.values);
         let derivative1_2 = derivative1_1.derive();
         assert_eq!(Values::from([0; 4]), derivative1_2.values);
 
-        let reading2 = Reading::from_line("1 3 6 10 15 21");
+        let reading2 = Reading::from_line("1 3 6 10 15 21").unwrap();
         let derivative2_1 = reading2.derive();
         assert_eq!(Values::from([2, 3, 4, 5, 6]), derivative2_1.values);
         let derivative2_2 = derivative2_1.derive();
@@ -172,7 +181,7 @@ mod tests {
 
     #[test]
     fn test_parse_readings() {
-        let readings = Reading::from_file("day9-example.txt");
+        let readings = Reading::from_file("day9-example.txt").unwrap();
         assert_eq!(3, readings.len());
         assert_eq!(Values::from([0, 3, 6, 9, 12, 15]), readings[0].values);
         assert_eq!(Values::from([1, 3, 6, 10, 15, 21]), readings[1].values);
@@ -181,7 +190,7 @@ mod tests {
 
     #[test]
     fn test_parse_reading() {
-        let reading = Reading::from_line("0 3 6 9 -12 15");
+        let reading = Reading::from_line("0 3 6 9 -12 15").unwrap();
         assert_eq!(Values::from([0, 3, 6, 9, -12, 15]), reading.values);
     }
 }
@@ -1,26 +1,35 @@
+use aoc_utils::parse;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::iter::zip;
 
-pub fn execute() -> String {
-    let mine = aoc_utils::read_lines("input/day1.txt");
-    let (list1, list2) = build_lists(mine.as_slice());
-    let part1 = total_distance(list1.as_slice(), list2.as_slice());
-    let part2 = similarity_score(list1.as_slice(), list2.as_slice());
+pub struct Day1;
 
-    format!("{} {}", part1, part2)
-}
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Input = (Vec<u32>, Vec<u32>);
 
-fn build_lists(lines: &[String]) -> (Vec<u32>, Vec<u32>) {
-    let mut list1 = Vec::new();
-    let mut list2 = Vec::new();
-    for line in lines {
-        let (str1, str2) = line.split_once(' ').unwrap();
-        let int1 = str1.trim().parse::<u32>().unwrap();
-        let int2 = str2.trim().parse::<u32>().unwrap();
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        build_lists(lines)
+    }
 
-        list1.push(int1);
-        list2.push(int2);
+    fn part1((list1, list2): &Self::Input) -> Answer {
+        total_distance(list1, list2).into()
+    }
+
+    fn part2((list1, list2): &Self::Input) -> Answer {
+        similarity_score(list1, list2).into()
     }
-    (list1, list2)
+}
+
+fn build_lists(lines: &[String]) -> aoc_utils::Result<(Vec<u32>, Vec<u32>)> {
+    let pairs = parse::lines(lines, |line| {
+        let (str1, str2) = parse::split_once_or_err(line, " ")?;
+        let int1 = parse::value::<u32>(str1.trim())?;
+        let int2 = parse::value::<u32>(str2.trim())?;
+        Ok((int1, int2))
+    })?;
+    Ok(pairs.into_iter().unzip())
 }
 
 fn count_in_list(number: u32, list: &[u32]) -> u32 {
@@ -56,12 +65,14 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), String::from("1506483 23126924"));
+        let result = aoc_utils::solution::execute::<Day1>().unwrap();
+        assert_eq!(result.part1, 1506483);
+        assert_eq!(result.part2, 23126924);
     }
 
     #[test]
     fn test_build_lists() {
-        let (list1, list2) = build_lists(_example().as_slice());
+        let (list1, list2) = build_lists(_example().as_slice()).unwrap();
         assert_eq!(list1.len(), 6);
         assert_eq!(list2.len(), 6);
 
@@ -69,15 +80,22 @@ mod tests {
         assert_eq!(list2, vec![4, 3, 5, 3, 9, 3]);
     }
 
+    #[test]
+    fn test_parts() {
+        let lists = Day1::parse(&_example()).unwrap();
+        assert_eq!(Day1::part1(&lists), 11);
+        assert_eq!(Day1::part2(&lists), 31);
+    }
+
     #[test]
     fn test_total_distance() {
-        let (list1, list2) = build_lists(_example().as_slice());
+        let (list1, list2) = build_lists(_example().as_slice()).unwrap();
         assert_eq!(total_distance(list1.as_slice(), list2.as_slice()), 11);
     }
 
     #[test]
     fn test_count_in_list() {
-        let (_list1, list2) = build_lists(_example().as_slice());
+        let (_list1, list2) = build_lists(_example().as_slice()).unwrap();
 
         assert_eq!(count_in_list(3, &list2), 3);
         assert_eq!(count_in_list(4, &list2), 1);
@@ -88,7 +106,7 @@ mod tests {
 
     #[test]
     fn test_similarity_score() {
-        let (list1, list2) = build_lists(_example().as_slice());
+        let (list1, list2) = build_lists(_example().as_slice()).unwrap();
         assert_eq!(similarity_score(list1.as_slice(), list2.as_slice()), 31);
     }
 
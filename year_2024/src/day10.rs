@@ -1,57 +1,41 @@
+use aoc_utils::grid::{Grid, Position};
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::collections::{HashMap, HashSet};
 
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day10.txt");
-    let topo = Topography::from_lines(&data);
+pub struct Day10;
 
-    let part1 = topo.count_all_reachable_peaks();
-    let part2 = topo.count_all_possible_routes();
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Topography;
 
-    format!("{} {}", part1, part2)
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Position(u32, u32);
-
-struct Topography {
-    size: u32,
-    altitudes: Vec<u32>,
-}
-impl Topography {
-    fn from_lines(lines: &Vec<String>) -> Topography {
-        let size = lines.len() as u32;
-
-        let mut result = Topography {
-            size,
-            altitudes: vec![0; (size * size) as usize],
-        };
-
-        for (j, line) in lines.iter().enumerate() {
-            assert_eq!(line.len(), size as usize);
-
-            for (i, c) in line.chars().enumerate() {
-                let altitude = c.to_digit(10).unwrap();
-                let position = Position(i as u32, j as u32);
-                let offset = result.offset(position);
-                result.altitudes[offset] = altitude;
-            }
-        }
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Topography::from_lines(&lines.to_vec())
+    }
 
-        result
+    fn part1(topo: &Self::Input) -> Answer {
+        topo.count_all_reachable_peaks().into()
     }
 
-    fn altitude(&self, position: Position) -> u32 {
-        self.altitudes[self.offset(position)]
+    fn part2(topo: &Self::Input) -> Answer {
+        topo.count_all_possible_routes().into()
     }
+}
 
-    fn offset(&self, position: Position) -> usize {
-        position.0 as usize * self.size as usize + position.1 as usize
+pub struct Topography {
+    altitudes: Grid<u32>,
+}
+impl Topography {
+    fn from_lines(lines: &Vec<String>) -> aoc_utils::Result<Topography> {
+        let altitudes = Grid::from_lines(lines, |c| {
+            c.to_digit(10)
+                .ok_or_else(|| aoc_utils::Error::parse(&c.to_string(), "not a digit"))
+        })?;
+        Ok(Topography { altitudes })
     }
 
-    fn position(&self, offset: usize) -> Position {
-        let j = offset % self.size as usize;
-        let i = offset / self.size as usize;
-        Position(i as u32, j as u32)
+    fn altitude(&self, position: Position) -> u32 {
+        self.altitudes[position]
     }
 
     fn count_all_reachable_peaks(&self) -> usize {
@@ -79,24 +63,11 @@ impl Topography {
     }
 
     fn next_reachable(&self, from: Position) -> Vec<Position> {
-        let mut result = vec![];
-
-        let offset = self.offset(from);
         let altitude = self.altitude(from) + 1;
-
-        if from.0 > 0 && self.altitudes[offset - self.size as usize] == altitude {
-            result.push(Position(from.0 - 1, from.1));
-        }
-        if from.0 < self.size - 1 && self.altitudes[offset + self.size as usize] == altitude {
-            result.push(Position(from.0 + 1, from.1));
-        }
-        if from.1 > 0 && self.altitudes[offset - 1] == altitude {
-            result.push(Position(from.0, from.1 - 1));
-        }
-        if from.1 < self.size - 1 && self.altitudes[offset + 1] == altitude {
-            result.push(Position(from.0, from.1 + 1));
-        }
-        result
+        self.altitudes
+            .neighbours4(from)
+            .filter(|&position| self.altitude(position) == altitude)
+            .collect()
     }
 
     fn count_all_possible_routes(&self) -> usize {
@@ -132,11 +103,7 @@ impl Topography {
     }
 
     fn trailheads(&self) -> Vec<Position> {
-        self.altitudes
-            .iter()
-            .enumerate()
-            .filter_map(|(i, &alt)| (alt == 0).then_some(self.position(i)))
-            .collect()
+        self.altitudes.find_all(&0).collect()
     }
 }
 
@@ -146,38 +113,41 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "582 1302");
+        let result = aoc_utils::solution::execute::<Day10>().unwrap();
+        assert_eq!(result.part1, 582);
+        assert_eq!(result.part2, 1302);
     }
 
     #[test]
     fn test_from_lines() {
-        let topo = Topography::from_lines(&_example());
-
-        assert_eq!(topo.size, 8);
-        assert_eq!(topo.altitude(Position(0, 0)), 8);
-        assert_eq!(topo.altitude(Position(1, 1)), 8);
-        assert_eq!(topo.altitude(Position(1, 0)), 9);
-        assert_eq!(topo.altitude(Position(0, 1)), 7);
-        assert_eq!(topo.altitude(Position(0, 6)), 0);
-        assert_eq!(topo.altitude(Position(0, 7)), 1);
-        assert_eq!(topo.altitude(Position(6, 0)), 2);
-        assert_eq!(topo.altitude(Position(7, 0)), 3);
-        assert_eq!(topo.altitude(Position(6, 6)), 0);
-        assert_eq!(topo.altitude(Position(7, 7)), 2);
+        let topo = Topography::from_lines(&_example()).unwrap();
+
+        assert_eq!(topo.altitudes.width(), 8);
+        assert_eq!(topo.altitudes.height(), 8);
+        assert_eq!(topo.altitude((0, 0)), 8);
+        assert_eq!(topo.altitude((1, 1)), 8);
+        assert_eq!(topo.altitude((1, 0)), 9);
+        assert_eq!(topo.altitude((0, 1)), 7);
+        assert_eq!(topo.altitude((0, 6)), 0);
+        assert_eq!(topo.altitude((0, 7)), 1);
+        assert_eq!(topo.altitude((6, 0)), 2);
+        assert_eq!(topo.altitude((7, 0)), 3);
+        assert_eq!(topo.altitude((6, 6)), 0);
+        assert_eq!(topo.altitude((7, 7)), 2);
     }
 
     #[test]
     fn test_reachable_peaks() {
-        let topo = Topography::from_lines(&_example());
+        let topo = Topography::from_lines(&_example()).unwrap();
 
-        assert_eq!(topo.reachable_peaks(Position(2, 0)).len(), 5);
+        assert_eq!(topo.reachable_peaks((2, 0)).len(), 5);
         assert_eq!(topo.count_all_reachable_peaks(), 36);
     }
     #[test]
     fn test_possible_routes() {
-        let topo = Topography::from_lines(&_example());
+        let topo = Topography::from_lines(&_example()).unwrap();
 
-        let possible_routes = topo.possible_routes(Position(2, 0));
+        let possible_routes = topo.possible_routes((2, 0));
         assert_eq!(possible_routes.len(), 5);
         assert_eq!(
             possible_routes
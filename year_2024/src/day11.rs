@@ -1,13 +1,26 @@
+use aoc_utils::num;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::collections::HashMap;
-use std::fs::read_to_string;
 
-pub fn execute() -> String {
-    let data = read_to_string("input/day11.txt").unwrap();
+pub struct Day11;
 
-    let part1 = blink_many_times(data.clone(), 25);
-    let part2 = blink_many_times(data.clone(), 75);
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    /// The engraved stones.
+    type Input = String;
 
-    format!("{} {}", part1, part2)
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Ok(lines.join("\n"))
+    }
+
+    fn part1(stones: &Self::Input) -> Answer {
+        blink_many_times(stones.clone(), 25).into()
+    }
+
+    fn part2(stones: &Self::Input) -> Answer {
+        blink_many_times(stones.clone(), 75).into()
+    }
 }
 
 type Cache = HashMap<(String, u16), u128>;
@@ -33,13 +46,18 @@ fn _actual_process_part(input: &String) -> Vec<String> {
         if i.is_err() {
             println!("{}", input);
         }
-        result.push(format!("{}", i.unwrap() * 2024).to_string());
+        let engraved = i
+            .unwrap()
+            .checked_mul(2024)
+            .expect("Stones should fit in a usize");
+        result.push(format!("{}", engraved).to_string());
     }
     result
 }
 
 fn process_arrangement_deep(input: &Vec<String>, depth: u16, cache: &mut Cache) -> u128 {
-    input.iter().map(|s| process_deep(s, depth, cache)).sum()
+    let counts = input.iter().map(|s| process_deep(s, depth, cache));
+    num::checked_sum(counts).expect("The count of stones should fit in a u128")
 }
 
 fn process_deep(input: &String, depth: u16, cache: &mut Cache) -> u128 {
@@ -52,10 +70,11 @@ fn process_deep(input: &String, depth: u16, cache: &mut Cache) -> u128 {
             1
         } else {
             let parts = process_part(input);
-            let result = parts
+            let counts = parts
                 .into_iter()
-                .map(|part| process_deep(&part, depth - 1, cache))
-                .sum();
+                .map(|part| process_deep(&part, depth - 1, cache));
+            let result =
+                num::checked_sum(counts).expect("The count of stones should fit in a u128");
 
             cache.insert(cache_key, result);
 
@@ -77,7 +96,9 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "186175 220566831337810");
+        let result = aoc_utils::solution::execute::<Day11>().unwrap();
+        assert_eq!(result.part1, 186175);
+        assert_eq!(result.part2, 220566831337810);
     }
 
     #[test]
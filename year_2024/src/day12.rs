@@ -1,14 +1,25 @@
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::collections::{HashMap, VecDeque};
 
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day12.txt");
-    let plots = from_lines(&data);
-    let areas = to_areas(&plots);
+pub struct Day12;
 
-    let part1 = calculate_cost_part1(&areas);
-    let part2 = calculate_cost_part2(&areas);
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    /// The plots, numbered by the area they belong to.
+    type Input = Vec<Vec<u16>>;
 
-    format!("{} {}", part1, part2)
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Ok(to_areas(&from_lines(&lines.to_vec())))
+    }
+
+    fn part1(areas: &Self::Input) -> Answer {
+        calculate_cost_part1(areas).into()
+    }
+
+    fn part2(areas: &Self::Input) -> Answer {
+        calculate_cost_part2(areas).into()
+    }
 }
 
 fn from_lines(lines: &Vec<String>) -> Vec<Vec<char>> {
@@ -166,7 +177,9 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "1477924 841934");
+        let result = aoc_utils::solution::execute::<Day12>().unwrap();
+        assert_eq!(result.part1, 1477924);
+        assert_eq!(result.part2, 841934);
     }
 
     #[test]
@@ -217,9 +230,9 @@ mod tests {
     }
 
     fn example2() -> Vec<String> {
-        aoc_utils::read_lines("input/day12-example2.txt")
+        aoc_utils::read_lines("input/day12-example2.txt").unwrap()
     }
     fn example3() -> Vec<String> {
-        aoc_utils::read_lines("input/day12-example3.txt")
+        aoc_utils::read_lines("input/day12-example3.txt").unwrap()
     }
 }
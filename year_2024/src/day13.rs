@@ -1,13 +1,27 @@
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day13.txt");
-    let mut machines = ClawMachine::many_from_lines(data);
+use aoc_utils::num::{self, Rational};
+use aoc_utils::parse;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 
-    let part1 = min_tokens(&machines);
+pub struct Day13;
 
-    machines.iter_mut().for_each(|m| m.fix_prize());
-    let part2 = min_tokens(&machines);
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<ClawMachine>;
 
-    format!("{} {}", part1, part2)
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        ClawMachine::many_from_lines(lines.to_vec())
+    }
+
+    fn part1(machines: &Self::Input) -> Answer {
+        min_tokens(machines).into()
+    }
+
+    fn part2(machines: &Self::Input) -> Answer {
+        let mut machines = machines.clone();
+        machines.iter_mut().for_each(|m| m.fix_prize());
+        min_tokens(&machines).into()
+    }
 }
 
 type Dimension = i64;
@@ -15,75 +29,61 @@ type Dimension = i64;
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 struct Coord(Dimension, Dimension);
 
-impl Coord {
-    fn from_text(s: &str) -> Coord {
-        let parts = s.split_once(", ").unwrap();
-
-        assert!(parts.0.starts_with("X"));
-        assert!(parts.1.starts_with("Y"));
-
-        let x = parts.0[2..].parse::<Dimension>().unwrap();
-        let y = parts.1[2..].parse::<Dimension>().unwrap();
-
-        Coord(x, y)
-    }
-}
-
 impl PartialEq<(Dimension, Dimension)> for Coord {
     fn eq(&self, other: &(Dimension, Dimension)) -> bool {
         self.0 == other.0 && self.1 == other.1
     }
 }
 
-struct ClawMachine {
+#[derive(Clone)]
+pub struct ClawMachine {
     a: Coord,
     b: Coord,
     p: Coord,
 }
 
 impl ClawMachine {
-    fn from_lines(block: &[String]) -> Self {
-        assert_eq!(block.len(), 3);
-        let mut pairs = ["Button A", "Button B", "Prize"]
-            .into_iter()
-            .zip(block)
-            .map(|(label, line)| {
-                let full_label = format!("{}: ", label);
-                assert!(line.starts_with(&full_label));
-                Coord::from_text(&line[full_label.len()..])
-            })
-            .collect::<Vec<_>>();
-        let p = pairs.pop().unwrap();
-        let b = pairs.pop().unwrap();
-        let a = pairs.pop().unwrap();
-
-        assert_ne!(a.0 * b.1, a.1 * b.0);
-
-        Self { a, b, p }
+    fn from_lines(block: &[String], offset: usize) -> aoc_utils::Result<Self> {
+        const PATTERNS: [&str; 3] = [
+            "Button A: X+{}, Y+{}",
+            "Button B: X+{}, Y+{}",
+            "Prize: X={}, Y={}",
+        ];
+        let first = block.first().map_or("", String::as_str);
+        if block.len() != PATTERNS.len() {
+            let message = format!("expected {} lines, found {}", PATTERNS.len(), block.len());
+            return Err(aoc_utils::Error::parse(first, message).at_line(offset + 1, first));
+        }
+        let coord = |i: usize| {
+            let line = &block[i];
+            parse::scan(PATTERNS[i], line)
+                .map(|[x, y]| Coord(x, y))
+                .map_err(|error| error.at_line(offset + i + 1, line))
+        };
+        let (a, b, p) = (coord(0)?, coord(1)?, coord(2)?);
+
+        if a.0 * b.1 == a.1 * b.0 {
+            return Err(aoc_utils::Error::parse(first, "the buttons are collinear")
+                .at_line(offset + 1, first));
+        }
+
+        Ok(Self { a, b, p })
     }
 
-    fn many_from_lines(lines: Vec<String>) -> Vec<ClawMachine> {
-        lines
-            .split(|l| l.trim().is_empty())
-            .map(Self::from_lines)
+    fn many_from_lines(lines: Vec<String>) -> aoc_utils::Result<Vec<ClawMachine>> {
+        parse::paragraphs(&lines)
+            .map(|(offset, block)| Self::from_lines(block, offset))
             .collect()
     }
 
     fn find_presses(&self) -> Option<(Dimension, Dimension)> {
-        let a = self.a;
-        let b = self.b;
-        let p = self.p;
-
-        let a_den = b.0 * p.1 - b.1 * p.0;
-        let a_num = b.0 * a.1 - b.1 * a.0;
-        if a_den % a_num == 0 {
-            let a_presses = a_den / a_num;
-            let b_den = p.0 - a.0 * a_presses;
-            if b_den % b.0 == 0 {
-                return Some((a_presses, b_den / b.0));
-            }
-        }
-        None
+        let exact = |n: Dimension| Rational::from(n as i128);
+        let [a, b, p] = [self.a, self.b, self.p].map(|c| [exact(c.0), exact(c.1)]);
+        let [a_presses, b_presses] = num::solve([[a[0], b[0]], [a[1], b[1]]], p)?;
+        Some((
+            a_presses.to_integer()? as Dimension,
+            b_presses.to_integer()? as Dimension,
+        ))
     }
 
     fn fix_prize(&mut self) {
@@ -106,12 +106,14 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "26599 106228669504887");
+        let result = aoc_utils::solution::execute::<Day13>().unwrap();
+        assert_eq!(result.part1, 26599);
+        assert_eq!(result.part2, 106228669504887);
     }
 
     #[test]
     fn test_min_tokens() {
-        let mut machines = ClawMachine::many_from_lines(_example());
+        let mut machines = ClawMachine::many_from_lines(_example()).unwrap();
         assert_eq!(min_tokens(&machines), 480);
 
         machines.iter_mut().for_each(|m| m.fix_prize());
@@ -120,7 +122,7 @@ mod tests {
 
     #[test]
     fn test_find_presses() {
-        let machines = ClawMachine::many_from_lines(_example());
+        let machines = ClawMachine::many_from_lines(_example()).unwrap();
         assert_eq!(machines[0].find_presses(), Some((80, 40)));
         assert_eq!(machines[1].find_presses(), None);
         assert_eq!(machines[2].find_presses(), Some((38, 86)));
@@ -133,7 +135,7 @@ mod tests {
             "Button B: X+22, Y+67".to_string(),
             "Prize: X=8400, Y=5400".to_string(),
         ];
-        let machine = ClawMachine::from_lines(block);
+        let machine = ClawMachine::from_lines(block, 0).unwrap();
         assert_eq!(machine.a, (94, 34));
         assert_eq!(machine.b, (22, 67));
         assert_eq!(machine.p, (8400, 5400));
@@ -141,14 +143,46 @@ mod tests {
 
     #[test]
     fn test_many_from_lines() {
-        let machines = ClawMachine::many_from_lines(_example());
+        let machines = ClawMachine::many_from_lines(_example()).unwrap();
         assert_eq!(machines.len(), 4);
         assert_eq!(machines[0].p, (8400, 5400));
         assert_eq!(machines[1].a, (26, 66));
         assert_eq!(machines[3].b, (27, 71));
     }
 
+    #[test]
+    fn test_parse_errors() {
+        let lines = |lines: &[&str]| lines.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let error = |lines| {
+            ClawMachine::many_from_lines(lines)
+                .err()
+                .unwrap()
+                .to_string()
+        };
+
+        assert_eq!(
+            error(lines(&["", "Button A: X+1, Y+2", "Button B: X+3, Y+4"])),
+            "2: expected 3 lines, found 2: `Button A: X+1, Y+2`"
+        );
+        assert_eq!(
+            error(lines(&[
+                "Button A: X+1, Y+2",
+                "Button C: X+3, Y+4",
+                "Prize: X=5, Y=6"
+            ])),
+            "2: expected `Button B: X+` at the start: `Button C: X+3, Y+4`"
+        );
+        assert_eq!(
+            error(lines(&[
+                "Button A: X+1, Y+2",
+                "Button B: X+2, Y+4",
+                "Prize: X=5, Y=6"
+            ])),
+            "1: the buttons are collinear: `Button A: X+1, Y+2`"
+        );
+    }
+
     fn _example() -> Vec<String> {
-        aoc_utils::read_lines("input/day13-example.txt")
+        aoc_utils::read_lines("input/day13-example.txt").unwrap()
     }
 }
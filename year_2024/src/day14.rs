@@ -1,24 +1,36 @@
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day14.txt");
+use aoc_utils::parallel;
+use aoc_utils::parse;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 
-    let mut lobby = Lobby::from_lines(data.clone(), Vector(101, 103));
-    lobby.progress(100);
-    let part1 = lobby.safety_factor();
+pub struct Day14;
 
-    let mut lobby = Lobby::from_lines(data, Vector(101, 103));
-    let start = 5000;
-    let end = 10000;
-    lobby.progress(start);
-    let mut scores = vec![];
-    for i in start..end {
-        scores.push((i, lobby.score()));
-        lobby.progress(1);
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Lobby;
+
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Lobby::from_lines(lines.to_vec(), Vector(101, 103))
+    }
+
+    fn part1(initial: &Self::Input) -> Answer {
+        let mut lobby = initial.clone();
+        lobby.progress(100);
+        lobby.safety_factor().into()
     }
-    scores.sort_by_key(|&(i, score)| (score, -i));
 
-    let part2 = scores.last().unwrap().0;
+    fn part2(initial: &Self::Input) -> Answer {
+        let start = 5000;
+        let end = 10000;
+        let mut scores = parallel::map((start..end).collect(), |i| {
+            let mut lobby = initial.clone();
+            lobby.progress(i);
+            (i, lobby.score())
+        });
+        scores.sort_by_key(|&(i, score)| (score, -i));
 
-    format!("{} {}", part1, part2)
+        scores.last().unwrap().0.into()
+    }
 }
 
 type Dimension = i32;
@@ -27,32 +39,25 @@ type Dimension = i32;
 struct Vector(Dimension, Dimension);
 
 impl Vector {
-    fn from_text(s: &str) -> Vector {
-        let parts = s.split_once(",").unwrap();
-        let x = parts.0.parse::<Dimension>().unwrap();
-        let y = parts.1.parse::<Dimension>().unwrap();
-
-        Vector(x, y)
+    fn from_text(s: &str) -> aoc_utils::Result<Vector> {
+        let [x, y] = parse::scan("{},{}", s)?;
+        Ok(Vector(x, y))
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Robot {
     position: Vector,
     velocity: Vector,
 }
 
 impl Robot {
-    fn from_text(s: &str) -> Robot {
-        let (position_str, velocity_str) = s.split_once(" ").unwrap();
-
-        assert!(position_str.starts_with("p="));
-        assert!(velocity_str.starts_with("v="));
-
-        let position = Vector::from_text(&position_str[2..]);
-        let velocity = Vector::from_text(&velocity_str[2..]);
-
-        Robot { position, velocity }
+    fn from_text(s: &str) -> aoc_utils::Result<Robot> {
+        let [position, velocity] = parse::captures("p={} v={}", s)?;
+        Ok(Robot {
+            position: Vector::from_text(position)?,
+            velocity: Vector::from_text(velocity)?,
+        })
     }
 
     fn progress(&mut self, size: &Vector, seconds: Dimension) {
@@ -72,17 +77,18 @@ impl Robot {
     }
 }
 
-struct Lobby {
+#[derive(Clone)]
+pub struct Lobby {
     robots: Vec<Robot>,
     size: Vector,
 }
 
 impl Lobby {
-    fn from_lines(lines: Vec<String>, size: Vector) -> Lobby {
-        let robots = lines.iter().map(|line| Robot::from_text(line)).collect();
+    fn from_lines(lines: Vec<String>, size: Vector) -> aoc_utils::Result<Lobby> {
+        let robots = parse::lines(&lines, Robot::from_text)?;
         assert_eq!(size.0 % 2, 1);
         assert_eq!(size.1 % 2, 1);
-        Lobby { robots, size }
+        Ok(Lobby { robots, size })
     }
 
     fn progress(&mut self, seconds: Dimension) {
@@ -142,7 +148,9 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "225521010 7774");
+        let result = aoc_utils::solution::execute::<Day14>().unwrap();
+        assert_eq!(result.part1, 225521010);
+        assert_eq!(result.part2, 7774);
     }
 
     impl PartialEq<(Dimension, Dimension)> for Vector {
@@ -153,32 +161,32 @@ mod tests {
 
     #[test]
     fn test_coord_from_text() {
-        assert_eq!(Vector::from_text("0,0"), (0, 0));
-        assert_eq!(Vector::from_text("19,25"), (19, 25));
-        assert_eq!(Vector::from_text("-39,-15"), (-39, -15));
-        assert_eq!(Vector::from_text("7,-35"), (7, -35));
-        assert_eq!(Vector::from_text("-13,42"), (-13, 42));
+        assert_eq!(Vector::from_text("0,0").unwrap(), (0, 0));
+        assert_eq!(Vector::from_text("19,25").unwrap(), (19, 25));
+        assert_eq!(Vector::from_text("-39,-15").unwrap(), (-39, -15));
+        assert_eq!(Vector::from_text("7,-35").unwrap(), (7, -35));
+        assert_eq!(Vector::from_text("-13,42").unwrap(), (-13, 42));
     }
 
     #[test]
     fn test_robot_from_text() {
         let examples = _example();
 
-        assert_eq!(Robot::from_text(&examples[0]).position, (0, 4));
-        assert_eq!(Robot::from_text(&examples[1]).position, (6, 3));
-        assert_eq!(Robot::from_text(&examples[2]).position, (10, 3));
-        assert_eq!(Robot::from_text(&examples[11]).position, (9, 5));
+        assert_eq!(Robot::from_text(&examples[0]).unwrap().position, (0, 4));
+        assert_eq!(Robot::from_text(&examples[1]).unwrap().position, (6, 3));
+        assert_eq!(Robot::from_text(&examples[2]).unwrap().position, (10, 3));
+        assert_eq!(Robot::from_text(&examples[11]).unwrap().position, (9, 5));
 
-        assert_eq!(Robot::from_text(&examples[0]).velocity, (3, -3));
-        assert_eq!(Robot::from_text(&examples[1]).velocity, (-1, -3));
-        assert_eq!(Robot::from_text(&examples[2]).velocity, (-1, 2));
-        assert_eq!(Robot::from_text(&examples[11]).velocity, (-3, -3));
+        assert_eq!(Robot::from_text(&examples[0]).unwrap().velocity, (3, -3));
+        assert_eq!(Robot::from_text(&examples[1]).unwrap().velocity, (-1, -3));
+        assert_eq!(Robot::from_text(&examples[2]).unwrap().velocity, (-1, 2));
+        assert_eq!(Robot::from_text(&examples[11]).unwrap().velocity, (-3, -3));
     }
 
     #[test]
     fn test_robot_progress() {
         let size = Vector(11, 7);
-        let mut robot = Robot::from_text("p=2,4 v=2,-3");
+        let mut robot = Robot::from_text("p=2,4 v=2,-3").unwrap();
 
         assert_eq!(robot.position, Vector(2, 4));
         robot.progress(&size, 1);
@@ -192,7 +200,7 @@ mod tests {
     #[test]
     fn test_lobby() {
         let size = Vector(11, 7);
-        let mut example = Lobby::from_lines(_example(), size);
+        let mut example = Lobby::from_lines(_example(), size).unwrap();
 
         assert_eq!(example.robots.len(), 12);
         assert_eq!(example.robots[6].position, (7, 6));
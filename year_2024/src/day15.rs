@@ -1,16 +1,33 @@
+use aoc_utils::parse;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::collections::{HashSet, VecDeque};
 
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day15.txt");
-    let mut warehouse = Warehouse::from_lines(data.clone(), false);
-    while warehouse.progress() {}
-    let part1 = warehouse.checksum();
+pub struct Day15;
 
-    let mut warehouse2 = Warehouse::from_lines(data, true);
-    while warehouse2.progress() {}
-    let part2 = warehouse2.checksum();
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    /// The warehouse as in part 1, and as widened in part 2.
+    type Input = (Warehouse, Warehouse);
 
-    format!("{} {}", part1, part2)
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Ok((
+            Warehouse::from_lines(lines.to_vec(), false)?,
+            Warehouse::from_lines(lines.to_vec(), true)?,
+        ))
+    }
+
+    fn part1((warehouse, _): &Self::Input) -> Answer {
+        let mut warehouse = warehouse.clone();
+        while warehouse.progress() {}
+        warehouse.checksum().into()
+    }
+
+    fn part2((_, warehouse): &Self::Input) -> Answer {
+        let mut warehouse = warehouse.clone();
+        while warehouse.progress() {}
+        warehouse.checksum().into()
+    }
 }
 
 #[derive(Clone, Eq, PartialEq, Debug)]
@@ -21,7 +38,8 @@ enum Tile {
     BoxLeft,
     BoxRight,
 }
-struct Warehouse {
+#[derive(Clone)]
+pub struct Warehouse {
     size: (usize, usize),
     tiles: Vec<Vec<Tile>>,
     robot: (usize, usize),
@@ -31,48 +49,51 @@ struct Warehouse {
 }
 
 impl Warehouse {
-    fn from_lines(lines: Vec<String>, is_part2: bool) -> Warehouse {
-        let input_width = lines[0].len();
-        let mut tiles = vec![];
-
-        let mut robot = (0, 0);
-
-        let mut j = 0;
-        loop {
-            let line = &lines[j];
-            if line.is_empty() {
-                break;
-            }
-
-            assert_eq!(line.len(), input_width);
-
-            let row_iter = line.chars().enumerate().map(|(i, c)| match c {
-                '#' => Tile::Wall,
-                '.' => Tile::Floor,
-                'O' => Tile::Box,
-                '@' => {
-                    robot.0 = if is_part2 { i * 2 } else { i };
-                    robot.1 = j;
-                    Tile::Floor
+    fn from_lines(lines: Vec<String>, is_part2: bool) -> aoc_utils::Result<Warehouse> {
+        let mut blocks = parse::paragraphs(&lines);
+        let (_, map) = blocks
+            .next()
+            .ok_or_else(|| aoc_utils::Error::parse("", "empty warehouse"))?;
+        let input_width = map[0].len();
+
+        let mut robot = None;
+        let tiles = map
+            .iter()
+            .enumerate()
+            .map(|(j, line)| {
+                if line.len() != input_width {
+                    let message = format!("expected {} tiles", input_width);
+                    return Err(aoc_utils::Error::parse(line, message).at_line(j + 1, line));
                 }
-                _ => unreachable!("Should not be here but {:?}", (j, i)),
-            });
-
-            let row = if is_part2 {
-                row_iter
-                    .flat_map(|tile| match tile {
-                        Tile::Box => vec![Tile::BoxLeft, Tile::BoxRight],
-                        other => vec![other.clone(), other],
+                let row = line
+                    .chars()
+                    .enumerate()
+                    .map(|(i, c)| match c {
+                        '#' => Ok(Tile::Wall),
+                        '.' => Ok(Tile::Floor),
+                        'O' => Ok(Tile::Box),
+                        '@' => {
+                            robot = Some((if is_part2 { i * 2 } else { i }, j));
+                            Ok(Tile::Floor)
+                        }
+                        _ => Err(aoc_utils::Error::parse(&c.to_string(), "unknown tile")
+                            .at_line(j + 1, line)),
                     })
-                    .collect::<Vec<_>>()
-            } else {
-                row_iter.collect::<Vec<_>>()
-            };
-
-            tiles.push(row);
-
-            j += 1;
-        }
+                    .collect::<aoc_utils::Result<Vec<_>>>()?;
+
+                Ok(match is_part2 {
+                    true => row
+                        .into_iter()
+                        .flat_map(|tile| match tile {
+                            Tile::Box => vec![Tile::BoxLeft, Tile::BoxRight],
+                            other => vec![other.clone(), other],
+                        })
+                        .collect(),
+                    false => row,
+                })
+            })
+            .collect::<aoc_utils::Result<Vec<Vec<Tile>>>>()?;
+        let robot = robot.ok_or_else(|| aoc_utils::Error::parse("", "missing robot `@`"))?;
 
         let width = if is_part2 {
             input_width * 2
@@ -82,29 +103,29 @@ impl Warehouse {
         let size = (width, tiles.len());
 
         let mut instructions = vec![];
-        while j < lines.len() {
-            let line = &lines[j];
-
-            let instruction_block = line.chars().map(|c| match c {
-                '^' => (0, -1),
-                '>' => (1, 0),
-                'v' => (0, 1),
-                '<' => (-1, 0),
-                _ => unreachable!(),
-            });
-            instructions.extend(instruction_block);
-
-            j += 1;
+        for (offset, block) in blocks {
+            let moves = parse::lines_at(block, offset, |line| {
+                line.chars()
+                    .map(|c| match c {
+                        '^' => Ok((0, -1)),
+                        '>' => Ok((1, 0)),
+                        'v' => Ok((0, 1)),
+                        '<' => Ok((-1, 0)),
+                        _ => Err(aoc_utils::Error::parse(&c.to_string(), "unknown move")),
+                    })
+                    .collect::<aoc_utils::Result<Vec<_>>>()
+            })?;
+            instructions.extend(moves.into_iter().flatten());
         }
 
-        Warehouse {
+        Ok(Warehouse {
             size,
             tiles,
             robot,
             instructions,
             current: 0,
             is_part2,
-        }
+        })
     }
 
     fn push(
@@ -257,17 +278,19 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "1446158 1446175");
+        let result = aoc_utils::solution::execute::<Day15>().unwrap();
+        assert_eq!(result.part1, 1446158);
+        assert_eq!(result.part2, 1446175);
     }
 
     #[test]
     fn test_from_lines() {
-        let warehouse = Warehouse::from_lines(_example(), false);
+        let warehouse = Warehouse::from_lines(_example(), false).unwrap();
         assert_eq!(warehouse.size, (10, 10));
         assert_eq!(warehouse.instructions.len(), 700);
         assert_eq!(warehouse.robot, (4, 4));
 
-        let warehouse2 = Warehouse::from_lines(_example(), true);
+        let warehouse2 = Warehouse::from_lines(_example(), true).unwrap();
         assert_eq!(warehouse2.size, (20, 10));
         assert_eq!(warehouse2.instructions.len(), 700);
         assert_eq!(warehouse2.robot, (8, 4));
@@ -275,7 +298,7 @@ mod tests {
 
     #[test]
     fn test_push() {
-        let warehouse = Warehouse::from_lines(_example(), false);
+        let warehouse = Warehouse::from_lines(_example(), false).unwrap();
         assert_eq!(warehouse.push((1, 1), (1, 0)), Some(HashSet::from([])));
         assert_eq!(warehouse.push((1, 1), (0, 1)), Some(HashSet::from([])));
         assert_eq!(warehouse.push((1, 1), (-1, 0)), None);
@@ -297,7 +320,7 @@ mod tests {
 
     #[test]
     fn test_step() {
-        let mut warehouse = Warehouse::from_lines(_example(), false);
+        let mut warehouse = Warehouse::from_lines(_example(), false).unwrap();
         let state_0 = warehouse.tiles.clone();
 
         assert_eq!(warehouse.step((1, 1), (1, 0)), (2, 1));
@@ -339,15 +362,38 @@ mod tests {
 
     #[test]
     fn test_progress() {
-        let mut warehouse = Warehouse::from_lines(_example(), false);
+        let mut warehouse = Warehouse::from_lines(_example(), false).unwrap();
         while warehouse.progress() {}
         assert_eq!(warehouse.checksum(), 10092);
 
-        let mut warehouse2 = Warehouse::from_lines(_example(), true);
+        let mut warehouse2 = Warehouse::from_lines(_example(), true).unwrap();
         while warehouse2.progress() {}
         assert_eq!(warehouse2.checksum(), 9021);
     }
 
+    #[test]
+    fn test_parse_errors() {
+        let lines = |lines: &[&str]| lines.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let error = |lines| {
+            Warehouse::from_lines(lines, true)
+                .err()
+                .unwrap()
+                .to_string()
+        };
+
+        assert_eq!(error(lines(&["#@#", "#."])), "2: expected 3 tiles: `#.`");
+        assert_eq!(
+            error(lines(&["#@#", "#x#"])),
+            "2: unknown tile: `x` in `#x#`"
+        );
+        assert_eq!(error(lines(&["#.#", "", "<"])), "missing robot `@`: ``");
+        assert_eq!(
+            error(lines(&["#@#", "", "<^", "v?"])),
+            "4: unknown move: `?` in `v?`"
+        );
+        assert_eq!(error(lines(&[])), "empty warehouse: ``");
+    }
+
     impl Tile {
         fn to_char(&self) -> char {
             match self {
@@ -380,6 +426,6 @@ mod tests {
     }
 
     fn _example() -> Vec<String> {
-        aoc_utils::read_lines("input/day15-example.txt")
+        aoc_utils::read_lines("input/day15-example.txt").unwrap()
     }
 }
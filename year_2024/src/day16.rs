@@ -1,205 +1,117 @@
-use std::collections::{HashMap, HashSet, VecDeque};
-use std::ops::{Add, Sub};
+use aoc_utils::geometry::{Direction4, Point2};
+use aoc_utils::search::{self, Paths};
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
+use std::collections::HashSet;
 
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day16.txt");
+pub struct Day16;
 
-    let labirynth = Labyrinth::from_lines(data);
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input = Labyrinth;
 
-    let part1 = labirynth.shortest_route();
-    let part2 = labirynth.best_seats();
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Labyrinth::from_lines(lines.to_vec())
+    }
+
+    fn part1(labyrinth: &Self::Input) -> Answer {
+        labyrinth.shortest_route().into()
+    }
 
-    format!("{} {}", part1, part2)
+    fn part2(labyrinth: &Self::Input) -> Answer {
+        labyrinth.best_seats().into()
+    }
 }
 
-struct Labyrinth {
+pub struct Labyrinth {
     tiles: Vec<Vec<bool>>,
     start: Position,
     end: Position,
 }
 
 impl Labyrinth {
-    fn from_lines(lines: Vec<String>) -> Labyrinth {
-        let width = lines[0].len();
-        let mut start = Position(0, 0);
-        let mut end = Position(0, 0);
+    fn from_lines(lines: Vec<String>) -> aoc_utils::Result<Labyrinth> {
+        let width = lines.first().map_or(0, |line| line.len());
+        let mut start = None;
+        let mut end = None;
 
         let tiles = lines
             .iter()
             .enumerate()
             .map(|(j, line)| {
-                assert_eq!(line.len(), width);
+                if line.len() != width {
+                    let message = format!("expected {} tiles", width);
+                    return Err(aoc_utils::Error::parse(line, message).at_line(j + 1, line));
+                }
                 line.chars()
                     .enumerate()
                     .map(|(i, c)| match c {
                         'S' => {
-                            start = Position::from_usize(i, j);
-                            true
+                            start = Some(Point2::new(i as isize, j as isize));
+                            Ok(true)
                         }
                         'E' => {
-                            end = Position::from_usize(i, j);
-                            true
+                            end = Some(Point2::new(i as isize, j as isize));
+                            Ok(true)
                         }
-                        '.' => true,
-                        '#' => false,
-                        _ => unreachable!(),
+                        '.' => Ok(true),
+                        '#' => Ok(false),
+                        _ => Err(aoc_utils::Error::parse(&c.to_string(), "unknown tile")
+                            .at_line(j + 1, line)),
                     })
                     .collect()
             })
-            .collect::<Vec<Vec<bool>>>();
+            .collect::<aoc_utils::Result<Vec<Vec<bool>>>>()?;
 
-        Labyrinth { tiles, start, end }
+        let start = start.ok_or_else(|| aoc_utils::Error::parse("", "missing start `S`"))?;
+        let end = end.ok_or_else(|| aoc_utils::Error::parse("", "missing end `E`"))?;
+        Ok(Labyrinth { tiles, start, end })
     }
 
     fn tile(&self, pos: &Position) -> bool {
-        self.tiles[pos.1 as usize][pos.0 as usize]
+        self.tiles[pos.y as usize][pos.x as usize]
     }
 
     fn shortest_route(&self) -> usize {
-        let scores = self.dijkstra();
-        scores
-            .iter()
-            .filter_map(|((pos, _direction), score)| (*pos == self.end).then_some(*score))
-            .min()
-            .expect("We should always reach the end")
+        let (_end, score) = self
+            .search()
+            .goal()
+            .expect("We should always reach the end");
+        score
     }
 
     fn best_seats(&self) -> usize {
-        let scores = self.dijkstra();
-
-        let best_score = scores
-            .iter()
-            .filter_map(|((pos, _direction), score)| (*pos == self.end).then_some(*score))
-            .min()
-            .expect("We should always reach the end");
-
-        let best_ends = scores
-            .iter()
-            .filter_map(|((pos, direction), score)| {
-                (*pos == self.end && *score == best_score).then_some((*pos, *direction, *score))
-            })
-            .collect::<Vec<(Position, Direction, usize)>>();
-
-        let mut best_seats = HashSet::from([self.end]);
-        let mut queue = VecDeque::from_iter(best_ends.iter().cloned());
-
-        while let Some((pos, direction, score)) = queue.pop_front() {
-            let next = pos - direction.delta();
-            if scores
-                .get(&(next, direction))
-                .is_some_and(|&next_score| next_score + 1 == score)
-            {
-                queue.push_back((next.clone(), direction, score - 1));
-                best_seats.insert(next);
-            }
-
-            if scores
-                .get(&(pos, direction.rotate_left()))
-                .is_some_and(|&next_score| next_score + 1000 == score)
-            {
-                queue.push_back((pos, direction.rotate_left(), score - 1000));
-            }
-            if scores
-                .get(&(pos, direction.rotate_right()))
-                .is_some_and(|&next_score| next_score + 1000 == score)
-            {
-                queue.push_back((pos, direction.rotate_right(), score - 1000));
-            }
-        }
-
+        let paths = self.search();
+        let best_ends = paths.goals().to_vec();
+
+        let best_seats = paths
+            .nodes_on_paths_to(best_ends)
+            .into_iter()
+            .map(|(pos, _direction)| pos)
+            .collect::<HashSet<_>>();
         best_seats.len()
     }
 
-    fn dijkstra(&self) -> HashMap<(Position, Direction), usize> {
-        use Direction::*;
-
-        let mut scores = HashMap::<(Position, Direction), usize>::new();
-        let mut queue = VecDeque::new();
-        queue.push_back((self.start, East, 0usize));
-
-        while let Some((current, direction, score)) = queue.pop_front() {
-            let existing_score = scores.get(&(current, direction));
-            if existing_score.is_some_and(|prev| *prev <= score) {
-                continue;
-            }
-            scores.insert((current, direction), score);
-
-            if current == self.end {
-                continue;
-            }
-
-            let next = current + direction.delta();
-            if self.tile(&next) {
-                queue.push_back((next, direction, score + 1));
-            }
-            queue.push_back((current, direction.rotate_left(), score + 1000));
-            queue.push_back((current, direction.rotate_right(), score + 1000));
-        }
-        scores
-    }
-}
-
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-struct Position(isize, isize);
-
-impl Position {
-    fn from_usize(x: usize, y: usize) -> Position {
-        Position(x as isize, y as isize)
-    }
-}
-
-impl Add for Position {
-    type Output = Position;
-    fn add(self, rhs: Self) -> Self::Output {
-        Position(self.0 + rhs.0, self.1 + rhs.1)
+    fn search(&self) -> Paths<(Position, Direction4), usize> {
+        search::dijkstra(
+            [(self.start, Direction4::Right)],
+            |&(current, direction)| {
+                let mut next = vec![
+                    ((current, direction.turn_left()), 1000),
+                    ((current, direction.turn_right()), 1000),
+                ];
+                let ahead = current + direction.delta();
+                if self.tile(&ahead) {
+                    next.push(((ahead, direction), 1));
+                }
+                next
+            },
+            |&(current, _direction)| current == self.end,
+        )
     }
 }
 
-impl Sub for Position {
-    type Output = Position;
-    fn sub(self, rhs: Self) -> Self::Output {
-        Position(self.0 - rhs.0, self.1 - rhs.1)
-    }
-}
-
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
-enum Direction {
-    East,
-    West,
-    South,
-    North,
-}
-
-impl Direction {
-    fn delta(&self) -> Position {
-        use Direction::*;
-        match self {
-            East => Position(1, 0),
-            West => Position(-1, 0),
-            South => Position(0, 1),
-            North => Position(0, -1),
-        }
-    }
-
-    fn rotate_left(&self) -> Direction {
-        use Direction::*;
-        match self {
-            East => North,
-            West => South,
-            South => East,
-            North => West,
-        }
-    }
-    fn rotate_right(&self) -> Direction {
-        use Direction::*;
-        match self {
-            East => South,
-            West => North,
-            South => West,
-            North => East,
-        }
-    }
-}
+type Position = Point2<isize>;
 
 #[cfg(test)]
 mod tests {
@@ -207,15 +119,17 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "102488 559");
+        let result = aoc_utils::solution::execute::<Day16>().unwrap();
+        assert_eq!(result.part1, 102488);
+        assert_eq!(result.part2, 559);
     }
 
     #[test]
     fn test_from_lines() {
-        let lab = Labyrinth::from_lines(_example());
+        let lab = Labyrinth::from_lines(_example()).unwrap();
         assert_eq!(lab.tiles.len(), 15);
-        assert_eq!(lab.start, Position(1, 13));
-        assert_eq!(lab.end, Position(13, 1));
+        assert_eq!(lab.start, Point2::new(1, 13));
+        assert_eq!(lab.end, Point2::new(13, 1));
 
         assert_eq!(lab.tiles[0][0], false);
         assert_eq!(lab.tiles[1][1], true);
@@ -226,17 +140,30 @@ mod tests {
 
     #[test]
     fn test_shortest_route() {
-        let lab = Labyrinth::from_lines(_example());
+        let lab = Labyrinth::from_lines(_example()).unwrap();
         assert_eq!(lab.shortest_route(), 7036);
     }
 
     #[test]
     fn test_best_seats() {
-        let lab = Labyrinth::from_lines(_example());
+        let lab = Labyrinth::from_lines(_example()).unwrap();
         assert_eq!(lab.best_seats(), 45);
     }
 
+    #[test]
+    fn test_parse_errors() {
+        let lines = |lines: &[&str]| lines.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let error = |lines| Labyrinth::from_lines(lines).err().unwrap().to_string();
+
+        assert_eq!(error(lines(&["#S#", "#E"])), "2: expected 3 tiles: `#E`");
+        assert_eq!(
+            error(lines(&["#S#", "#x#"])),
+            "2: unknown tile: `x` in `#x#`"
+        );
+        assert_eq!(error(lines(&["#S#", "#.#"])), "missing end `E`: ``");
+    }
+
     fn _example() -> Vec<String> {
-        aoc_utils::read_lines("input/day16-example.txt")
+        aoc_utils::read_lines("input/day16-example.txt").unwrap()
     }
 }
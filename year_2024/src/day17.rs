@@ -1,18 +1,30 @@
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day17.txt");
+use aoc_utils::parse;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 
-    let mut program = Program::from_lines(data.clone());
-    program.execute();
-    let part1 = program.computer.format_outputs();
+pub struct Day17;
 
-    let program = Program::from_lines(data);
-    let part2 = find_program_reproduction(&program);
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input = Program;
 
-    format!("{} {}", part1, part2)
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Program::from_lines(lines.to_vec())
+    }
+
+    fn part1(initial: &Self::Input) -> Answer {
+        let mut program = initial.clone();
+        program.execute();
+        program.computer.format_outputs().into()
+    }
+
+    fn part2(program: &Self::Input) -> Answer {
+        find_program_reproduction(program).into()
+    }
 }
 
 #[derive(Clone)]
-struct Program {
+pub struct Program {
     computer: Computer,
     instructions: Vec<u8>,
 }
@@ -28,12 +40,17 @@ struct Computer {
 }
 
 impl Program {
-    fn from_lines(lines: Vec<String>) -> Program {
+    fn from_lines(lines: Vec<String>) -> aoc_utils::Result<Program> {
         assert_eq!(lines.len(), 5);
 
-        let reg_a = lines[0].split_once(": ").unwrap().1.parse().unwrap();
-        let reg_b = lines[1].split_once(": ").unwrap().1.parse().unwrap();
-        let reg_c = lines[2].split_once(": ").unwrap().1.parse().unwrap();
+        let register = |i: usize| {
+            parse::split_once_or_err(&lines[i], ": ")
+                .and_then(|(_, value)| parse::value(value))
+                .map_err(|error| error.at_line(i + 1, &lines[i]))
+        };
+        let reg_a = register(0)?;
+        let reg_b = register(1)?;
+        let reg_c = register(2)?;
 
         let computer = Computer {
             reg_a,
@@ -42,18 +59,14 @@ impl Program {
             outputs: Vec::new(),
         };
 
-        let instructions = lines[4]
-            .split_once(": ")
-            .unwrap()
-            .1
-            .split(",")
-            .map(|x| x.parse().unwrap())
-            .collect();
+        let instructions = parse::split_once_or_err(&lines[4], ": ")
+            .and_then(|(_, values)| values.split(",").map(parse::value).collect())
+            .map_err(|error| error.at_line(5, &lines[4]))?;
 
-        Program {
+        Ok(Program {
             computer,
             instructions,
-        }
+        })
     }
 
     fn execute(&mut self) {
@@ -185,12 +198,14 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "4,3,7,1,5,3,0,5,4 190384615275535");
+        let result = aoc_utils::solution::execute::<Day17>().unwrap();
+        assert_eq!(result.part1, "4,3,7,1,5,3,0,5,4");
+        assert_eq!(result.part2, 190384615275535);
     }
 
     #[test]
     fn test_from_lines() {
-        let program = Program::from_lines(example1());
+        let program = Program::from_lines(example1()).unwrap();
         assert_eq!(program.computer.reg_a, 729);
         assert_eq!(program.computer.reg_b, 0);
         assert_eq!(program.computer.reg_c, 0);
@@ -199,7 +214,7 @@ mod tests {
 
     #[test]
     fn test_execute() {
-        let mut program = Program::from_lines(example1());
+        let mut program = Program::from_lines(example1()).unwrap();
         program.execute();
         let result = program.computer.format_outputs();
 
@@ -208,16 +223,16 @@ mod tests {
 
     #[test]
     fn test_find_self_reproduction() {
-        let program = Program::from_lines(example2());
+        let program = Program::from_lines(example2()).unwrap();
         let result = find_program_reproduction(&program);
 
         assert_eq!(result, 117440);
     }
 
     fn example1() -> Vec<String> {
-        aoc_utils::read_lines("input/day17-example1.txt")
+        aoc_utils::read_lines("input/day17-example1.txt").unwrap()
     }
     fn example2() -> Vec<String> {
-        aoc_utils::read_lines("input/day17-example2.txt")
+        aoc_utils::read_lines("input/day17-example2.txt").unwrap()
     }
 }
@@ -1,33 +1,35 @@
-use std::collections::VecDeque;
+use aoc_utils::parse;
+use aoc_utils::search;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day18.txt");
+pub struct Day18;
 
-    let map = corruption_map_from_lines(data.clone(), 71, 1024);
-    let part1 = dijkstra(&map, 71, (0, 0), (70, 70)).unwrap();
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    /// The coordinates of the falling bytes, in order.
+    type Input = Vec<(usize, usize)>;
 
-    let coords = coords_from_lines(data);
-    let part2 = find_first_blocking_byte(coords, 71);
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        coords_from_lines(lines.to_vec())
+    }
 
-    format!("{} {},{}", part1, part2.0, part2.1)
-}
+    fn part1(coords: &Self::Input) -> Answer {
+        let map = corruption_map_from_coords(coords, 71, 1024);
+        dijkstra(&map, 71, (0, 0), (70, 70)).map_or(Answer::Unsolved, Answer::from)
+    }
 
-fn corruption_map_from_lines(lines: Vec<String>, size: usize, limit: usize) -> Vec<Vec<bool>> {
-    let coords = coords_from_lines(lines);
-    corruption_map_from_coords(&coords, size, limit)
+    fn part2(coords: &Self::Input) -> Answer {
+        let (x, y) = find_first_blocking_byte(coords.clone(), 71);
+        format!("{},{}", x, y).into()
+    }
 }
 
-fn coords_from_lines(lines: Vec<String>) -> Vec<(usize, usize)> {
-    lines
-        .iter()
-        .map(|l| {
-            let (x_str, y_str) = l.split_once(",").unwrap();
-            (
-                x_str.parse::<usize>().unwrap(),
-                y_str.parse::<usize>().unwrap(),
-            )
-        })
-        .collect::<Vec<_>>()
+fn coords_from_lines(lines: Vec<String>) -> aoc_utils::Result<Vec<(usize, usize)>> {
+    parse::lines(&lines, |l| {
+        let (x_str, y_str) = parse::split_once_or_err(l, ",")?;
+        Ok((parse::value::<usize>(x_str)?, parse::value::<usize>(y_str)?))
+    })
 }
 
 fn corruption_map_from_coords(
@@ -51,28 +53,16 @@ fn dijkstra(
     start: (usize, usize),
     end: (usize, usize),
 ) -> Option<usize> {
-    let mut queue = VecDeque::from([(start, 0)]);
-    let mut distance = vec![vec![usize::MAX; size]; size];
-
-    while let Some((pos, dist)) = queue.pop_front() {
-        if dist < distance[pos.1][pos.0] {
-            distance[pos.1][pos.0] = dist;
-        } else {
-            continue;
-        }
-
-        if pos == end {
-            break;
-        }
-
-        for next in valid_steps(pos, size) {
-            if !map[next.1][next.0] {
-                queue.push_back((next, dist + 1));
-            }
-        }
-    }
-
-    (distance[end.1][end.0] != usize::MAX).then_some(distance[end.1][end.0])
+    let paths = search::bfs(
+        [start],
+        |&pos| {
+            valid_steps(pos, size)
+                .into_iter()
+                .filter(|next| !map[next.1][next.0])
+        },
+        |&pos| pos == end,
+    );
+    paths.goal().map(|(_end, distance)| distance)
 }
 
 fn valid_steps(pos: (usize, usize), size: usize) -> Vec<(usize, usize)> {
@@ -115,21 +105,23 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "296 28,44");
+        let result = aoc_utils::solution::execute::<Day18>().unwrap();
+        assert_eq!(result.part1, 296);
+        assert_eq!(result.part2, "28,44");
     }
     #[test]
     fn test_dijkstra() {
-        let map = corruption_map_from_lines(example(), 7, 12);
+        let map = corruption_map_from_coords(&coords_from_lines(example()).unwrap(), 7, 12);
         assert_eq!(dijkstra(&map, 7, (0, 0), (6, 6)), Some(22));
     }
     #[test]
     fn test_find_first_blocking_byte() {
-        let coords = coords_from_lines(example());
+        let coords = coords_from_lines(example()).unwrap();
         assert_eq!(find_first_blocking_byte(coords.clone(), 7), (6, 1));
     }
     #[test]
     fn test_from_lines() {
-        let map = corruption_map_from_lines(example(), 7, 12);
+        let map = corruption_map_from_coords(&coords_from_lines(example()).unwrap(), 7, 12);
         for (j, row) in map.iter().enumerate() {
             for (i, pixel) in row.iter().enumerate() {
                 assert_eq!(
@@ -158,6 +150,6 @@ mod tests {
     }
 
     fn example() -> Vec<String> {
-        aoc_utils::read_lines("input/day18-example.txt")
+        aoc_utils::read_lines("input/day18-example.txt").unwrap()
     }
 }
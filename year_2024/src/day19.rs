@@ -1,15 +1,26 @@
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::collections::HashMap;
 
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day19.txt");
-    let (designs, patterns) = from_lines(data);
+pub struct Day19;
 
-    let part1 = count_possible_designs(&designs, &patterns);
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    /// The designs, and the available towel patterns.
+    type Input = (Vec<String>, Vec<String>);
 
-    let mut cache = HashMap::new();
-    let part2 = count_all_possible_designs(&designs, &patterns, &mut cache);
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Ok(from_lines(lines.to_vec()))
+    }
+
+    fn part1((designs, patterns): &Self::Input) -> Answer {
+        count_possible_designs(designs, patterns).into()
+    }
 
-    format!("{} {}", part1, part2)
+    fn part2((designs, patterns): &Self::Input) -> Answer {
+        let mut cache = HashMap::new();
+        count_all_possible_designs(designs, patterns, &mut cache).into()
+    }
 }
 
 fn from_lines(lines: Vec<String>) -> (Vec<String>, Vec<String>) {
@@ -84,7 +95,9 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "358 600639829400603");
+        let result = aoc_utils::solution::execute::<Day19>().unwrap();
+        assert_eq!(result.part1, 358);
+        assert_eq!(result.part2, 600639829400603);
     }
 
     #[test]
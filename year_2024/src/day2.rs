@@ -1,10 +1,24 @@
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day2.txt");
-    let reports = read_reports(data);
-    let part1 = count_safe(&reports, 0);
-    let part2 = count_safe(&reports, 1);
+use aoc_utils::parse;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 
-    format!("{} {}", part1, part2)
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        read_reports(lines.to_vec())
+    }
+
+    fn part1(reports: &Self::Input) -> Answer {
+        count_safe(reports, 0).into()
+    }
+
+    fn part2(reports: &Self::Input) -> Answer {
+        count_safe(reports, 1).into()
+    }
 }
 
 fn count_safe(reports: &Vec<Vec<i32>>, max_problems: u32) -> usize {
@@ -48,16 +62,10 @@ fn is_safe(list: &[i32], max_problems: u32) -> bool {
     true
 }
 
-fn read_reports(lines: Vec<String>) -> Vec<Vec<i32>> {
-    lines
-        .iter()
-        .map(|line| {
-            line.split(' ')
-                .into_iter()
-                .map(|part| part.parse::<i32>().unwrap())
-                .collect()
-        })
-        .collect()
+fn read_reports(lines: Vec<String>) -> aoc_utils::Result<Vec<Vec<i32>>> {
+    parse::lines(&lines, |line| {
+        line.split(' ').map(parse::value::<i32>).collect()
+    })
 }
 
 #[cfg(test)]
@@ -66,12 +74,14 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "282 349");
+        let result = aoc_utils::solution::execute::<Day2>().unwrap();
+        assert_eq!(result.part1, 282);
+        assert_eq!(result.part2, 349);
     }
 
     #[test]
     fn test_read_reports() {
-        let reports = read_reports(_example());
+        let reports = read_reports(_example()).unwrap();
         assert_eq!(reports.len(), 6);
         for report in reports.iter() {
             assert_eq!(report.len(), 5);
@@ -122,7 +132,7 @@ mod tests {
 
     #[test]
     fn test_count_safe() {
-        let examples = read_reports(_example());
+        let examples = read_reports(_example()).unwrap();
         assert_eq!(count_safe(&examples, 0), 2);
         assert_eq!(count_safe(&examples, 1), 4);
     }
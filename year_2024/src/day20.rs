@@ -1,18 +1,32 @@
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day20.txt");
-    let race = Race::from_lines(&data);
-    let path = race.find_path();
+use aoc_utils::search;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 
-    let cheats_part1 = race.find_cheats(&path, 2);
-    let part1 = count_cheats(&cheats_part1, 100, usize::MAX);
+pub struct Day20;
 
-    let cheats_part2 = race.find_cheats(&path, 20);
-    let part2 = count_cheats(&cheats_part2, 100, usize::MAX);
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    /// The race track, and the path through it.
+    type Input = (Race, Vec<Vec<usize>>);
 
-    format!("{} {}", part1, part2)
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        let race = Race::from_lines(&lines.to_vec());
+        let path = race.find_path();
+        Ok((race, path))
+    }
+
+    fn part1((race, path): &Self::Input) -> Answer {
+        let cheats = race.find_cheats(path, 2);
+        count_cheats(&cheats, 100, usize::MAX).into()
+    }
+
+    fn part2((race, path): &Self::Input) -> Answer {
+        let cheats = race.find_cheats(path, 20);
+        count_cheats(&cheats, 100, usize::MAX).into()
+    }
 }
 
-struct Race {
+pub struct Race {
     track: Vec<Vec<bool>>,
     start: (usize, usize),
     end: (usize, usize),
@@ -52,30 +66,29 @@ impl Race {
         Race { track, start, end }
     }
 
+    /// The distance of each cell of the track from the start along the race,
+    /// `usize::MAX` off the track.
     fn find_path(&self) -> Vec<Vec<usize>> {
-        let mut result = vec![vec![usize::MAX; self.track[0].len()]; self.track.len()];
-        result[self.start.1][self.start.0] = 0;
-
-        let mut prev = (0, 0);
-        let mut current = self.start;
-        let mut distance = 1;
-        while current != self.end {
-            for option in [
-                (current.0 - 1, current.1),
-                (current.0 + 1, current.1),
-                (current.0, current.1 - 1),
-                (current.0, current.1 + 1),
-            ] {
-                if option != prev && self.track[option.1][option.0] {
-                    result[option.1][option.0] = distance;
-                    prev = current;
-                    current = option;
-                    break;
-                }
-            }
-            distance += 1;
+        let (width, height) = (self.track[0].len(), self.track.len());
+        let paths = search::bfs(
+            [self.start],
+            |&(i, j)| {
+                [
+                    (i.wrapping_sub(1), j),
+                    (i + 1, j),
+                    (i, j.wrapping_sub(1)),
+                    (i, j + 1),
+                ]
+                .into_iter()
+                .filter(|&(i, j)| i < width && j < height && self.track[j][i])
+            },
+            |&position| position == self.end,
+        );
+
+        let mut result = vec![vec![usize::MAX; width]; height];
+        for (&(i, j), &distance) in paths.distances() {
+            result[j][i] = distance;
         }
-
         result
     }
 
@@ -156,7 +169,9 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "1411 1010263");
+        let result = aoc_utils::solution::execute::<Day20>().unwrap();
+        assert_eq!(result.part1, 1411);
+        assert_eq!(result.part2, 1010263);
     }
 
     #[test]
@@ -215,6 +230,6 @@ mod tests {
     }
 
     fn example() -> Vec<String> {
-        aoc_utils::read_lines("input/day20-example.txt")
+        aoc_utils::read_lines("input/day20-example.txt").unwrap()
     }
 }
@@ -1,13 +1,34 @@
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::collections::HashMap;
 use RemoteKey::*;
 
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day21.txt");
+pub struct Day21;
 
-    let part1: usize = data.iter().map(|code| score(code, 2)).sum();
-    let part2: usize = data.iter().map(|code| score(code, 25)).sum();
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    /// The codes to type.
+    type Input = Vec<String>;
 
-    format!("{} {}", part1, part2)
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Ok(lines.to_vec())
+    }
+
+    fn part1(codes: &Self::Input) -> Answer {
+        codes
+            .iter()
+            .map(|code| score(code, 2))
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(codes: &Self::Input) -> Answer {
+        codes
+            .iter()
+            .map(|code| score(code, 25))
+            .sum::<usize>()
+            .into()
+    }
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -151,7 +172,9 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "163086 198466286401228");
+        let result = aoc_utils::solution::execute::<Day21>().unwrap();
+        assert_eq!(result.part1, 163086);
+        assert_eq!(result.part2, 198466286401228);
     }
 
     #[test]
@@ -1,16 +1,29 @@
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::collections::HashMap;
 
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day22.txt");
-    let secrets = from_lines(data);
-    let part1 = part1(&secrets);
-    let part2 = part2(&secrets);
+pub struct Day22;
 
-    format!("{} {}", part1, part2)
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    /// The initial secrets of the buyers.
+    type Input = Vec<u32>;
+
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        from_lines(lines.to_vec())
+    }
+
+    fn part1(secrets: &Self::Input) -> Answer {
+        part1(secrets).into()
+    }
+
+    fn part2(secrets: &Self::Input) -> Answer {
+        part2(secrets).into()
+    }
 }
 
-fn from_lines(lines: Vec<String>) -> Vec<u32> {
-    lines.iter().map(|line| line.parse().unwrap()).collect()
+fn from_lines(lines: Vec<String>) -> aoc_utils::Result<Vec<u32>> {
+    aoc_utils::parse::lines(&lines, aoc_utils::parse::value)
 }
 
 fn next(secret: u32) -> u32 {
@@ -95,18 +108,20 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "12979353889 1449");
+        let result = aoc_utils::solution::execute::<Day22>().unwrap();
+        assert_eq!(result.part1, 12979353889);
+        assert_eq!(result.part2, 1449);
     }
 
     #[test]
     fn test_part1() {
-        let secrets = from_lines(example1());
+        let secrets = from_lines(example1()).unwrap();
         assert_eq!(part1(&secrets), 37327623);
     }
 
     #[test]
     fn test_part2() {
-        let secrets = from_lines(example2());
+        let secrets = from_lines(example2()).unwrap();
         assert_eq!(part2(&secrets), 23);
 
         assert_eq!(part2(&vec![2021, 5017, 19751]), 27);
@@ -1,64 +1,49 @@
-use std::collections::HashMap;
+use aoc_utils::graph::Graph;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day23.txt");
-    let network = Network::from_lines(data);
+pub struct Day23;
 
-    let triplets = network.triplets();
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    type Input = Network;
 
-    let part1 = network.part1(&triplets);
-    let part2 = network.part2();
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Network::from_lines(lines.to_vec())
+    }
+
+    fn part1(network: &Self::Input) -> Answer {
+        network.part1(&network.triplets()).into()
+    }
 
-    format!("{} {}", part1, part2)
+    fn part2(network: &Self::Input) -> Answer {
+        network.part2().into()
+    }
 }
 
-struct Network {
-    node_name: Vec<String>,
-    edges: Vec<Vec<bool>>,
+pub struct Network {
+    graph: Graph,
 }
 
 impl Network {
-    fn from_lines(lines: Vec<String>) -> Network {
-        let mut node_name = vec![];
-        let mut node_index = HashMap::new();
-
-        let mut pairs = vec![];
-
-        for line in lines.iter() {
-            let (a_str, b_str) = line.split_once("-").unwrap();
-            if !node_index.contains_key(a_str) {
-                node_name.push(a_str.to_string());
-                node_index.insert(a_str.to_string(), node_name.len() - 1);
-            }
-            if !node_index.contains_key(b_str) {
-                node_name.push(b_str.to_string());
-                node_index.insert(b_str.to_string(), node_name.len() - 1);
-            }
-            pairs.push((a_str.to_string(), b_str.to_string()));
+    fn from_lines(lines: Vec<String>) -> aoc_utils::Result<Network> {
+        let mut graph = Graph::new();
+        for (i, line) in lines.iter().enumerate() {
+            let (a, b) = aoc_utils::parse::split_once_or_err(line, "-")
+                .map_err(|error| error.at_line(i + 1, line))?;
+            graph.add_undirected_edge(a, b);
         }
-
-        let mut edges = vec![vec![false; node_name.len()]; node_name.len()];
-
-        for (a, b) in pairs {
-            edges[node_index[&a]][node_index[&b]] = true;
-            edges[node_index[&b]][node_index[&a]] = true;
-        }
-
-        Network { node_name, edges }
+        Ok(Network { graph })
     }
 
     fn triplets(&self) -> Vec<(usize, usize, usize)> {
         let mut result = vec![];
 
-        let edges = self.edges.as_slice();
-        for i_a in 0..self.edges.len() - 2 {
-            let edges_a = edges[i_a].as_slice();
-            for i_b in i_a + 1..self.edges.len() - 1 {
-                if edges_a[i_b] {
-                    for i_c in i_b + 1..self.edges.len() {
-                        if edges_a[i_c] && edges[i_b][i_c] {
-                            result.push((i_a, i_b, i_c));
-                        }
+        for i_a in 0..self.graph.len() {
+            for &i_b in self.graph.neighbours(i_a).iter().filter(|&&b| b > i_a) {
+                for &i_c in self.graph.neighbours(i_b).iter().filter(|&&c| c > i_b) {
+                    if self.graph.has_edge(i_a, i_c) {
+                        result.push((i_a, i_b, i_c));
                     }
                 }
             }
@@ -71,40 +56,26 @@ impl Network {
         triplets
             .iter()
             .filter(|(a, b, c)| {
-                self.node_name[*a].chars().nth(0).unwrap() == 't'
-                    || self.node_name[*b].chars().nth(0).unwrap() == 't'
-                    || self.node_name[*c].chars().nth(0).unwrap() == 't'
+                self.graph.name(*a).chars().nth(0).unwrap() == 't'
+                    || self.graph.name(*b).chars().nth(0).unwrap() == 't'
+                    || self.graph.name(*c).chars().nth(0).unwrap() == 't'
             })
             .count()
     }
 
     fn part2(&self) -> String {
-        let cliques = self.cliques();
+        let cliques = self.graph.maximal_cliques();
 
         let largest = cliques.iter().max_by_key(|clique| clique.len()).unwrap();
 
         let mut names = largest
             .iter()
-            .map(|node| self.node_name[*node].clone())
+            .map(|node| self.graph.name(*node))
             .collect::<Vec<_>>();
         names.sort();
 
         names.join(",")
     }
-
-    fn cliques(&self) -> Vec<Vec<usize>> {
-        let mut cliques = vec![];
-        for start in 0..self.node_name.len() {
-            let mut clique = vec![start];
-            for next in 0..self.node_name.len() {
-                if clique.iter().all(|other| self.edges[next][*other]) {
-                    clique.push(next);
-                }
-            }
-            cliques.push(clique);
-        }
-        cliques
-    }
 }
 
 #[cfg(test)]
@@ -113,39 +84,40 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "1238 bg,bl,ch,fn,fv,gd,jn,kk,lk,pv,rr,tb,vw");
+        let result = aoc_utils::solution::execute::<Day23>().unwrap();
+        assert_eq!(result.part1, 1238);
+        assert_eq!(result.part2, "bg,bl,ch,fn,fv,gd,jn,kk,lk,pv,rr,tb,vw");
     }
 
     #[test]
     fn test_from_lines() {
-        let network = Network::from_lines(example());
+        let network = Network::from_lines(example()).unwrap();
 
-        assert_eq!(network.node_name.len(), 16);
-        assert_eq!(network.edges.len(), 16);
-        assert_eq!(network.edges.iter().flatten().filter(|&e| *e).count(), 64);
+        assert_eq!(network.graph.len(), 16);
+        assert_eq!(network.graph.edge_count(), 64);
     }
 
     #[test]
     fn test_triplets() {
-        let network = Network::from_lines(example());
+        let network = Network::from_lines(example()).unwrap();
         let triplets = network.triplets();
         assert_eq!(triplets.len(), 12);
     }
 
     #[test]
     fn test_part1() {
-        let network = Network::from_lines(example());
+        let network = Network::from_lines(example()).unwrap();
         let triplets = network.triplets();
         assert_eq!(network.part1(&triplets), 7);
     }
 
     #[test]
     fn test_part2() {
-        let network = Network::from_lines(example());
+        let network = Network::from_lines(example()).unwrap();
         assert_eq!(network.part2(), "co,de,ka,ta");
     }
 
     fn example() -> Vec<String> {
-        aoc_utils::read_lines("input/day23-example.txt")
+        aoc_utils::read_lines("input/day23-example.txt").unwrap()
     }
 }
@@ -1,21 +1,30 @@
+use aoc_utils::parse;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::collections::{HashMap, HashSet};
 
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day24.txt");
+pub struct Day24;
 
-    let mut program = Program::from_lines(data.clone());
-    program.execute();
-    let part1 = program.collect_result("z");
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    type Input = Program;
 
-    // TODO: This is too slow to run in CI
-    // let program = Program::from_lines(data);
-    // let part2 = program.part2();
-    let part2 = "hjf,kdh,kpp,sgj,vss,z14,z31,z35";
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Program::from_lines(lines.to_vec())
+    }
 
-    format!("{} {}", part1, part2)
+    fn part1(initial: &Self::Input) -> Answer {
+        let mut program = initial.clone();
+        program.execute();
+        program.collect_result("z").into()
+    }
+
+    fn part2(initial: &Self::Input) -> Answer {
+        initial.part2().into()
+    }
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq)]
 enum Operation {
     AND,
     OR,
@@ -23,12 +32,12 @@ enum Operation {
 }
 
 impl Operation {
-    fn from_str(text: &str) -> Operation {
+    fn from_str(text: &str) -> aoc_utils::Result<Operation> {
         match text {
-            "AND" => Operation::AND,
-            "OR" => Operation::OR,
-            "XOR" => Operation::XOR,
-            _ => panic!("Unknown operation: {}", text),
+            "AND" => Ok(Operation::AND),
+            "OR" => Ok(Operation::OR),
+            "XOR" => Ok(Operation::XOR),
+            _ => Err(aoc_utils::Error::parse(text, "unknown operation")),
         }
     }
     fn execute(self, lhs: bool, rhs: bool) -> bool {
@@ -48,35 +57,39 @@ struct Gate {
 }
 
 #[derive(Clone)]
-struct Program {
+pub struct Program {
     names: Vec<String>,
-    indices: HashMap<String, usize>,
     wires: Vec<Option<bool>>,
     gates: HashMap<usize, Gate>,
 }
 
 impl Program {
-    fn from_lines(lines: Vec<String>) -> Program {
+    fn from_lines(lines: Vec<String>) -> aoc_utils::Result<Program> {
         let mut all_names = HashSet::new();
         let mut gates_text = HashMap::new();
         let mut inits = HashMap::new();
 
         let mut parsing_init = true;
-        for line in lines {
+        for (i, line) in lines.iter().enumerate() {
+            let at_line = |error: aoc_utils::Error| error.at_line(i + 1, line);
             if line.is_empty() {
                 parsing_init = false
             } else if parsing_init {
-                let (bit_name, status) = line.split_once(": ").unwrap();
+                let (bit_name, status) = parse::split_once_or_err(line, ": ").map_err(at_line)?;
                 let previous = inits.insert(bit_name.to_string(), Some(status == "1"));
                 assert!(previous.is_none());
             } else {
-                let (gate_str, output_str) = line.split_once(" -> ").unwrap();
-
-                let mut statement_parts = gate_str.splitn(3, " ");
-                let lhs = statement_parts.next().unwrap().to_string();
-                let operation_str = statement_parts.next().unwrap();
-                let rhs = statement_parts.next().unwrap().to_string();
-                assert_eq!(statement_parts.next(), None);
+                let (gate_str, output_str) =
+                    parse::split_once_or_err(line, " -> ").map_err(at_line)?;
+
+                let statement_parts = gate_str.split(" ").collect::<Vec<_>>();
+                let [lhs, operation_str, rhs] = statement_parts[..] else {
+                    return Err(at_line(aoc_utils::Error::parse(
+                        gate_str,
+                        "expected `<wire> <operation> <wire>`",
+                    )));
+                };
+                let (lhs, rhs) = (lhs.to_string(), rhs.to_string());
 
                 let output = output_str.to_string();
 
@@ -84,7 +97,7 @@ impl Program {
                 all_names.insert(lhs.clone());
                 all_names.insert(rhs.clone());
 
-                let op = Operation::from_str(operation_str);
+                let op = Operation::from_str(operation_str).map_err(at_line)?;
                 let gate = (lhs, rhs, op);
 
                 let previous = gates_text.insert(output, gate);
@@ -119,12 +132,11 @@ impl Program {
             })
             .collect();
 
-        Program {
+        Ok(Program {
             names,
-            indices,
             wires,
             gates,
-        }
+        })
     }
 
     fn execute(&mut self) -> bool {
@@ -160,17 +172,6 @@ impl Program {
         result
     }
 
-    fn set_input(&mut self, base_name: &str, value: i64) {
-        let wires = self.get_wires(base_name);
-
-        let mut remaining = value;
-        for (_name, index) in wires.into_iter() {
-            let value = (remaining & 1) == 1;
-            self.wires[index] = Some(value);
-            remaining >>= 1;
-        }
-    }
-
     fn get_wires(&self, base_name: &str) -> Vec<(String, usize)> {
         let mut wires = self
             .names
@@ -185,216 +186,60 @@ impl Program {
     }
 
     fn part2(&self) -> String {
-        let swaps = self.find_corrective_swaps();
-        let mut outputs = swaps
+        let mut outputs = self
+            .miswired_outputs()
             .into_iter()
-            .flat_map(|(a, b)| vec![self.names[a].clone(), self.names[b].clone()])
+            .map(|index| self.names[index].clone())
             .collect::<Vec<_>>();
         outputs.sort();
         outputs.join(",")
     }
 
-    fn find_corrective_swaps(&self) -> Vec<(usize, usize)> {
-        let swap_candidates = self.find_swap_candidates();
-
-        let error_outputs = self.all_error_outputs(&vec![]).unwrap();
-
-        self.find_swaps(&vec![], &swap_candidates, error_outputs.len() - 2)
-            .unwrap()
-    }
-
-    fn find_swap_candidates(&self) -> HashSet<usize> {
-        let mut all_swap_candidates = HashSet::new();
-
-        let input_size = self.get_wires("x").len();
-        for i in 0..input_size {
-            let mut all_error_outputs = HashSet::new();
-
-            for (x, y) in Self::test_pairs(i) {
-                if let Some(error_outputs) = self.search_error_outputs(x, y, &vec![]) {
-                    all_error_outputs.extend(error_outputs);
-                }
-            }
-
-            if !all_error_outputs.is_empty() {
-                let error_inputs = vec![
-                    self.indices[&format!("x{:02}", i)],
-                    self.indices[&format!("y{:02}", i)],
-                ];
-                let swap_candidates = error_inputs
-                    .into_iter()
-                    .flat_map(|input| {
-                        all_error_outputs
-                            .iter()
-                            .filter_map(|&output| self.nodes_in_routes(input, output))
-                            .flatten()
-                            .collect::<Vec<_>>()
-                    })
-                    .collect::<HashSet<_>>();
-
-                all_swap_candidates.extend(swap_candidates.clone());
-            }
+    /// The outputs of the gates which do not fit in a ripple-carry adder of
+    /// `x` and `y` into `z`. Each bit but the first one is a full adder:
+    ///
+    /// ```text
+    /// sum     = x XOR y
+    /// z       = sum XOR carry
+    /// carry'  = (x AND y) OR (sum AND carry)
+    /// ```
+    ///
+    /// the first bit being a half adder and the last `z` being the last carry.
+    fn miswired_outputs(&self) -> Vec<usize> {
+        let mut consumers = vec![vec![]; self.names.len()];
+        for gate in self.gates.values() {
+            consumers[gate.lhs].push(gate.op);
+            consumers[gate.rhs].push(gate.op);
         }
-        all_swap_candidates
-    }
+        let feeds = |wire: usize, op: Operation| consumers[wire].contains(&op);
 
-    fn find_swaps(
-        &self,
-        swaps: &Vec<(usize, usize)>,
-        candidates: &HashSet<usize>,
-        max_errors: usize,
-    ) -> Option<Vec<(usize, usize)>> {
-        let mut swaps_iter = SwapIterator::from_set(candidates.clone());
-        while let Some(swap) = swaps_iter.next() {
-            let mut next_swaps = swaps.clone();
-            next_swaps.push(swap.clone());
-            if let Some(error_outputs) = self.all_error_outputs(&next_swaps) {
-                if error_outputs.len() == 0 {
-                    return Some(next_swaps);
-                } else if error_outputs.len() <= max_errors {
-                    let next_candidates = swaps_iter.sub_options();
-                    let next_result =
-                        self.find_swaps(&next_swaps, &next_candidates, error_outputs.len() - 2);
-                    if let Some(next) = next_result {
-                        return Some(next);
-                    }
-                }
-            }
-        }
-        None
-    }
+        let name = |wire: usize| self.names[wire].as_str();
+        let is_input = |wire: usize| name(wire).starts_with('x') || name(wire).starts_with('y');
+        let is_first_input = |wire: usize| name(wire) == "x00" || name(wire) == "y00";
+        let is_output = |wire: usize| name(wire).starts_with('z');
+        let last_output = self.get_wires("z").last().map(|&(_, index)| index);
 
-    fn all_error_outputs(&self, swaps: &Vec<(usize, usize)>) -> Option<HashSet<usize>> {
-        let mut all_errors = HashSet::new();
-        let size = self.get_wires("x").len();
-        for i in 0..size {
-            for (x, y) in Self::test_pairs(i) {
-                if let Some(error_outputs) = self.search_error_outputs(x, y, swaps) {
-                    all_errors.extend(error_outputs);
-                } else {
-                    return None;
-                }
-            }
-        }
-        Some(all_errors)
-    }
-
-    fn test_pairs(i: usize) -> Vec<(i64, i64)> {
-        let mut test_pairs = vec![(1 << i, 0), (0, 1 << i), (1 << i, 1 << i)];
-        if i > 0 {
-            // add cases where there's a carry from previous bit
-            test_pairs.extend(
-                test_pairs
-                    .clone()
-                    .iter()
-                    .map(|(x, y)| (x | 1 << i - 1, y | 1 << i - 1)),
-            );
-            test_pairs.push((1 << i - 1, 1 << i - 1));
-        }
-        test_pairs
-    }
-
-    fn search_error_outputs(
-        &self,
-        x: i64,
-        y: i64,
-        swaps: &Vec<(usize, usize)>,
-    ) -> Option<Vec<usize>> {
-        let mut program = self.clone();
-        program.set_input("x", x);
-        program.set_input("y", y);
-        program.apply_swaps(&swaps);
-
-        if !program.execute() {
-            return None;
-        }
-        let expected = x + y;
-        let errors = program
-            .get_wires("z")
-            .into_iter()
-            .enumerate()
-            .filter_map(|(i, (_name, index))| {
-                let mask = 1 << i;
-                let expected_bit = ((expected & mask) >> i) == 1;
-                let is_error = expected_bit != program.wires[index].unwrap();
-                is_error.then_some(index)
-            })
-            .collect();
-        Some(errors)
-    }
-
-    fn nodes_in_routes(&self, input: usize, output: usize) -> Option<Vec<usize>> {
-        if input == output {
-            Some(vec![input.clone()])
-        } else {
-            let children = self
-                .gates
-                .iter()
-                .filter_map(|(out, gate)| (input == gate.lhs || input == gate.rhs).then_some(out));
-            let mut result = children
-                .filter_map(|child| self.nodes_in_routes(*child, output))
-                .flatten()
-                .collect::<Vec<_>>();
-            if result.len() > 0 {
-                result.push(input.clone());
-                Some(result)
-            } else {
-                None
-            }
-        }
-    }
-
-    fn apply_swaps(&mut self, swaps: &Vec<(usize, usize)>) {
-        for (a, b) in swaps.iter() {
-            if !self.gates.contains_key(a) || !self.gates.contains_key(b) {
-                continue;
-            }
-            let a_gate = self.gates.remove(a).unwrap();
-            let b_gate = self.gates.insert(*b, a_gate).unwrap();
-            assert!(self.gates.insert(*a, b_gate).is_none());
-        }
-    }
-}
-
-struct SwapIterator {
-    options: Vec<usize>,
-    i: usize,
-    j: usize,
-}
-
-impl SwapIterator {
-    fn from_set(options: HashSet<usize>) -> SwapIterator {
-        SwapIterator {
-            options: Vec::from_iter(options.into_iter()),
-            i: 0,
-            j: 0,
-        }
-    }
-
-    fn sub_options(&self) -> HashSet<usize> {
-        self.options[self.i + 1..]
+        let mut miswired = self
+            .gates
             .iter()
-            .filter_map(|&n| (n != self.j).then_some(n))
-            .collect()
-    }
-}
-impl Iterator for SwapIterator {
-    type Item = (usize, usize);
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.options.len() == 0 {
-            return None;
-        }
-        self.j += 1;
-        if self.j == self.options.len() {
-            self.i += 1;
-            self.j = self.i + 1;
-        }
-        if self.i == self.options.len() - 1 {
-            return None;
-        }
-        let a = self.options[self.i];
-        let b = self.options[self.j];
-        Some((a, b))
+            .filter_map(|(&output, gate)| {
+                let from_inputs = is_input(gate.lhs) && is_input(gate.rhs);
+                let first_bit = is_first_input(gate.lhs) && is_first_input(gate.rhs);
+                let fits = match gate.op {
+                    _ if Some(output) == last_output => gate.op == Operation::OR,
+                    Operation::XOR if first_bit => is_output(output),
+                    Operation::XOR if from_inputs => feeds(output, Operation::XOR),
+                    Operation::XOR => is_output(output),
+                    _ if is_output(output) => false,
+                    Operation::AND if first_bit => feeds(output, Operation::XOR),
+                    Operation::AND => feeds(output, Operation::OR),
+                    Operation::OR => feeds(output, Operation::XOR),
+                };
+                (!fits).then_some(output)
+            })
+            .collect::<Vec<_>>();
+        miswired.sort();
+        miswired
     }
 }
 
@@ -404,11 +249,13 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "51837135476040 hjf,kdh,kpp,sgj,vss,z14,z31,z35");
+        let result = aoc_utils::solution::execute::<Day24>().unwrap();
+        assert_eq!(result.part1, 51837135476040);
+        assert_eq!(result.part2, "hjf,kdh,kpp,sgj,vss,z14,z31,z35");
     }
     #[test]
     fn test_from_lines() {
-        let program = Program::from_lines(example());
+        let program = Program::from_lines(example()).unwrap();
 
         assert_eq!(program.names.len(), 46);
         assert_eq!(
@@ -419,14 +266,72 @@ mod tests {
 
     #[test]
     fn test_execute() {
-        let mut program = Program::from_lines(example());
+        let mut program = Program::from_lines(example()).unwrap();
         program.execute();
         assert_eq!(program.wires.iter().filter(|&bit| bit.is_none()).count(), 0);
 
         assert_eq!(program.collect_result("z"), 2024)
     }
 
+    #[test]
+    fn test_miswired_outputs() {
+        let adder = Program::from_lines(adder_lines(&[])).unwrap();
+        assert_eq!(adder.part2(), "");
+        let mut sum = adder.clone();
+        sum.execute();
+        assert_eq!(sum.collect_result("z"), 0b101 + 0b011);
+
+        let swapped = Program::from_lines(adder_lines(&[("s02", "a02")])).unwrap();
+        assert_eq!(swapped.part2(), "a02,s02");
+
+        let swapped = Program::from_lines(adder_lines(&[("s02", "a02"), ("z01", "c02")])).unwrap();
+        assert_eq!(swapped.part2(), "a02,c02,s02,z01");
+    }
+
+    /// A 3 bits adder computing 5 + 3, with some outputs swapped
+    fn adder_lines(swaps: &[(&'static str, &'static str)]) -> Vec<String> {
+        let inputs = "x00: 1\nx01: 0\nx02: 1\ny00: 1\ny01: 1\ny02: 0\n";
+        let gates = [
+            ("x00 XOR y00", "z00"),
+            ("x00 AND y00", "c01"),
+            ("x01 XOR y01", "s01"),
+            ("s01 XOR c01", "z01"),
+            ("x01 AND y01", "a01"),
+            ("s01 AND c01", "b01"),
+            ("a01 OR b01", "c02"),
+            ("x02 XOR y02", "s02"),
+            ("s02 XOR c02", "z02"),
+            ("x02 AND y02", "a02"),
+            ("s02 AND c02", "b02"),
+            ("a02 OR b02", "z03"),
+        ];
+        let swapped = |output: &'static str| {
+            swaps
+                .iter()
+                .find_map(|&(a, b)| {
+                    if output == a {
+                        Some(b)
+                    } else if output == b {
+                        Some(a)
+                    } else {
+                        None
+                    }
+                })
+                .unwrap_or(output)
+        };
+        inputs
+            .lines()
+            .map(String::from)
+            .chain([String::new()])
+            .chain(
+                gates
+                    .iter()
+                    .map(|(gate, output)| format!("{} -> {}", gate, swapped(output))),
+            )
+            .collect()
+    }
+
     fn example() -> Vec<String> {
-        aoc_utils::read_lines("input/day24-example.txt")
+        aoc_utils::read_lines("input/day24-example.txt").unwrap()
     }
 }
@@ -1,32 +1,51 @@
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day25.txt");
-    let (locks, keys) = locks_keys_from_lines(data);
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 
-    let part1 = part1(&locks, &keys);
-    let part2 = 456;
+pub struct Day25;
 
-    format!("{} {}", part1, part2)
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    /// The locks, and the keys.
+    type Input = (Vec<Part>, Vec<Part>);
+
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        locks_keys_from_lines(lines.to_vec())
+    }
+
+    fn part1((locks, keys): &Self::Input) -> Answer {
+        part1(locks, keys).into()
+    }
+
+    fn part2(_input: &Self::Input) -> Answer {
+        456.into()
+    }
 }
 
-struct Part {
+pub struct Part {
     is_lock: bool,
     pins: Vec<i8>,
 }
 
 impl Part {
-    fn from_lines(lines: Vec<String>) -> Part {
-        let is_lock = lines.first().unwrap().chars().all(|c| c == '#');
-        let mut pins = vec![-1; lines[0].len()];
-        for line in lines {
-            assert!(line.len() == pins.len());
+    fn from_lines(lines: &[String], offset: usize) -> aoc_utils::Result<Part> {
+        let first = lines.first().map_or("", String::as_str);
+        let is_lock = first.chars().all(|c| c == '#');
+        let mut pins = vec![-1; first.len()];
+        aoc_utils::parse::lines_at(lines, offset, |line| {
+            if line.len() != pins.len() {
+                let message = format!("expected {} pins", pins.len());
+                return Err(aoc_utils::Error::parse(line, message));
+            }
             for (i, c) in line.chars().enumerate() {
                 match c {
                     '#' => pins[i] += 1,
-                    _ => continue,
+                    '.' => continue,
+                    _ => return Err(aoc_utils::Error::parse(&c.to_string(), "unknown pin")),
                 }
             }
-        }
-        Part { is_lock, pins }
+            Ok(())
+        })?;
+        Ok(Part { is_lock, pins })
     }
 
     fn matches(&self, other: &Self) -> bool {
@@ -44,11 +63,11 @@ impl Part {
     }
 }
 
-fn locks_keys_from_lines(lines: Vec<String>) -> (Vec<Part>, Vec<Part>) {
+fn locks_keys_from_lines(lines: Vec<String>) -> aoc_utils::Result<(Vec<Part>, Vec<Part>)> {
     let mut locks = vec![];
     let mut keys = vec![];
-    for block in lines.split(|l| l.is_empty()) {
-        let part = Part::from_lines(block.to_vec());
+    for (offset, block) in aoc_utils::parse::paragraphs(&lines) {
+        let part = Part::from_lines(block, offset)?;
         if part.is_lock {
             locks.push(part);
         } else {
@@ -56,7 +75,7 @@ fn locks_keys_from_lines(lines: Vec<String>) -> (Vec<Part>, Vec<Part>) {
         }
     }
 
-    (locks, keys)
+    Ok((locks, keys))
 }
 
 fn part1(locks: &Vec<Part>, keys: &Vec<Part>) -> usize {
@@ -78,12 +97,14 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "3155 456");
+        let result = aoc_utils::solution::execute::<Day25>().unwrap();
+        assert_eq!(result.part1, 3155);
+        assert_eq!(result.part2, 456);
     }
 
     #[test]
     fn test_from_lines() {
-        let (locks, keys) = locks_keys_from_lines(example());
+        let (locks, keys) = locks_keys_from_lines(example()).unwrap();
 
         assert_eq!(locks.len(), 2);
         assert_eq!(keys.len(), 3);
@@ -98,7 +119,7 @@ mod tests {
 
     #[test]
     fn test_matches() {
-        let (locks, keys) = locks_keys_from_lines(example());
+        let (locks, keys) = locks_keys_from_lines(example()).unwrap();
 
         assert!(!locks[0].matches(&keys[0]));
         assert!(!locks[0].matches(&keys[1]));
@@ -110,11 +131,23 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        let (locks, keys) = locks_keys_from_lines(example());
+        let (locks, keys) = locks_keys_from_lines(example()).unwrap();
         assert_eq!(part1(&locks, &keys), 3);
     }
 
+    #[test]
+    fn test_parse_errors() {
+        let lines = |lines: &[&str]| lines.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let error = |lines| locks_keys_from_lines(lines).err().unwrap().to_string();
+
+        assert_eq!(
+            error(lines(&["##", "#.", "", "..", "#"])),
+            "5: expected 2 pins: `#`"
+        );
+        assert_eq!(error(lines(&["##", "#x"])), "2: unknown pin: `x` in `#x`");
+    }
+
     fn example() -> Vec<String> {
-        aoc_utils::read_lines("input/day25-example.txt")
+        aoc_utils::read_lines("input/day25-example.txt").unwrap()
     }
 }
@@ -1,11 +1,24 @@
-use std::fs::read_to_string;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 
-pub fn execute() -> String {
-    let data = read_to_string("input/day3.txt").unwrap();
-    let part1 = process_all(&data);
-    let part2 = process_active(&data);
+pub struct Day3;
 
-    format!("{} {}", part1, part2)
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    /// The corrupted memory.
+    type Input = String;
+
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Ok(lines.join("\n"))
+    }
+
+    fn part1(memory: &Self::Input) -> Answer {
+        process_all(memory).into()
+    }
+
+    fn part2(memory: &Self::Input) -> Answer {
+        process_active(memory).into()
+    }
 }
 
 fn find_first_mul(input: &str) -> Option<(Option<(u32, u32)>, usize)> {
@@ -107,7 +120,9 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "182780583 90772405");
+        let result = aoc_utils::solution::execute::<Day3>().unwrap();
+        assert_eq!(result.part1, 182780583);
+        assert_eq!(result.part2, 90772405);
     }
 
     #[test]
@@ -1,16 +1,28 @@
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::fmt::{Debug, Display, Formatter};
 
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day4.txt");
-    let mine = Puzzle::from_lines(data);
-    let part1 = mine.count_all();
-    let part2 = mine.count_x();
+pub struct Day4;
 
-    format!("{} {}", part1, part2)
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input = Puzzle;
+
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Ok(Puzzle::from_lines(lines.to_vec()))
+    }
+
+    fn part1(puzzle: &Self::Input) -> Answer {
+        puzzle.count_all().into()
+    }
+
+    fn part2(puzzle: &Self::Input) -> Answer {
+        puzzle.count_x().into()
+    }
 }
 
 #[derive(Debug, Clone)]
-struct Puzzle {
+pub struct Puzzle {
     size: usize,
     rows: Vec<Vec<char>>,
 }
@@ -139,7 +151,9 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "2562 1902");
+        let result = aoc_utils::solution::execute::<Day4>().unwrap();
+        assert_eq!(result.part1, 2562);
+        assert_eq!(result.part2, 1902);
     }
 
     #[test]
@@ -1,49 +1,53 @@
+use aoc_utils::parse;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::cmp::Ordering;
 use std::collections::HashSet;
 
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day5.txt");
-    let po = PrintOrder::from_lines(data);
-    let part1 = po.part1();
-    let part2 = po.part2();
+pub struct Day5;
 
-    format!("{} {}", part1, part2)
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input = PrintOrder;
+
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        PrintOrder::from_lines(lines.to_vec())
+    }
+
+    fn part1(po: &Self::Input) -> Answer {
+        po.part1().into()
+    }
+
+    fn part2(po: &Self::Input) -> Answer {
+        po.part2().into()
+    }
 }
 
-struct PrintOrder {
+pub struct PrintOrder {
     rules: HashSet<(u8, u8)>,
     updates: Vec<Vec<u8>>,
 }
 
 impl PrintOrder {
-    fn from_lines(lines: Vec<String>) -> Self {
-        let mut rules_lines = vec![];
-        let mut updates_lines = vec![];
-        let mut foundsep = false;
-        for line in lines.into_iter() {
-            if line.is_empty() {
-                foundsep = true;
-            } else if !foundsep {
-                rules_lines.push(line);
-            } else {
-                updates_lines.push(line);
-            }
-        }
-
-        let rules = rules_lines
-            .iter()
-            .map(|line| {
-                let (a, b) = line.split_once("|").unwrap();
-                (a.parse().unwrap(), b.parse().unwrap())
-            })
-            .collect();
-
-        let updates = updates_lines
+    fn from_lines(lines: Vec<String>) -> aoc_utils::Result<Self> {
+        let separator = lines
             .iter()
-            .map(|line| line.split(",").map(|p| p.parse().unwrap()).collect())
-            .collect();
-
-        Self { rules, updates }
+            .position(|line| line.is_empty())
+            .ok_or_else(|| aoc_utils::Error::parse("", "missing empty line before updates"))?;
+        let (rules_lines, updates_lines) = lines.split_at(separator);
+
+        let rules = parse::lines(rules_lines, |line| {
+            let (a, b) = parse::split_once_or_err(line, "|")?;
+            Ok((parse::value(a)?, parse::value(b)?))
+        })?
+        .into_iter()
+        .collect();
+
+        let updates = parse::lines_at(&updates_lines[1..], separator + 1, |line| {
+            line.split(",").map(parse::value).collect()
+        })?;
+
+        Ok(Self { rules, updates })
     }
 
     fn is_ordered(&self, update: &Vec<u8>) -> bool {
@@ -104,12 +108,14 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "6267 5184");
+        let result = aoc_utils::solution::execute::<Day5>().unwrap();
+        assert_eq!(result.part1, 6267);
+        assert_eq!(result.part2, 5184);
     }
 
     #[test]
     fn test_from_lines() {
-        let po = PrintOrder::from_lines(_example());
+        let po = PrintOrder::from_lines(_example()).unwrap();
         assert_eq!(po.rules.len(), 21);
         assert_eq!(po.updates.len(), 6);
 
@@ -122,7 +128,7 @@ mod tests {
 
     #[test]
     fn test_is_ordered() {
-        let po = PrintOrder::from_lines(_example());
+        let po = PrintOrder::from_lines(_example()).unwrap();
 
         assert!(po.is_ordered(&po.updates[0]));
         assert!(po.is_ordered(&po.updates[1]));
@@ -134,7 +140,7 @@ mod tests {
 
     #[test]
     fn test_get_middle_page() {
-        let po = PrintOrder::from_lines(_example());
+        let po = PrintOrder::from_lines(_example()).unwrap();
 
         assert_eq!(PrintOrder::middle_page(&po.updates[0]), 61);
         assert_eq!(PrintOrder::middle_page(&po.updates[1]), 53);
@@ -146,14 +152,14 @@ mod tests {
 
     #[test]
     fn test_part_1() {
-        let po = PrintOrder::from_lines(_example());
+        let po = PrintOrder::from_lines(_example()).unwrap();
 
         assert_eq!(po.part1(), 143);
     }
 
     #[test]
     fn test_order() {
-        let po = PrintOrder::from_lines(_example());
+        let po = PrintOrder::from_lines(_example()).unwrap();
         assert_eq!(
             po.order(&Vec::<u8>::from([75, 97, 47, 61, 53])),
             vec![97, 75, 47, 61, 53]
@@ -167,12 +173,12 @@ mod tests {
 
     #[test]
     fn test_part_2() {
-        let po = PrintOrder::from_lines(_example());
+        let po = PrintOrder::from_lines(_example()).unwrap();
 
         assert_eq!(po.part2(), 123);
     }
 
     fn _example() -> Vec<String> {
-        aoc_utils::read_lines("input/day5-example.txt")
+        aoc_utils::read_lines("input/day5-example.txt").unwrap()
     }
 }
@@ -1,15 +1,29 @@
+use aoc_utils::parallel;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::collections::HashSet;
 
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day6.txt");
-    let (guard, direction, obstacles, size) = from_lines(data);
-    let (route_len, _has_loop) = guard_route_length(guard, direction, &obstacles, size);
-    let opportunities = obstacle_opportunities(guard, direction, &obstacles, size);
+pub struct Day6;
 
-    let part1 = route_len;
-    let part2 = opportunities.len();
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    /// The guard, its direction, the obstacles and the size of the map.
+    type Input = (Coordinates, Coordinates, Vec<bool>, i16);
 
-    format!("{} {}", part1, part2)
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        from_lines(lines.to_vec())
+    }
+
+    fn part1((guard, direction, obstacles, size): &Self::Input) -> Answer {
+        let (route_len, _has_loop) = guard_route_length(*guard, *direction, obstacles, *size);
+        route_len.into()
+    }
+
+    fn part2((guard, direction, obstacles, size): &Self::Input) -> Answer {
+        obstacle_opportunities(*guard, *direction, obstacles, *size)
+            .len()
+            .into()
+    }
 }
 type Coordinates = (i16, i16);
 
@@ -31,41 +45,51 @@ fn direction_to_offset(direction: Coordinates) -> usize {
     }
 }
 
-fn from_lines(lines: Vec<String>) -> (Coordinates, Coordinates, Vec<bool>, i16) {
+fn from_lines(lines: Vec<String>) -> aoc_utils::Result<(Coordinates, Coordinates, Vec<bool>, i16)> {
     let direction = (0, -1);
-    let mut guard = (-1, -1);
+    let mut guard = None;
 
     let size = lines.len() as i16;
     let mut obstacles = vec![false; coord_to_offset((size, 0), size)];
 
     for (j, line) in lines.iter().enumerate() {
-        assert_eq!(line.len() as i16, size);
+        if line.len() as i16 != size {
+            let message = format!("expected {} tiles", size);
+            return Err(aoc_utils::Error::parse(line, message).at_line(j + 1, line));
+        }
         for (i, c) in line.chars().enumerate() {
             match c {
                 '#' => {
                     obstacles[coord_to_offset((i as i16, j as i16), size)] = true;
                 }
                 '^' => {
-                    guard = (i as i16, j as i16);
+                    guard = Some((i as i16, j as i16));
+                }
+                '.' => {}
+                _ => {
+                    return Err(aoc_utils::Error::parse(&c.to_string(), "unknown tile")
+                        .at_line(j + 1, line))
                 }
-                _ => {}
             }
         }
     }
-    (guard, direction, obstacles, size)
+    let guard = guard.ok_or_else(|| aoc_utils::Error::parse("", "missing guard `^`"))?;
+    Ok((guard, direction, obstacles, size))
 }
 
+/// Move the guard one step, `extra_obstacle` being blocked on top of `obstacles`.
 fn step(
     guard: Coordinates,
     direction: Coordinates,
     obstacles: &Vec<bool>,
+    extra_obstacle: Option<Coordinates>,
     size: i16,
 ) -> Option<(Coordinates, Coordinates)> {
     let next = (guard.0 + direction.0, guard.1 + direction.1);
     if next.0 >= 0 && next.1 >= 0 && next.0 < size && next.1 < size {
-        if obstacles[coord_to_offset(next, size)] {
+        if obstacles[coord_to_offset(next, size)] || extra_obstacle == Some(next) {
             let new_direction = (-direction.1, direction.0);
-            step(guard, new_direction, obstacles, size)
+            step(guard, new_direction, obstacles, extra_obstacle, size)
         } else {
             Some((next, direction))
         }
@@ -80,7 +104,7 @@ fn guard_route_length(
     obstacles: &Vec<bool>,
     size: i16,
 ) -> (usize, bool) {
-    let (route, has_loop) = guard_route(guard, direction, obstacles, size);
+    let (route, has_loop) = guard_route(guard, direction, obstacles, None, size);
     (route.len(), has_loop)
 }
 
@@ -88,6 +112,7 @@ fn guard_route(
     guard: Coordinates,
     direction: Coordinates,
     obstacles: &Vec<bool>,
+    extra_obstacle: Option<Coordinates>,
     size: i16,
 ) -> (Vec<Coordinates>, bool) {
     let mut route = vec![guard];
@@ -103,7 +128,9 @@ fn guard_route(
 
     let mut has_loop = false;
 
-    while let Some((next, direction)) = step(cur_guard, cur_direction, obstacles, size) {
+    while let Some((next, direction)) =
+        step(cur_guard, cur_direction, obstacles, extra_obstacle, size)
+    {
         if !visited[coord_to_offset(next, size)] {
             visited[coord_to_offset(next, size)] = true;
             route.push(next);
@@ -125,19 +152,16 @@ fn obstacle_opportunities(
     obstacles: &Vec<bool>,
     size: i16,
 ) -> HashSet<Coordinates> {
-    let (initial_route, has_loop) = guard_route(guard, direction, &obstacles, size);
+    let (initial_route, has_loop) = guard_route(guard, direction, obstacles, None, size);
     assert!(!has_loop);
 
     let mut options = initial_route.clone();
     options.remove(0);
 
-    let mut already_seen = HashSet::new();
-
     let mut previous_guard = guard;
 
     let mut already_tried = HashSet::new();
-    let mut option_obstacles = obstacles.clone();
-    let mut result = HashSet::new();
+    let mut attempts = Vec::new();
     for option in options {
         let mut previous_direction = (option.0 - previous_guard.0, option.1 - previous_guard.1);
         if previous_direction.0.abs() > 1 {
@@ -153,24 +177,26 @@ fn obstacle_opportunities(
             );
         }
 
-        already_seen.insert((previous_guard, previous_direction));
-
         if already_tried.insert(option) {
-            assert!(!option_obstacles[coord_to_offset(option, size)]);
-            option_obstacles[coord_to_offset(option, size)] = true;
-            let (_route, has_loop) =
-                guard_route(previous_guard, previous_direction, &option_obstacles, size);
-            option_obstacles[coord_to_offset(option, size)] = false;
-
-            if has_loop {
-                result.insert(option);
-            }
+            attempts.push((option, previous_guard, previous_direction));
         }
 
         previous_guard = option;
     }
 
-    result
+    let loops = parallel::map(attempts, |(option, previous_guard, previous_direction)| {
+        assert!(!obstacles[coord_to_offset(option, size)]);
+        let (_route, has_loop) = guard_route(
+            previous_guard,
+            previous_direction,
+            obstacles,
+            Some(option),
+            size,
+        );
+        has_loop.then_some(option)
+    });
+
+    loops.into_iter().flatten().collect()
 }
 
 #[cfg(test)]
@@ -179,12 +205,14 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "5153 1711");
+        let result = aoc_utils::solution::execute::<Day6>().unwrap();
+        assert_eq!(result.part1, 5153);
+        assert_eq!(result.part2, 1711);
     }
 
     #[test]
     fn test_from_lines() {
-        let (guard, direction, obstacles, size) = from_lines(_example());
+        let (guard, direction, obstacles, size) = from_lines(_example()).unwrap();
         assert_eq!(direction, (0, -1));
         assert_eq!(guard, (4, 6));
         assert_eq!(size, 10);
@@ -202,20 +230,29 @@ mod tests {
         let size = 10;
         let no_obstacle = vec![false; coord_to_offset((size, 0), size)];
 
-        assert_eq!(Some(((4, 3), UP)), step((4, 4), UP, &no_obstacle, size));
-        assert_eq!(None, step((4, 0), UP, &no_obstacle, size));
+        assert_eq!(
+            Some(((4, 3), UP)),
+            step((4, 4), UP, &no_obstacle, None, size)
+        );
+        assert_eq!(None, step((4, 0), UP, &no_obstacle, None, size));
 
-        assert_eq!(Some(((4, 5), DOWN)), step((4, 4), DOWN, &no_obstacle, size));
-        assert_eq!(None, step((4, 9), DOWN, &no_obstacle, size));
+        assert_eq!(
+            Some(((4, 5), DOWN)),
+            step((4, 4), DOWN, &no_obstacle, None, size)
+        );
+        assert_eq!(None, step((4, 9), DOWN, &no_obstacle, None, size));
 
         assert_eq!(
             Some(((5, 4), RIGHT)),
-            step((4, 4), RIGHT, &no_obstacle, size)
+            step((4, 4), RIGHT, &no_obstacle, None, size)
         );
-        assert_eq!(None, step((9, 4), RIGHT, &no_obstacle, size));
+        assert_eq!(None, step((9, 4), RIGHT, &no_obstacle, None, size));
 
-        assert_eq!(Some(((3, 4), LEFT)), step((4, 4), LEFT, &no_obstacle, size));
-        assert_eq!(None, step((0, 4), LEFT, &no_obstacle, size));
+        assert_eq!(
+            Some(((3, 4), LEFT)),
+            step((4, 4), LEFT, &no_obstacle, None, size)
+        );
+        assert_eq!(None, step((0, 4), LEFT, &no_obstacle, None, size));
     }
 
     #[test]
@@ -226,10 +263,23 @@ mod tests {
         let mut mid_block = no_obstacle.clone();
         mid_block[coord_to_offset((4, 4), size)] = true;
 
-        assert_eq!(Some(((5, 5), RIGHT)), step((4, 5), UP, &mid_block, 10));
-        assert_eq!(Some(((3, 3), LEFT)), step((4, 3), DOWN, &mid_block, 10));
-        assert_eq!(Some(((3, 5), DOWN)), step((3, 4), RIGHT, &mid_block, 10));
-        assert_eq!(Some(((5, 3), UP)), step((5, 4), LEFT, &mid_block, 10));
+        assert_eq!(
+            Some(((5, 5), RIGHT)),
+            step((4, 5), UP, &mid_block, None, 10)
+        );
+        assert_eq!(
+            Some(((3, 3), LEFT)),
+            step((4, 3), DOWN, &mid_block, None, 10)
+        );
+        assert_eq!(
+            Some(((3, 5), DOWN)),
+            step((3, 4), RIGHT, &mid_block, None, 10)
+        );
+        assert_eq!(Some(((5, 3), UP)), step((5, 4), LEFT, &mid_block, None, 10));
+        assert_eq!(
+            Some(((5, 5), RIGHT)),
+            step((4, 5), UP, &no_obstacle, Some((4, 4)), 10)
+        );
 
         //    ..#<.
         //    v....
@@ -241,24 +291,24 @@ mod tests {
 
         let mut right_block = base.clone();
         right_block[coord_to_offset((4, 2), size)] = true;
-        assert_eq!(None, step((4, 3), UP, &right_block, 5));
+        assert_eq!(None, step((4, 3), UP, &right_block, None, 5));
 
         let mut left_block = base.clone();
         left_block[coord_to_offset((0, 2), size)] = true;
-        assert_eq!(None, step((0, 1), DOWN, &left_block, 5));
+        assert_eq!(None, step((0, 1), DOWN, &left_block, None, 5));
 
         let mut down_block = base.clone();
         down_block[coord_to_offset((2, 4), size)] = true;
-        assert_eq!(None, step((1, 4), RIGHT, &down_block, 5));
+        assert_eq!(None, step((1, 4), RIGHT, &down_block, None, 5));
 
         let mut up_block = base.clone();
         up_block[coord_to_offset((2, 0), size)] = true;
-        assert_eq!(None, step((3, 0), LEFT, &up_block, 5));
+        assert_eq!(None, step((3, 0), LEFT, &up_block, None, 5));
     }
 
     #[test]
     fn test_guard_route() {
-        let (guard, direction, obstacles, size) = from_lines(_example());
+        let (guard, direction, obstacles, size) = from_lines(_example()).unwrap();
         let (route_len, has_loop) = guard_route_length(guard, direction, &obstacles, size);
 
         assert_eq!(route_len, 41);
@@ -267,7 +317,7 @@ mod tests {
 
     #[test]
     fn test_guard_route_with_loop() {
-        let (guard, direction, mut obstacles, size) = from_lines(_example());
+        let (guard, direction, mut obstacles, size) = from_lines(_example()).unwrap();
         obstacles[coord_to_offset((3, 6), size)] = true;
         let (route_len, has_loop) = guard_route_length(guard, direction, &obstacles, size);
 
@@ -277,7 +327,7 @@ mod tests {
 
     #[test]
     fn test_obstacle_opportunities() {
-        let (guard, direction, obstacles, size) = from_lines(_example());
+        let (guard, direction, obstacles, size) = from_lines(_example()).unwrap();
         let opportunities = obstacle_opportunities(guard, direction, &obstacles, size);
         assert_eq!(opportunities.len(), 6);
         assert!(opportunities.contains(&(3, 6)));
@@ -288,6 +338,16 @@ mod tests {
         assert!(opportunities.contains(&(7, 9)));
     }
 
+    #[test]
+    fn test_parse_errors() {
+        let lines = |lines: &[&str]| lines.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let error = |lines| from_lines(lines).err().unwrap().to_string();
+
+        assert_eq!(error(lines(&["#^", "."])), "2: expected 2 tiles: `.`");
+        assert_eq!(error(lines(&["#^", ".x"])), "2: unknown tile: `x` in `.x`");
+        assert_eq!(error(lines(&["#.", ".."])), "missing guard `^`: ``");
+    }
+
     fn _example() -> Vec<String> {
         vec![
             String::from("....#....."),
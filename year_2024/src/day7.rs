@@ -1,10 +1,24 @@
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day7.txt");
-    let calculations = Calculation::from_lines(data);
-    let part1 = part1(&calculations);
-    let part2 = part2(&calculations);
+use aoc_utils::parse;
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 
-    format!("{} {}", part1, part2)
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input = Vec<Calculation>;
+
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Calculation::from_lines(lines.to_vec())
+    }
+
+    fn part1(calculations: &Self::Input) -> Answer {
+        part1(calculations).into()
+    }
+
+    fn part2(calculations: &Self::Input) -> Answer {
+        part2(calculations).into()
+    }
 }
 
 fn part1(calculations: &Vec<Calculation>) -> i64 {
@@ -27,29 +41,26 @@ fn part2(calculations: &Vec<Calculation>) -> i64 {
         .sum()
 }
 
-struct Calculation {
+pub struct Calculation {
     result: i64,
     operand: Vec<i64>,
 }
 
 impl Calculation {
-    fn from_lines(lines: Vec<String>) -> Vec<Calculation> {
-        lines
-            .into_iter()
-            .map(|l| Calculation::from_line(l))
-            .collect()
+    fn from_lines(lines: Vec<String>) -> aoc_utils::Result<Vec<Calculation>> {
+        parse::lines(&lines, |l| Calculation::from_line(l.to_string()))
     }
 
-    fn from_line(line: String) -> Calculation {
-        let (result_str, operands_str) = line.split_once(":").unwrap();
-        let result = result_str.parse::<i64>().unwrap();
+    fn from_line(line: String) -> aoc_utils::Result<Calculation> {
+        let (result_str, operands_str) = parse::split_once_or_err(&line, ":")?;
+        let result = parse::value::<i64>(result_str)?;
         let operand = operands_str
             .trim()
             .split(" ")
-            .map(|x| x.parse::<i64>().unwrap())
-            .collect();
+            .map(parse::value::<i64>)
+            .collect::<aoc_utils::Result<_>>()?;
 
-        Calculation { result, operand }
+        Ok(Calculation { result, operand })
     }
 
     fn find_valid_operation<Op: Operator>(&self) -> Option<Operation<Op>> {
@@ -186,12 +197,14 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "8401132154762 95297119227552");
+        let result = aoc_utils::solution::execute::<Day7>().unwrap();
+        assert_eq!(result.part1, 8401132154762);
+        assert_eq!(result.part2, 95297119227552);
     }
 
     #[test]
     fn test_from_line() {
-        let calc = Calculation::from_line("3267: 81 40 27".to_string());
+        let calc = Calculation::from_line("3267: 81 40 27".to_string()).unwrap();
         assert_eq!(calc.result, 3267);
         assert_eq!(calc.operand[0], 81);
         assert_eq!(calc.operand[1], 40);
@@ -207,11 +220,11 @@ mod tests {
 
     #[test]
     fn test_operate() {
-        let calc = Calculation::from_line("0: 10 19".to_string());
+        let calc = Calculation::from_line("0: 10 19".to_string()).unwrap();
         assert_eq!(calc.operate(&ope![Add]), 29);
         assert_eq!(calc.operate(&ope![Mul]), 190);
 
-        let calc = Calculation::from_line("0: 1 5 11".to_string());
+        let calc = Calculation::from_line("0: 1 5 11".to_string()).unwrap();
         assert_eq!(calc.operate(&ope![Add, Add]), 17);
         assert_eq!(calc.operate(&ope![Add, Mul]), 66);
         assert_eq!(calc.operate(&ope![Mul, Add]), 16);
@@ -220,25 +233,25 @@ mod tests {
 
     #[test]
     fn test_find_operations() {
-        let calc = Calculation::from_line("190: 10 19".to_string());
+        let calc = Calculation::from_line("190: 10 19".to_string()).unwrap();
         assert_eq!(calc.find_valid_operation(), Some(ope![Mul]));
 
-        let calc = Calculation::from_line("3267: 81 40 27".to_string());
+        let calc = Calculation::from_line("3267: 81 40 27".to_string()).unwrap();
         assert_eq!(calc.find_valid_operation(), Some(ope![Add, Mul]));
 
-        let calc = Calculation::from_line("292: 11 6 16 20".to_string());
+        let calc = Calculation::from_line("292: 11 6 16 20".to_string()).unwrap();
         assert_eq!(calc.find_valid_operation(), Some(ope![Add, Mul, Add]));
     }
 
     #[test]
     fn test_part1() {
-        let example = Calculation::from_lines(_example());
+        let example = Calculation::from_lines(_example()).unwrap();
         assert_eq!(part1(&example), 3749);
     }
 
     #[test]
     fn test_part2() {
-        let example = Calculation::from_lines(_example());
+        let example = Calculation::from_lines(_example()).unwrap();
         assert_eq!(part2(&example), 11387);
     }
 
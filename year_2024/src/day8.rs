@@ -1,16 +1,27 @@
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::collections::{HashMap, HashSet};
 
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day8.txt");
-    let city = City::from_lines(data);
+pub struct Day8;
 
-    let part1 = city.find_antinodes(false).len();
-    let part2 = city.find_antinodes(true).len();
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Input = City;
 
-    format!("{} {}", part1, part2)
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Ok(City::from_lines(lines.to_vec()))
+    }
+
+    fn part1(city: &Self::Input) -> Answer {
+        city.find_antinodes(false).len().into()
+    }
+
+    fn part2(city: &Self::Input) -> Answer {
+        city.find_antinodes(true).len().into()
+    }
 }
 
-struct City {
+pub struct City {
     size: isize,
     antennas: HashMap<char, Vec<(isize, isize)>>,
 }
@@ -47,7 +58,7 @@ impl City {
 
                     let (start, step) = if part2 {
                         let dtemp = d.clone();
-                        let gcd = aoc_utils::gcd(dtemp.0.abs(), dtemp.1.abs());
+                        let gcd = aoc_utils::number_theory::gcd(dtemp.0.abs(), dtemp.1.abs());
                         (a, (d.0 / gcd, d.1 / gcd))
                     } else {
                         (b, d)
@@ -79,7 +90,9 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), String::from("259 927"));
+        let result = aoc_utils::solution::execute::<Day8>().unwrap();
+        assert_eq!(result.part1, 259);
+        assert_eq!(result.part2, 927);
     }
 
     #[test]
@@ -1,30 +1,38 @@
+use aoc_utils::solution::Solution;
+use aoc_utils::Answer;
 use std::collections::{HashSet, VecDeque};
-use std::fs::read_to_string;
 
-pub fn execute() -> String {
-    let data = read_to_string("input/day9.txt").unwrap();
+pub struct Day9;
 
-    let disk = from_string(data);
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input = VecDeque<Block>;
 
-    let compact1 = compact(&disk);
-    let part1 = checksum(&compact1);
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        from_string(lines.join("\n"))
+    }
 
-    let compact2 = compact2(&disk);
-    let part2 = checksum(&compact2);
+    fn part1(disk: &Self::Input) -> Answer {
+        checksum(&compact(disk)).into()
+    }
 
-    format!("{} {}", part1, part2)
+    fn part2(disk: &Self::Input) -> Answer {
+        checksum(&compact2(disk)).into()
+    }
 }
 
 // (type, length)
 // None is empty space, u32 is a file ID
 #[derive(Clone, Eq, PartialEq, Debug)]
-struct Block(Option<usize>, u32);
+pub struct Block(Option<usize>, u32);
 
-fn from_string(s: String) -> VecDeque<Block> {
+fn from_string(s: String) -> aoc_utils::Result<VecDeque<Block>> {
     let mut result = VecDeque::new();
 
-    for (i, c) in s.chars().enumerate() {
-        let n = c.to_digit(10).unwrap();
+    for (i, c) in s.trim_end().chars().enumerate() {
+        let n = c
+            .to_digit(10)
+            .ok_or_else(|| aoc_utils::Error::parse(&s[i..i + 1], "not a digit"))?;
         if n > 0 {
             if i % 2 == 0 {
                 result.push_back(Block(Some(i / 2), n));
@@ -34,7 +42,7 @@ fn from_string(s: String) -> VecDeque<Block> {
         }
     }
 
-    result
+    Ok(result)
 }
 
 fn compact(input: &VecDeque<Block>) -> VecDeque<Block> {
@@ -133,12 +141,14 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "6359213660505 6381624803796");
+        let result = aoc_utils::solution::execute::<Day9>().unwrap();
+        assert_eq!(result.part1, 6359213660505);
+        assert_eq!(result.part2, 6381624803796);
     }
 
     #[test]
     fn test_from_string() {
-        let example = Vec::from_iter(from_string(_example()));
+        let example = Vec::from_iter(from_string(_example()).unwrap());
         assert_eq!(example.len(), 18);
         assert_eq!(example[0], Block(Some(0), 2));
         assert_eq!(example[1], Block(None, 3));
@@ -152,7 +162,7 @@ mod tests {
 
     #[test]
     fn test_compact() {
-        let example = from_string(_example());
+        let example = from_string(_example()).unwrap();
 
         let compacted = Vec::from_iter(compact(&example));
         assert_eq!(compacted.len(), 13);
@@ -166,7 +176,7 @@ mod tests {
 
     #[test]
     fn test_checksum() {
-        let example = from_string(_example());
+        let example = from_string(_example()).unwrap();
 
         let compacted = compact(&example);
         let checksum1 = checksum(&compacted);
@@ -180,7 +190,7 @@ mod tests {
     }
     #[test]
     fn test_compact2() {
-        let example = from_string(_example());
+        let example = from_string(_example()).unwrap();
 
         let compacted = Vec::from_iter(compact2(&example));
         assert_eq!(compacted.len(), 16);
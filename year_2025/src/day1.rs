@@ -1,20 +1,41 @@
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day1.txt");
+use aoc_utils::Answer;
+use aoc_utils::solution::Solution;
 
-    let part1 = count_zeros(&data, false);
-    let part2 = count_zeros(&data, true);
+pub struct Day1;
 
-    format!("{} {}", part1, part2)
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Input = Vec<(char, i32)>;
+
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        aoc_utils::parse::lines(lines, parse_rotation)
+    }
+
+    fn part1(rotations: &Self::Input) -> Answer {
+        count_zeros(rotations, false).into()
+    }
+
+    fn part2(rotations: &Self::Input) -> Answer {
+        count_zeros(rotations, true).into()
+    }
 }
 
-fn count_zeros(data: &Vec<String>, count_all: bool) -> i32 {
+fn parse_rotation(line: &str) -> aoc_utils::Result<(char, i32)> {
+    let mut chars = line.chars();
+    match chars.next() {
+        Some(dir @ ('L' | 'R')) => Ok((dir, aoc_utils::parse::value(chars.as_str())?)),
+        _ => Err(aoc_utils::Error::parse(
+            line,
+            "expected `L<steps>` or `R<steps>`",
+        )),
+    }
+}
+
+fn count_zeros(rotations: &[(char, i32)], count_all: bool) -> i32 {
     let mut current: i32 = 50;
     let mut count = 0;
 
-    for line in data {
-        let dir = line.chars().nth(0).unwrap();
-        let steps = line[1..].parse::<i32>().unwrap();
-
+    for &(dir, steps) in rotations {
         if count_all {
             count += steps / 100;
         }
@@ -58,13 +79,15 @@ mod tests {
 
     #[test]
     fn test_example() {
-        let data = example();
-        assert_eq!(count_zeros(&data, false), 3);
-        assert_eq!(count_zeros(&data, true), 6);
+        let rotations = Day1::parse(&example()).unwrap();
+        assert_eq!(Day1::part1(&rotations), 3);
+        assert_eq!(Day1::part2(&rotations), 6);
     }
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "1139 6684");
+        let result = aoc_utils::solution::execute::<Day1>().unwrap();
+        assert_eq!(result.part1, 1139);
+        assert_eq!(result.part2, 6684);
     }
 
     fn example() -> Vec<String> {
@@ -1,40 +1,57 @@
+use aoc_utils::Answer;
+use aoc_utils::parse;
 use good_lp::{Expression, Solution, SolverModel, constraint, default_solver, variable, variables};
 use std::collections::{HashSet, VecDeque};
 
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day10.txt");
+pub struct Day10;
 
-    let machines = Machine::from_lines(data);
+impl aoc_utils::solution::Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Vec<Machine>;
 
-    let part1 = machines
-        .iter()
-        .map(|m| m.find_least_presses_to_leds().unwrap())
-        .sum::<usize>();
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Machine::from_lines(lines.to_vec())
+    }
 
-    let part2 = machines
-        .iter()
-        .map(|m| m.find_least_presses_to_joltages().unwrap())
-        .sum::<usize>();
+    fn part1(machines: &Self::Input) -> Answer {
+        machines
+            .iter()
+            .map(|m| m.find_least_presses_to_leds())
+            .sum::<Option<usize>>()
+            .map_or(Answer::Unsolved, Answer::from)
+    }
 
-    format!("{} {}", part1, part2)
+    fn part2(machines: &Self::Input) -> Answer {
+        machines
+            .iter()
+            .map(|m| m.find_least_presses_to_joltages())
+            .sum::<Option<usize>>()
+            .map_or(Answer::Unsolved, Answer::from)
+    }
 }
 
 type LEDs = u16;
 type Joltage = u16;
 
-struct Machine {
+pub struct Machine {
     target: LEDs,
     buttons: Vec<LEDs>,
     joltages: Vec<Joltage>,
 }
 
 impl Machine {
-    fn from_lines(lines: Vec<String>) -> Vec<Self> {
-        lines.into_iter().map(Self::from_line).collect()
+    fn from_lines(lines: Vec<String>) -> aoc_utils::Result<Vec<Self>> {
+        parse::lines(&lines, |line| Self::from_line(line.to_string()))
     }
 
-    fn from_line(line: String) -> Self {
+    fn from_line(line: String) -> aoc_utils::Result<Self> {
         let elements: Vec<&str> = line.split(" ").collect();
+        if elements.len() < 2 || elements.iter().any(|element| element.len() < 2) {
+            return Err(aoc_utils::Error::parse(
+                &line,
+                "expected `[leds] (buttons)... {joltages}`",
+            ));
+        }
 
         let target_str = elements[0][1..elements[0].len() - 1].to_string();
         let target = target_str
@@ -48,23 +65,22 @@ impl Machine {
             .map(|button_str| {
                 button_str[1..button_str.len() - 1]
                     .split(',')
-                    .map(|wire| wire.parse::<u8>().unwrap())
-                    .map(|wire| 1 << wire)
+                    .map(|wire| Ok(1 << parse::value::<u8>(wire)?))
                     .sum()
             })
-            .collect();
+            .collect::<aoc_utils::Result<_>>()?;
 
         let joltages_str = *elements.last().unwrap();
         let joltages = joltages_str[1..joltages_str.len() - 1]
             .split(',')
-            .map(|s| s.parse::<Joltage>().unwrap())
-            .collect();
+            .map(parse::value::<Joltage>)
+            .collect::<aoc_utils::Result<_>>()?;
 
-        Self {
+        Ok(Self {
             target,
             buttons,
             joltages,
-        }
+        })
     }
 
     fn find_least_presses_to_leds(&self) -> Option<usize> {
@@ -135,12 +151,14 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "428 16613");
+        let result = aoc_utils::solution::execute::<Day10>().unwrap();
+        assert_eq!(result.part1, 428);
+        assert_eq!(result.part2, 16613);
     }
 
     #[test]
     fn test_find_least_presses_to_leds() {
-        let machines = Machine::from_lines(example());
+        let machines = Machine::from_lines(example()).unwrap();
         assert_eq!(machines[0].find_least_presses_to_leds(), Some(2));
         assert_eq!(machines[1].find_least_presses_to_leds(), Some(3));
         assert_eq!(machines[2].find_least_presses_to_leds(), Some(2));
@@ -148,7 +166,7 @@ mod tests {
 
     #[test]
     fn test_find_least_presses_to_joltages() {
-        let machines = Machine::from_lines(example());
+        let machines = Machine::from_lines(example()).unwrap();
         assert_eq!(machines[0].find_least_presses_to_joltages(), Some(10));
         assert_eq!(machines[1].find_least_presses_to_joltages(), Some(12));
         assert_eq!(machines[2].find_least_presses_to_joltages(), Some(11));
@@ -156,7 +174,7 @@ mod tests {
 
     #[test]
     fn test_from_lines() {
-        let machines = Machine::from_lines(example());
+        let machines = Machine::from_lines(example()).unwrap();
 
         assert_eq!(machines.len(), 3);
 
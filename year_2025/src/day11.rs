@@ -1,123 +1,67 @@
-use std::collections::{HashMap, VecDeque};
+use aoc_utils::Answer;
+use aoc_utils::graph::Graph;
+use aoc_utils::solution::Solution;
 
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day11.txt");
-    let tangle = from_lines(&data);
+pub struct Day11;
 
-    let part1 = count_paths(&tangle, "you", "out");
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    /// The devices of the reactor, and their outputs.
+    type Input = Graph;
 
-    let part2 = count_paths_trough(
-        &tangle,
-        "svr",
-        "out",
-        vec!["fft".to_string(), "dac".to_string()],
-    );
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        from_lines(lines)
+    }
 
-    format!("{} {}", part1, part2)
-}
+    fn part1(tangle: &Self::Input) -> Answer {
+        count_paths(tangle, "you", "out").into()
+    }
 
-fn from_lines(lines: &[String]) -> HashMap<String, Vec<String>> {
-    lines
-        .iter()
-        .map(|line| {
-            let (from, tos) = line.split_once(": ").unwrap();
-            (
-                from.to_string(),
-                tos.split(" ").map(|to| to.to_string()).collect(),
-            )
-        })
-        .collect()
+    fn part2(tangle: &Self::Input) -> Answer {
+        count_paths_trough(
+            tangle,
+            "svr",
+            "out",
+            vec!["fft".to_string(), "dac".to_string()],
+        )
+        .into()
+    }
 }
 
-fn count_paths(tangle: &HashMap<String, Vec<String>>, start: &str, end: &str) -> usize {
-    let mut to_explore = VecDeque::from([vec![start.to_string()]]);
-    let mut paths = vec![];
-
-    while let Some(current) = to_explore.pop_front() {
-        let current_node = current.last().unwrap();
-        if current_node == end {
-            // println!("{:?}", current);
-            paths.push(current);
-        } else {
-            let next_options = tangle.get(current_node);
-            if next_options.is_some() {
-                for next_option in next_options.unwrap() {
-                    if !current.contains(next_option) {
-                        let mut next = current.clone();
-                        next.push(next_option.clone());
-                        to_explore.push_back(next);
-                    } else {
-                        let mut looped = current.clone();
-                        looped.push(next_option.clone());
-                        println!("Loop {:?}", looped);
-                    }
-                }
-            }
+fn from_lines(lines: &[String]) -> aoc_utils::Result<Graph> {
+    let edges = aoc_utils::parse::lines(lines, |line| {
+        let (from, tos) = aoc_utils::parse::split_once_or_err(line, ": ")?;
+        Ok((from.to_string(), tos.to_string()))
+    })?;
+    let mut tangle = Graph::new();
+    for (from, tos) in edges {
+        for to in tos.split(" ") {
+            tangle.add_edge(&from, to);
         }
     }
-
-    paths.len()
-}
-
-fn count_paths_trough(
-    tangle: &HashMap<String, Vec<String>>,
-    start: &str,
-    end: &str,
-    through: Vec<String>,
-) -> usize {
-    dfs(
-        tangle,
-        &mut vec![start.to_string()],
-        &mut HashMap::new(),
-        end,
-        &through,
-    )
+    Ok(tangle)
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
-struct Key {
-    from: String,
-    via: Vec<String>,
-}
-
-fn dfs(
-    tangle: &HashMap<String, Vec<String>>,
-    current: &mut Vec<String>,
-    seen: &mut HashMap<Key, usize>,
-    end: &str,
-    through: &Vec<String>,
-) -> usize {
-    let here = current.last().unwrap();
-
-    if here == end {
-        return if through.is_empty() { 1 } else { 0 };
+fn count_paths(tangle: &Graph, start: &str, end: &str) -> usize {
+    match (tangle.index(start), tangle.index(end)) {
+        (Some(start), Some(end)) => tangle
+            .count_paths(start, end)
+            .expect("the devices should not loop"),
+        _ => 0,
     }
+}
 
-    let next_through = if through.contains(here) {
-        &through.iter().filter(|&t| t != here).cloned().collect()
-    } else {
-        through
-    };
-
-    let mut found = 0;
-    for next_option in tangle.get(here).unwrap_or(&vec![]) {
-        let key = Key {
-            from: next_option.clone(),
-            via: next_through.clone(),
-        };
-        let cached = seen.get(&key);
-        let result = if cached.is_some() {
-            *cached.unwrap()
-        } else {
-            current.push(next_option.clone());
-            let result = dfs(tangle, current, seen, end, next_through);
-            current.pop();
-            seen.insert(key, result);
-            result
-        };
-        found += result;
+fn count_paths_trough(tangle: &Graph, start: &str, end: &str, through: Vec<String>) -> usize {
+    if through.is_empty() {
+        return count_paths(tangle, start, end);
     }
-    found
+    through
+        .iter()
+        .map(|via| {
+            let others = through.iter().filter(|&t| t != via).cloned().collect();
+            count_paths(tangle, start, via) * count_paths_trough(tangle, via, end, others)
+        })
+        .sum()
 }
 
 #[cfg(test)]
@@ -126,24 +70,26 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "508 315116216513280");
+        let result = aoc_utils::solution::execute::<Day11>().unwrap();
+        assert_eq!(result.part1, 508);
+        assert_eq!(result.part2, 315116216513280);
     }
 
     #[test]
     fn test_count_paths() {
-        let tangle1 = from_lines(&example1());
+        let tangle1 = from_lines(&example1()).unwrap();
 
         assert_eq!(count_paths(&tangle1, "you", "bbb"), 1);
         assert_eq!(count_paths(&tangle1, "you", "ddd"), 2);
         assert_eq!(count_paths(&tangle1, "you", "aaa"), 0);
         assert_eq!(count_paths(&tangle1, "you", "out"), 5);
 
-        let tangle2 = from_lines(&example2());
+        let tangle2 = from_lines(&example2()).unwrap();
         assert_eq!(count_paths(&tangle2, "svr", "out"), 8);
     }
     #[test]
     fn test_count_paths_trough() {
-        let tangle2 = from_lines(&example2());
+        let tangle2 = from_lines(&example2()).unwrap();
         assert_eq!(
             count_paths_trough(
                 &tangle2,
@@ -156,11 +102,20 @@ mod tests {
     }
     #[test]
     fn test_from_lines() {
-        let tangle = from_lines(&example1());
-        assert_eq!(tangle.len(), 10);
-        assert_eq!(tangle["aaa"].len(), 2);
-        assert_eq!(tangle["you"].len(), 2);
-        assert_eq!(tangle["hhh"], vec!["ccc", "fff", "iii"]);
+        let tangle = from_lines(&example1()).unwrap();
+        let neighbours = |name: &str| -> Vec<&str> {
+            let node = tangle.index(name).unwrap();
+            tangle
+                .neighbours(node)
+                .iter()
+                .map(|&next| tangle.name(next))
+                .collect()
+        };
+        assert_eq!(tangle.len(), 11);
+        assert_eq!(neighbours("aaa").len(), 2);
+        assert_eq!(neighbours("you").len(), 2);
+        assert_eq!(neighbours("hhh"), vec!["ccc", "fff", "iii"]);
+        assert_eq!(neighbours("out").len(), 0);
     }
     fn example1() -> Vec<String> {
         vec![
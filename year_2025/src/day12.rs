@@ -1,36 +1,54 @@
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day12.txt");
+use aoc_utils::Answer;
+use aoc_utils::parse;
+use aoc_utils::solution::Solution;
+use std::collections::HashSet;
 
-    let puzzle = Puzzle::from_lines(data);
+pub struct Day12;
 
-    let part1 = puzzle.count_feasible();
-    let part2 = "_";
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Puzzle;
 
-    format!("{} {}", part1, part2)
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Puzzle::from_lines(lines.to_vec())
+    }
+
+    fn part1(puzzle: &Self::Input) -> Answer {
+        puzzle.count_feasible().into()
+    }
+
+    fn part2(_puzzle: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     shapes: Vec<Shape>,
     regions: Vec<Region>,
 }
 
 impl Puzzle {
-    fn from_lines(lines: Vec<String>) -> Self {
-        let mut blocks = lines.split(|line| line.is_empty()).collect::<Vec<_>>();
+    fn from_lines(lines: Vec<String>) -> aoc_utils::Result<Self> {
+        let mut blocks = parse::paragraphs(&lines).collect::<Vec<_>>();
 
-        let regions = blocks
+        let (regions_offset, regions_lines) = blocks
             .pop()
-            .unwrap()
-            .into_iter()
-            .map(|line| Region::from_line(line.clone()))
-            .collect();
-
+            .ok_or_else(|| aoc_utils::Error::parse("", "missing regions"))?;
         let shapes = blocks
             .into_iter()
-            .map(|lines| Shape::from_lines(lines.into()))
-            .collect();
+            .map(|(offset, block)| Shape::from_lines(block.into(), offset))
+            .collect::<aoc_utils::Result<Vec<_>>>()?;
+
+        let regions = parse::lines_at(regions_lines, regions_offset, |line| {
+            let region = Region::from_line(line.to_string())?;
+            if region.shapes_required.len() != shapes.len() {
+                let message = format!("expected a count for each of the {} shapes", shapes.len());
+                return Err(aoc_utils::Error::parse(line, message));
+            }
+            Ok(region)
+        })?;
 
-        Self { shapes, regions }
+        Ok(Self { shapes, regions })
     }
 
     fn count_feasible(&self) -> usize {
@@ -49,14 +67,27 @@ struct Shape {
 }
 
 impl Shape {
-    fn from_lines(lines: Vec<String>) -> Self {
-        let id = lines[0].strip_suffix(":").unwrap().parse().unwrap();
-        let mut bits = [[false; 3]; 3];
-        for (i, line) in lines[1..].iter().enumerate() {
-            for (j, c) in line.chars().enumerate() {
-                bits[i][j] = c == '#';
-            }
+    /// Parse the block of `lines` found after `offset` lines of the input.
+    fn from_lines(lines: Vec<String>, offset: usize) -> aoc_utils::Result<Self> {
+        let [id] =
+            parse::scan("{}:", &lines[0]).map_err(|error| error.at_line(offset + 1, &lines[0]))?;
+        if lines.len() != 4 {
+            let message = format!("expected 3 rows, found {}", lines.len() - 1);
+            return Err(aoc_utils::Error::parse(&lines[0], message).at_line(offset + 1, &lines[0]));
         }
+        let rows = parse::lines_at(&lines[1..], offset + 1, |line| {
+            let row = line
+                .chars()
+                .map(|c| match c {
+                    '#' => Ok(true),
+                    '.' => Ok(false),
+                    _ => Err(aoc_utils::Error::parse(&c.to_string(), "unknown cell")),
+                })
+                .collect::<aoc_utils::Result<Vec<_>>>()?;
+            <[bool; 3]>::try_from(row)
+                .map_err(|_| aoc_utils::Error::parse(line, "expected 3 cells"))
+        })?;
+        let bits = [rows[0], rows[1], rows[2]];
 
         let size = bits
             .iter()
@@ -66,7 +97,35 @@ impl Shape {
                     .sum::<usize>()
             })
             .sum();
-        Self { id, bits, size }
+        Ok(Self { id, bits, size })
+    }
+
+    /// The distinct rotations and flips of the shape, as the (row, column) of
+    /// their cells in row-major order, moved to touch the top and left sides.
+    fn orientations(&self) -> Vec<Vec<(usize, usize)>> {
+        let mut cells = (0..3)
+            .flat_map(|i| (0..3).map(move |j| (i, j)))
+            .filter(|&(i, j)| self.bits[i][j])
+            .collect::<Vec<_>>();
+
+        let mut orientations = Vec::new();
+        for _flip in 0..2 {
+            for _rotation in 0..4 {
+                let top = cells.iter().map(|&(i, _)| i).min().unwrap_or(0);
+                let left = cells.iter().map(|&(_, j)| j).min().unwrap_or(0);
+                let mut oriented = cells
+                    .iter()
+                    .map(|&(i, j)| (i - top, j - left))
+                    .collect::<Vec<_>>();
+                oriented.sort();
+                if !orientations.contains(&oriented) {
+                    orientations.push(oriented);
+                }
+                cells = cells.iter().map(|&(i, j)| (j, 2 - i)).collect();
+            }
+            cells = cells.iter().map(|&(i, j)| (i, 2 - j)).collect();
+        }
+        orientations
     }
 }
 
@@ -77,26 +136,30 @@ struct Region {
 }
 
 impl Region {
-    fn from_line(line: String) -> Self {
-        let (dims, requirements) = line.split_once(": ").unwrap();
-
-        let (width, height) = dims.split_once('x').unwrap();
-        let width = width.parse().unwrap();
-        let height = height.parse().unwrap();
+    fn from_line(line: String) -> aoc_utils::Result<Self> {
+        let [width, height, requirements] = parse::captures("{}x{}: {}", &line)?;
+        let width = parse::value(width)?;
+        let height = parse::value(height)?;
 
         let shapes_required = requirements
             .split_whitespace()
-            .map(|s| s.parse().unwrap())
-            .collect();
+            .map(parse::value)
+            .collect::<aoc_utils::Result<_>>()?;
 
-        Self {
+        Ok(Self {
             width,
             height,
             shapes_required,
-        }
+        })
+    }
+
+    fn is_feasible(&self, shapes: &[Shape]) -> bool {
+        self.packing(shapes).is_some()
     }
 
-    fn is_feasible(&self, shapes: &Vec<Shape>) -> bool {
+    /// Where to place the required shapes so that none of them overlap, if
+    /// they fit in the region.
+    fn packing(&self, shapes: &[Shape]) -> Option<Vec<Placement>> {
         let available_area = self.width * self.height;
         let required_area = self
             .shapes_required
@@ -104,7 +167,167 @@ impl Region {
             .zip(shapes.iter())
             .map(|(n, shape)| n * shape.size)
             .sum();
-        available_area >= required_area
+        if available_area < required_area {
+            return None;
+        }
+
+        let pieces = self.shapes_required.iter().sum::<usize>();
+        if (self.width / 3) * (self.height / 3) >= pieces {
+            return Some(self.side_by_side(shapes));
+        }
+
+        // Fewer cells are undecided at once when going along the short side,
+        // the shapes being turned every way the region can be transposed
+        let transposed = self.width > self.height;
+        let mut packing = Packing {
+            width: self.width.min(self.height),
+            height: self.width.max(self.height),
+            filled: vec![false; available_area],
+            orientations: shapes.iter().map(Shape::orientations).collect(),
+            remaining: self.shapes_required.clone(),
+            slack: available_area - required_area,
+            placements: Vec::new(),
+            dead_ends: HashSet::new(),
+        };
+        if !packing.search(0) {
+            return None;
+        }
+        let mut placements = packing.placements;
+        if transposed {
+            for placement in placements.iter_mut() {
+                for cell in placement.cells.iter_mut() {
+                    *cell = (cell.1, cell.0);
+                }
+            }
+        }
+        Some(placements)
+    }
+
+    /// Each shape in its own 3x3 square, when there is enough of those.
+    fn side_by_side(&self, shapes: &[Shape]) -> Vec<Placement> {
+        let per_row = self.width / 3;
+        let upright = shapes
+            .iter()
+            .map(|shape| shape.orientations().swap_remove(0))
+            .collect::<Vec<_>>();
+        self.shapes_required
+            .iter()
+            .enumerate()
+            .flat_map(|(shape, &count)| std::iter::repeat_n(shape, count))
+            .enumerate()
+            .map(|(k, shape)| {
+                let (top, left) = (3 * (k / per_row), 3 * (k % per_row));
+                let cells = upright[shape]
+                    .iter()
+                    .map(|&(i, j)| (top + i, left + j))
+                    .collect();
+                Placement { shape, cells }
+            })
+            .collect()
+    }
+}
+
+/// A shape placed in a region, with the (row, column) of the cells it covers.
+#[derive(Debug, Clone, PartialEq)]
+struct Placement {
+    shape: usize,
+    cells: Vec<(usize, usize)>,
+}
+
+/// Backtracking over the cells of a region in row-major order: the first
+/// free cell is either covered by the first cell of a shape, or left empty
+/// while there is still some area to spare.
+///
+/// The cells before the first free one are all decided, and the shapes
+/// placed from it reach at most two rows further, so the search from there
+/// only depends on the cells of these three rows and on the remaining shapes:
+/// such states that led nowhere are remembered as dead ends.
+struct Packing {
+    width: usize,
+    height: usize,
+    filled: Vec<bool>,
+    orientations: Vec<Vec<Vec<(usize, usize)>>>,
+    remaining: Vec<usize>,
+    slack: usize,
+    placements: Vec<Placement>,
+    dead_ends: HashSet<(usize, Vec<bool>, Vec<usize>)>,
+}
+
+impl Packing {
+    fn search(&mut self, start: usize) -> bool {
+        if self.remaining.iter().all(|&count| count == 0) {
+            return true;
+        }
+        let Some(position) = (start..self.filled.len()).find(|&k| !self.filled[k]) else {
+            return false;
+        };
+        let (row, column) = (position / self.width, position % self.width);
+        let window_end = self.filled.len().min((row + 3) * self.width);
+        let state = (
+            position,
+            self.filled[position..window_end].to_vec(),
+            self.remaining.clone(),
+        );
+        if self.dead_ends.contains(&state) {
+            return false;
+        }
+
+        for shape in 0..self.remaining.len() {
+            if self.remaining[shape] == 0 {
+                continue;
+            }
+            for orientation in 0..self.orientations[shape].len() {
+                let cells = &self.orientations[shape][orientation];
+                let Some(left) = column.checked_sub(cells[0].1) else {
+                    continue;
+                };
+                if !self.fits(cells, row, left) {
+                    continue;
+                }
+                let cells = cells.iter().map(|&(i, j)| (row + i, left + j)).collect();
+                self.place(Placement { shape, cells });
+                if self.search(position + 1) {
+                    return true;
+                }
+                self.remove_last();
+            }
+        }
+
+        if self.slack > 0 {
+            self.slack -= 1;
+            self.filled[position] = true;
+            if self.search(position + 1) {
+                return true;
+            }
+            self.filled[position] = false;
+            self.slack += 1;
+        }
+        self.dead_ends.insert(state);
+        false
+    }
+
+    /// Whether `cells`, moved by `top` rows and `left` columns, are all free.
+    fn fits(&self, cells: &[(usize, usize)], top: usize, left: usize) -> bool {
+        cells.iter().all(|&(i, j)| {
+            let (i, j) = (top + i, left + j);
+            i < self.height && j < self.width && !self.filled[i * self.width + j]
+        })
+    }
+
+    fn place(&mut self, placement: Placement) {
+        for &(i, j) in placement.cells.iter() {
+            self.filled[i * self.width + j] = true;
+        }
+        self.remaining[placement.shape] -= 1;
+        self.placements.push(placement);
+    }
+
+    fn remove_last(&mut self) {
+        let placement = self.placements.pop().unwrap();
+        for &(i, j) in placement.cells.iter() {
+            self.filled[i * self.width + j] = false;
+        }
+        self.remaining[placement.shape] += 1;
     }
 }
 
@@ -114,20 +337,61 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "531 _");
+        let result = aoc_utils::solution::execute::<Day12>().unwrap();
+        assert_eq!(result.part1, 531);
+        assert_eq!(result.part2, Answer::Unsolved);
     }
 
     #[test]
     fn test_is_feasible() {
-        let puzzle = Puzzle::from_lines(example());
+        let puzzle = Puzzle::from_lines(example()).unwrap();
 
         assert!(puzzle.regions[0].is_feasible(&puzzle.shapes));
         assert!(puzzle.regions[1].is_feasible(&puzzle.shapes));
-        assert!(puzzle.regions[2].is_feasible(&puzzle.shapes));
+        assert!(!puzzle.regions[2].is_feasible(&puzzle.shapes));
+        assert_eq!(puzzle.count_feasible(), 2);
+    }
+
+    #[test]
+    fn test_packing() {
+        let puzzle = Puzzle::from_lines(example()).unwrap();
+        for region in puzzle.regions[..2].iter() {
+            let placements = region.packing(&puzzle.shapes).unwrap();
+
+            let mut counts = vec![0; puzzle.shapes.len()];
+            let mut covered = vec![vec![false; region.width]; region.height];
+            for placement in placements {
+                counts[placement.shape] += 1;
+                assert_eq!(placement.cells.len(), puzzle.shapes[placement.shape].size);
+                for (i, j) in placement.cells {
+                    assert!(!covered[i][j]);
+                    covered[i][j] = true;
+                }
+            }
+            assert_eq!(counts, region.shapes_required);
+        }
+
+        let roomy = Region::from_line(String::from("9x6: 1 1 1 1 1 1")).unwrap();
+        assert_eq!(roomy.packing(&puzzle.shapes).unwrap().len(), 6);
+    }
+
+    #[test]
+    fn test_orientations() {
+        let puzzle = Puzzle::from_lines(example()).unwrap();
+        let counts = puzzle
+            .shapes
+            .iter()
+            .map(|shape| shape.orientations().len())
+            .collect::<Vec<_>>();
+        assert_eq!(counts, vec![8, 8, 2, 4, 4, 2]);
+        assert_eq!(
+            puzzle.shapes[4].orientations()[0],
+            vec![(0, 0), (0, 1), (0, 2), (1, 0), (2, 0), (2, 1), (2, 2)]
+        );
     }
     #[test]
     fn test_puzzle_from_lines() {
-        let puzzle = Puzzle::from_lines(example());
+        let puzzle = Puzzle::from_lines(example()).unwrap();
 
         assert_eq!(puzzle.shapes.len(), 6);
         assert_eq!(puzzle.regions.len(), 3);
@@ -153,7 +417,7 @@ mod tests {
             String::from("##."),
             String::from("##."),
         ];
-        let shape = Shape::from_lines(lines);
+        let shape = Shape::from_lines(lines, 0).unwrap();
         assert_eq!(shape.id, 0);
         assert_eq!(
             shape.bits,
@@ -161,10 +425,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_errors() {
+        let lines = |lines: &[&str]| lines.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let error = |lines| Puzzle::from_lines(lines).err().unwrap().to_string();
+
+        assert_eq!(
+            error(lines(&["0:", "###", "##", "###", "", "3x3: 1"])),
+            "3: expected 3 cells: `##`"
+        );
+        assert_eq!(
+            error(lines(&["0:", "###", "#x#", "###", "", "3x3: 1"])),
+            "3: unknown cell: `x` in `#x#`"
+        );
+        assert_eq!(
+            error(lines(&["0:", "###", "###", "###", "###", "", "3x3: 1"])),
+            "1: expected 3 rows, found 4: `0:`"
+        );
+        assert_eq!(
+            error(lines(&["0:", "###", "###", "###", "", "3x3: 1 1"])),
+            "6: expected a count for each of the 1 shapes: `3x3: 1 1`"
+        );
+    }
+
     #[test]
     fn test_region_from_line() {
         let line = String::from("12x5: 1 0 1 0 2 2");
-        let region = Region::from_line(line);
+        let region = Region::from_line(line).unwrap();
         assert_eq!(region.width, 12);
         assert_eq!(region.height, 5);
         assert_eq!(region.shapes_required, vec![1, 0, 1, 0, 2, 2]);
@@ -1,31 +1,33 @@
-pub fn execute() -> String {
-    let data = aoc_utils::read_line("input/day2.txt");
+use aoc_utils::Answer;
+use aoc_utils::intervals::RangeSet;
+use aoc_utils::parse;
+use aoc_utils::solution::Solution;
 
-    let part1 = get_all_invalids(&data, false).iter().sum::<usize>();
-    let part2 = get_all_invalids(&data, true).iter().sum::<usize>();
+pub struct Day2;
 
-    format!("{} {}", part1, part2)
-}
-type ProductID = usize;
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input = RangeSet<ProductID>;
 
-fn get_all_invalids(data: &String, any_splits: bool) -> Vec<ProductID> {
-    let ranges = parse_ranges(&data);
-    ranges
-        .iter()
-        .flat_map(|range| get_invalid_ids_in_range(range, any_splits))
-        .collect()
-}
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        parse_ranges(&lines.join("\n"))
+    }
 
-fn get_invalid_ids_in_range(range: &(ProductID, ProductID), any_splits: bool) -> Vec<ProductID> {
-    let mut invalids = vec![];
+    fn part1(ranges: &Self::Input) -> Answer {
+        get_all_invalids(ranges, false).iter().sum::<usize>().into()
+    }
 
-    for n in range.0..range.1 + 1 {
-        if !is_valid_id(n, any_splits) {
-            invalids.push(n);
-        }
+    fn part2(ranges: &Self::Input) -> Answer {
+        get_all_invalids(ranges, true).iter().sum::<usize>().into()
     }
+}
+type ProductID = usize;
 
-    invalids
+fn get_all_invalids(ranges: &RangeSet<ProductID>, any_splits: bool) -> Vec<ProductID> {
+    ranges
+        .values()
+        .filter(|&n| !is_valid_id(n, any_splits))
+        .collect()
 }
 
 fn is_valid_id(n: ProductID, any_splits: bool) -> bool {
@@ -58,14 +60,12 @@ fn is_invalid_id(n: ProductID, splits: usize) -> bool {
     true
 }
 
-fn parse_ranges(data: &String) -> Vec<(ProductID, ProductID)> {
-    data.split(',')
+fn parse_ranges(data: &str) -> aoc_utils::Result<RangeSet<ProductID>> {
+    data.trim_end()
+        .split(',')
         .map(|range| {
-            let mut split = range.split('-');
-            (
-                split.next().unwrap().parse::<ProductID>().unwrap(),
-                split.next().unwrap().parse::<ProductID>().unwrap(),
-            )
+            let (first, last) = parse::split_once_or_err(range, "-")?;
+            Ok(parse::value::<ProductID>(first)?..parse::value::<ProductID>(last)? + 1)
         })
         .collect()
 }
@@ -76,25 +76,27 @@ mod tests {
 
     #[test]
     fn test_example() {
-        let data = example();
+        let ranges = parse_ranges(&example()).unwrap();
 
-        let all_invalids = get_all_invalids(&data, false);
+        let all_invalids = get_all_invalids(&ranges, false);
         assert_eq!(all_invalids.iter().sum::<usize>(), 1227775554);
 
-        let all_invalids_really = get_all_invalids(&data, true);
+        let all_invalids_really = get_all_invalids(&ranges, true);
         assert_eq!(all_invalids_really.iter().sum::<usize>(), 4174379265);
     }
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "41294979841 66500947346");
+        let result = aoc_utils::solution::execute::<Day2>().unwrap();
+        assert_eq!(result.part1, 41294979841);
+        assert_eq!(result.part2, 66500947346);
     }
 
     #[test]
     fn test_parse_ranges() {
-        let ranges = parse_ranges(&example());
-        assert_eq!(ranges.len(), 11);
-        for range in ranges {
-            assert!(range.0 < range.1);
+        let ranges = parse_ranges(&example()).unwrap();
+        assert_eq!(ranges.ranges().len(), 11);
+        for range in ranges.ranges() {
+            assert!(range.end - range.start > 1);
         }
     }
 
@@ -1,13 +1,28 @@
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day3.txt");
-    let part1 = get_max_power(&data, 2);
-    let part2 = get_max_power(&data, 12);
+use aoc_utils::Answer;
+use aoc_utils::solution::Solution;
 
-    format!("{} {}", part1, part2)
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    /// The joltage ratings of the batteries of each bank.
+    type Input = Vec<Vec<u8>>;
+
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        parse_data(lines)
+    }
+
+    fn part1(banks: &Self::Input) -> Answer {
+        get_max_power(banks, 2).into()
+    }
+
+    fn part2(banks: &Self::Input) -> Answer {
+        get_max_power(banks, 12).into()
+    }
 }
 
-fn get_max_power(data: &Vec<String>, num_batteries: usize) -> u64 {
-    parse_data(data)
+fn get_max_power(banks: &[Vec<u8>], num_batteries: usize) -> u64 {
+    banks
         .iter()
         .map(|bank| get_best_joltage(bank, num_batteries))
         .collect::<Vec<u64>>()
@@ -15,14 +30,16 @@ fn get_max_power(data: &Vec<String>, num_batteries: usize) -> u64 {
         .sum()
 }
 
-fn parse_data(data: &Vec<String>) -> Vec<Vec<u8>> {
-    data.iter()
-        .map(|line| {
-            line.chars()
-                .map(|c| c.to_digit(10).unwrap() as u8)
-                .collect()
-        })
-        .collect()
+fn parse_data(data: &[String]) -> aoc_utils::Result<Vec<Vec<u8>>> {
+    aoc_utils::parse::lines(data, |line| {
+        line.chars()
+            .map(|c| {
+                c.to_digit(10)
+                    .map(|digit| digit as u8)
+                    .ok_or_else(|| aoc_utils::Error::parse(&c.to_string(), "not a digit"))
+            })
+            .collect()
+    })
 }
 
 fn get_best_joltage(bank: &Vec<u8>, num_batteries: usize) -> u64 {
@@ -59,22 +76,28 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "17144 170371185255900");
+        let result = aoc_utils::solution::execute::<Day3>().unwrap();
+        assert_eq!(result.part1, 17144);
+        assert_eq!(result.part2, 170371185255900);
     }
 
     #[test]
     fn test_example() {
-        assert_eq!(get_max_power(&example(), 2), 357);
-        assert_eq!(get_max_power(&example(), 12), 3121910778619);
+        let banks = parse_data(&example()).unwrap();
+        assert_eq!(get_max_power(&banks, 2), 357);
+        assert_eq!(get_max_power(&banks, 12), 3121910778619);
     }
 
     #[test]
     fn test_parse_data() {
-        let banks = parse_data(&example());
+        let banks = parse_data(&example()).unwrap();
         assert_eq!(banks.len(), 4);
         for bank in banks {
             assert_eq!(bank.len(), 15);
         }
+
+        let error = parse_data(&[String::from("123"), String::from("4x6")]).unwrap_err();
+        assert_eq!(error.to_string(), "2: not a digit: `x` in `4x6`");
     }
 
     #[test]
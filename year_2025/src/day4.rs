@@ -1,71 +1,53 @@
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day4.txt");
-    let parsed = parse_data(&data);
+use aoc_utils::Answer;
+use aoc_utils::grid::Grid;
+use aoc_utils::solution::Solution;
 
-    let part1 = count_accessible_spaces(&parsed);
+pub struct Day4;
 
-    let (all_removed, _) = remove_all_accessible_spaces(get_counts_map(&parsed));
-    let part2 = all_removed;
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    /// Where the rolls of paper are.
+    type Input = Grid<bool>;
 
-    format!("{} {}", part1, part2)
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        parse_data(lines)
+    }
+
+    fn part1(rolls: &Self::Input) -> Answer {
+        count_accessible_spaces(rolls).into()
+    }
+
+    fn part2(rolls: &Self::Input) -> Answer {
+        let (all_removed, _) = remove_all_accessible_spaces(get_counts_map(rolls));
+        all_removed.into()
+    }
 }
 
-fn parse_data(data: &[String]) -> Vec<Vec<bool>> {
-    data.iter()
-        .map(|line| line.chars().map(|c| c == '@').collect())
-        .collect()
+fn parse_data(data: &[String]) -> aoc_utils::Result<Grid<bool>> {
+    Grid::from_lines(data, |c| Ok(c == '@'))
 }
 
-fn count_accessible_spaces(data: &Vec<Vec<bool>>) -> usize {
+fn count_accessible_spaces(data: &Grid<bool>) -> usize {
     let counts = get_counts_map(data);
     counts
         .iter()
-        .flat_map(|row| row.iter().filter(|&count| count.is_some_and(|c| c < 4)))
+        .filter(|(_, count)| count.is_some_and(|c| c < 4))
         .count()
 }
 
-fn get_counts_map(data: &Vec<Vec<bool>>) -> Vec<Vec<Option<usize>>> {
-    let mut counts = vec![vec![None; data[0].len()]; data.len()];
-    for y in 0..data.len() {
-        for x in 0..data[0].len() {
-            if data[y][x] {
-                counts[y][x] = Some(count_surrounding_rolls(data, x, y));
-            }
-        }
-    }
-    counts
+fn get_counts_map(data: &Grid<bool>) -> Grid<Option<usize>> {
+    Grid::from_fn(data.width(), data.height(), |(x, y)| {
+        data[(x, y)].then(|| count_surrounding_rolls(data, x, y))
+    })
 }
 
-fn count_surrounding_rolls(data: &Vec<Vec<bool>>, x: usize, y: usize) -> usize {
-    let mut count = 0;
-    for dx in 0..=2 {
-        // 0 means -1
-        for dy in 0..=2 {
-            if dx == 1 && dy == 1 {
-                continue;
-            }
-            if x == 0 && dx == 0 {
-                continue;
-            }
-            if y == 0 && dy == 0 {
-                continue;
-            }
-            let new_x = x + dx - 1;
-            let new_y = y + dy - 1;
-            if new_y >= data.len() || new_x >= data[new_y].len() {
-                continue;
-            }
-            if data[new_y][new_x] {
-                count += 1;
-            }
-        }
-    }
-    count
+fn count_surrounding_rolls(data: &Grid<bool>, x: usize, y: usize) -> usize {
+    data.neighbours8((x, y))
+        .filter(|&position| data[position])
+        .count()
 }
 
-fn remove_all_accessible_spaces(
-    mut counts: Vec<Vec<Option<usize>>>,
-) -> (usize, Vec<Vec<Option<usize>>>) {
+fn remove_all_accessible_spaces(mut counts: Grid<Option<usize>>) -> (usize, Grid<Option<usize>>) {
     let mut removed = 0;
     let mut removed_now: usize;
     loop {
@@ -77,39 +59,18 @@ fn remove_all_accessible_spaces(
     }
     (removed, counts)
 }
-fn remove_accessible_spaces(
-    mut counts: Vec<Vec<Option<usize>>>,
-) -> (usize, Vec<Vec<Option<usize>>>) {
-    let mut to_remove = vec![];
-    for y in 0..counts.len() {
-        for x in 0..counts[y].len() {
-            if counts[y][x].is_some_and(|c| c < 4) {
-                to_remove.push((x, y));
-            }
-        }
-    }
-    for &(x, y) in to_remove.iter() {
-        counts[y][x] = None;
-        for dx in 0..=2 {
-            for dy in 0..=2 {
-                if dx == 1 && dy == 1 {
-                    continue;
-                }
-                if x == 0 && dx == 0 {
-                    continue;
-                }
-                if y == 0 && dy == 0 {
-                    continue;
-                }
-                let new_x = x + dx - 1;
-                let new_y = y + dy - 1;
-                if new_y >= counts.len() || new_x >= counts[new_y].len() {
-                    continue;
-                }
-                if counts[new_y][new_x].is_some() {
-                    let new_count = counts[new_y][new_x].unwrap() - 1;
-                    counts[new_y][new_x] = Some(new_count);
-                }
+fn remove_accessible_spaces(mut counts: Grid<Option<usize>>) -> (usize, Grid<Option<usize>>) {
+    let to_remove = counts
+        .iter()
+        .filter(|(_, count)| count.is_some_and(|c| c < 4))
+        .map(|(position, _)| position)
+        .collect::<Vec<_>>();
+    for &position in to_remove.iter() {
+        counts[position] = None;
+        let neighbours = counts.neighbours8(position).collect::<Vec<_>>();
+        for neighbour in neighbours {
+            if let Some(count) = counts[neighbour].as_mut() {
+                *count -= 1;
             }
         }
     }
@@ -123,46 +84,46 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "1489 8890");
+        let result = aoc_utils::solution::execute::<Day4>().unwrap();
+        assert_eq!(result.part1, 1489);
+        assert_eq!(result.part2, 8890);
     }
 
     #[test]
     fn test_parse_data() {
-        let parsed = parse_data(example().as_slice());
-        assert_eq!(parsed.len(), 10);
-        for row in parsed.iter() {
-            assert_eq!(row.len(), 10);
-        }
-        assert!(!parsed[0][0]);
-        assert!(!parsed[0][1]);
-        assert!(parsed[0][2]);
-        assert!(parsed[1][0]);
-        assert!(parsed[2][0]);
-        assert!(parsed[9][8]);
-        assert!(!parsed[8][9]);
-        assert!(!parsed[9][9]);
+        let parsed = parse_data(example().as_slice()).unwrap();
+        assert_eq!(parsed.height(), 10);
+        assert_eq!(parsed.width(), 10);
+        assert!(!parsed[(0, 0)]);
+        assert!(!parsed[(1, 0)]);
+        assert!(parsed[(2, 0)]);
+        assert!(parsed[(0, 1)]);
+        assert!(parsed[(0, 2)]);
+        assert!(parsed[(8, 9)]);
+        assert!(!parsed[(9, 8)]);
+        assert!(!parsed[(9, 9)]);
     }
 
     #[test]
     fn test_example() {
-        let parsed = parse_data(example().as_slice());
+        let parsed = parse_data(example().as_slice()).unwrap();
         assert_eq!(count_accessible_spaces(&parsed), 13);
 
         let counts_map = get_counts_map(&parsed);
 
         let (removed, counts_map) = remove_accessible_spaces(counts_map);
         assert_eq!(removed, 13);
-        assert_eq!(counts_map[0][2], None);
-        assert_eq!(counts_map[0][3], None);
-        assert_eq!(counts_map[1][0], None);
-        assert_eq!(counts_map[2][0], Some(3));
-        assert_eq!(counts_map[8][8], Some(4));
-        assert_eq!(counts_map[9][8], None);
+        assert_eq!(counts_map[(2, 0)], None);
+        assert_eq!(counts_map[(3, 0)], None);
+        assert_eq!(counts_map[(0, 1)], None);
+        assert_eq!(counts_map[(0, 2)], Some(3));
+        assert_eq!(counts_map[(8, 8)], Some(4));
+        assert_eq!(counts_map[(8, 9)], None);
 
         let (removed, counts_map) = remove_accessible_spaces(counts_map);
         assert_eq!(removed, 12);
-        assert_eq!(counts_map[2][0], None);
-        assert_eq!(counts_map[8][8], Some(4));
+        assert_eq!(counts_map[(0, 2)], None);
+        assert_eq!(counts_map[(8, 8)], Some(4));
 
         let counts_map = get_counts_map(&parsed);
         let (removed, _counts_map) = remove_all_accessible_spaces(counts_map);
@@ -171,7 +132,7 @@ mod tests {
 
     #[test]
     fn test_count_surrounding_rolls() {
-        let parsed = parse_data(example().as_slice());
+        let parsed = parse_data(example().as_slice()).unwrap();
         assert_eq!(count_surrounding_rolls(&parsed, 0, 0), 2);
         assert_eq!(count_surrounding_rolls(&parsed, 1, 0), 4);
         assert_eq!(count_surrounding_rolls(&parsed, 2, 0), 3);
@@ -1,102 +1,68 @@
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day5.txt");
-    let inventory = Inventory::from_lines(data);
+use aoc_utils::Answer;
+use aoc_utils::intervals::RangeSet;
+use aoc_utils::parse;
+use aoc_utils::solution::Solution;
 
-    let part1 = inventory.count_available_fresh();
-    let part2 = inventory.count_all_fresh();
+pub struct Day5;
 
-    format!("{} {}", part1, part2)
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input = Inventory;
+
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Inventory::from_lines(lines.to_vec())
+    }
+
+    fn part1(inventory: &Self::Input) -> Answer {
+        inventory.count_available_fresh().into()
+    }
+
+    fn part2(inventory: &Self::Input) -> Answer {
+        inventory.count_all_fresh().into()
+    }
 }
 
-struct Inventory {
-    fresh_ranges: Vec<(usize, usize)>,
+pub struct Inventory {
+    fresh: RangeSet<usize>,
     available: Vec<usize>,
 }
 
 impl Inventory {
-    fn from_lines(lines: Vec<String>) -> Self {
-        let mut fresh_ranges = vec![];
-        let mut available = vec![];
-
-        let mut found_separator = false;
-
-        for line in lines {
-            if line.is_empty() {
-                found_separator = true;
-            } else if !found_separator {
-                let split = line.split_once('-').unwrap();
-                let a = split.0.parse::<usize>().unwrap();
-                let b = split.1.parse::<usize>().unwrap();
-                assert!(a <= b, "Invalid range: {}-{}", a, b);
-                fresh_ranges.push((a, b));
-            } else {
-                available.push(line.parse::<usize>().unwrap());
+    fn from_lines(lines: Vec<String>) -> aoc_utils::Result<Self> {
+        let separator = lines
+            .iter()
+            .position(|line| line.is_empty())
+            .unwrap_or(lines.len());
+
+        let fresh_ranges = parse::lines(&lines[..separator], |line| {
+            let (a, b) = parse::split_once_or_err(line, "-")?;
+            let (a, b) = (parse::value::<usize>(a)?, parse::value::<usize>(b)?);
+            if a > b {
+                return Err(aoc_utils::Error::parse(line, "invalid range"));
             }
-        }
-        Self {
-            fresh_ranges,
+            Ok(a..b + 1)
+        })?;
+        let available = parse::lines_at(
+            lines.get(separator + 1..).unwrap_or(&[]),
+            separator + 1,
+            parse::value::<usize>,
+        )?;
+
+        Ok(Self {
+            fresh: fresh_ranges.into_iter().collect(),
             available,
-        }
+        })
     }
 
     fn count_available_fresh(&self) -> usize {
-        let mut count = 0;
-        for &available in self.available.iter() {
-            if self
-                .fresh_ranges
-                .iter()
-                .any(|range| range.0 <= available && available <= range.1)
-            {
-                count += 1;
-            }
-        }
-        count
-    }
-
-    fn count_all_fresh(&self) -> usize {
-        let simplified = self.simplify();
-        simplified
-            .fresh_ranges
+        self.available
             .iter()
-            .map(|range| range.1 - range.0 + 1)
-            .sum::<usize>()
+            .filter(|&&available| self.fresh.contains(available))
+            .count()
     }
 
-    fn simplify(&self) -> Self {
-        let mut to_process = self.fresh_ranges.clone();
-        to_process.sort_by(|a, b| {
-            if a.0 != b.0 {
-                a.0.cmp(&b.0)
-            } else {
-                a.1.cmp(&b.1)
-            }
-        });
-
-        let mut fresh_ranges = vec![];
-
-        let mut i = 0;
-        let mut current = (0usize, 0usize);
-        while i < to_process.len() {
-            if i == 0 {
-                current = to_process[i];
-            } else {
-                if to_process[i].0 <= current.1 + 1 {
-                    if to_process[i].1 > current.1 {
-                        current = (current.0, to_process[i].1);
-                    }
-                } else {
-                    fresh_ranges.push(current);
-                    current = to_process[i];
-                }
-            }
-            i += 1;
-        }
-        fresh_ranges.push(current);
-
-        Self {
-            fresh_ranges,
-            available: self.available.clone(),
-        }
+    fn count_all_fresh(&self) -> usize {
+        self.fresh.len()
     }
 }
 
@@ -106,37 +72,27 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "698 352807801032167");
+        let result = aoc_utils::solution::execute::<Day5>().unwrap();
+        assert_eq!(result.part1, 698);
+        assert_eq!(result.part2, 352807801032167);
     }
 
     #[test]
     fn test_count_all_fresh() {
-        let inventory = Inventory::from_lines(example());
+        let inventory = Inventory::from_lines(example()).unwrap();
         assert_eq!(inventory.count_all_fresh(), 14);
     }
 
-    #[test]
-    fn test_simplify() {
-        let inventory = Inventory::from_lines(example());
-        let simplified = inventory.simplify();
-
-        assert_eq!(simplified.fresh_ranges, vec![(3, 5), (10, 20)]);
-        assert_eq!(simplified.count_available_fresh(), 3);
-    }
-
     #[test]
     fn test_count_available_fresh() {
-        let inventory = Inventory::from_lines(example());
+        let inventory = Inventory::from_lines(example()).unwrap();
         assert_eq!(inventory.count_available_fresh(), 3);
     }
 
     #[test]
     fn test_from_lines() {
-        let inventory = Inventory::from_lines(example());
-        assert_eq!(
-            inventory.fresh_ranges,
-            vec![(3, 5), (10, 14), (16, 20), (12, 18)]
-        );
+        let inventory = Inventory::from_lines(example()).unwrap();
+        assert_eq!(inventory.fresh.ranges(), &[3..6, 10..21]);
         assert_eq!(inventory.available, vec![1, 5, 8, 11, 17, 32]);
     }
 
@@ -1,67 +1,109 @@
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day6.txt");
+use aoc_utils::Answer;
+use aoc_utils::solution::Solution;
 
-    let homework = Homework::from_lines(&data);
-    let part1 = homework.row_math();
-    let cephalopod_homework = Homework::from_columns(&data);
-    let part2 = cephalopod_homework.column_math();
+pub struct Day6;
 
-    format!("{} {}", part1, part2)
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    /// The homework read by rows, and as cephalopods read it, by columns.
+    type Input = (Homework, Homework);
+
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Ok((Homework::from_lines(lines)?, Homework::from_columns(lines)?))
+    }
+
+    fn part1((homework, _): &Self::Input) -> Answer {
+        homework.row_math().into()
+    }
+
+    fn part2((_, cephalopod_homework): &Self::Input) -> Answer {
+        cephalopod_homework.column_math().into()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Operator {
+    Add,
+    Multiply,
 }
 
-struct Homework {
+impl Operator {
+    fn from_text(text: &str) -> aoc_utils::Result<Operator> {
+        match text {
+            "+" => Ok(Operator::Add),
+            "*" => Ok(Operator::Multiply),
+            _ => Err(aoc_utils::Error::parse(text, "invalid operator")),
+        }
+    }
+
+    fn apply<'a, I: Iterator<Item = &'a u64>>(&self, operands: I) -> u64 {
+        match self {
+            Operator::Add => operands.sum(),
+            Operator::Multiply => operands.product(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Homework {
     operands: Vec<Vec<u64>>,
-    operators: Vec<char>,
+    operators: Vec<Operator>,
 }
 
 impl Homework {
-    fn from_lines(lines: &Vec<String>) -> Self {
-        Self {
-            operands: lines[0..lines.len() - 1]
-                .iter()
-                .map(|line| {
-                    line.trim()
-                        .split_whitespace()
-                        .map(|operand| operand.parse::<u64>().unwrap())
-                        .collect()
-                })
-                .collect(),
-            operators: lines
-                .last()
-                .unwrap()
-                .trim()
+    fn from_lines(lines: &[String]) -> aoc_utils::Result<Self> {
+        let (operand_lines, operators) = Self::split_operators(lines)?;
+        let operands = aoc_utils::parse::lines(operand_lines, |line| {
+            let operands = line
                 .split_whitespace()
-                .map(|operator| operator.chars().nth(0).unwrap())
-                .collect(),
-        }
+                .map(aoc_utils::parse::value)
+                .collect::<aoc_utils::Result<Vec<u64>>>()?;
+            if operands.len() != operators.len() {
+                return Err(aoc_utils::Error::parse(
+                    line,
+                    format!("expected {} operands", operators.len()),
+                ));
+            }
+            Ok(operands)
+        })?;
+        Ok(Self {
+            operands,
+            operators,
+        })
+    }
+
+    /// The lines of operands, and the operators parsed from the last line.
+    fn split_operators(lines: &[String]) -> aoc_utils::Result<(&[String], Vec<Operator>)> {
+        let (operator_line, operand_lines) = lines
+            .split_last()
+            .ok_or_else(|| aoc_utils::Error::parse("", "missing operators"))?;
+        let operators = operator_line
+            .split_whitespace()
+            .map(Operator::from_text)
+            .collect::<aoc_utils::Result<_>>()
+            .map_err(|error| error.at_line(lines.len(), operator_line))?;
+        Ok((operand_lines, operators))
     }
 
     fn row_math(&self) -> u64 {
         self.operators
             .iter()
             .enumerate()
-            .map(|(i, operator)| -> u64 {
-                match operator {
-                    '*' => self.operands.iter().map(|operands| operands[i]).product(),
-                    '+' => self.operands.iter().map(|operands| operands[i]).sum(),
-                    _ => panic!("Invalid operator {operator}"),
-                }
-            })
+            .map(|(i, operator)| operator.apply(self.operands.iter().map(|operands| &operands[i])))
             .sum()
     }
 
-    fn from_columns(lines: &Vec<String>) -> Self {
-        let width = lines.iter().map(|line| line.len()).max().unwrap();
-        let starts = lines
-            .last()
-            .unwrap()
+    fn from_columns(lines: &[String]) -> aoc_utils::Result<Self> {
+        let (operand_lines, operators) = Self::split_operators(lines)?;
+        let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+        let starts = lines[lines.len() - 1]
             .chars()
             .enumerate()
             .filter(|(_i, c)| *c != ' ')
             .map(|(i, _c)| i)
             .collect::<Vec<usize>>();
 
-        let rows: Vec<_> = lines
+        let rows: Vec<_> = operand_lines
             .iter()
             .map(|line| line.chars().collect::<Vec<_>>())
             .collect();
@@ -77,43 +119,41 @@ impl Homework {
 
             for operand_index in 0..end - start {
                 let mut operand = 0;
-                for row in 0..lines.len() - 1 {
-                    let c = rows[row][start + operand_index];
+                for (i, (row, line)) in rows.iter().zip(operand_lines).enumerate() {
+                    let c = row.get(start + operand_index).copied().unwrap_or(' ');
                     if c != ' ' {
-                        operand = operand * 10 + (c as u8 - b'0') as u64;
+                        let digit = c.to_digit(10).ok_or_else(|| {
+                            aoc_utils::Error::parse(&c.to_string(), "not a digit")
+                                .at_line(i + 1, line)
+                        })?;
+                        operand = operand * 10 + digit as u64;
                     }
                 }
-                assert_ne!(
-                    operand, 0,
-                    "Invalid operand at index {operand_index} in operation {operation_index} ({end}-{start})"
-                );
+                if operand == 0 {
+                    return Err(aoc_utils::Error::parse(
+                        &lines[lines.len() - 1],
+                        format!(
+                            "no operand in column {} of operation {}",
+                            start + operand_index + 1,
+                            operation_index + 1
+                        ),
+                    ));
+                }
                 operands[operation_index].push(operand);
             }
         }
 
-        Self {
+        Ok(Self {
             operands,
-            operators: lines
-                .last()
-                .unwrap()
-                .trim()
-                .split_whitespace()
-                .map(|operator| operator.chars().nth(0).unwrap())
-                .collect(),
-        }
+            operators,
+        })
     }
 
     fn column_math(&self) -> u64 {
         self.operators
             .iter()
             .zip(self.operands.iter())
-            .map(|(operator, operands)| -> u64 {
-                match operator {
-                    '*' => operands.iter().product(),
-                    '+' => operands.iter().sum(),
-                    _ => panic!("Invalid operator {operator}"),
-                }
-            })
+            .map(|(operator, operands)| operator.apply(operands.iter()))
             .sum()
     }
 }
@@ -124,12 +164,14 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "5595593539811 10153315705125");
+        let result = aoc_utils::solution::execute::<Day6>().unwrap();
+        assert_eq!(result.part1, 5595593539811);
+        assert_eq!(result.part2, 10153315705125);
     }
 
     #[test]
     fn test_from_lines() {
-        let homework = Homework::from_lines(&example());
+        let homework = Homework::from_lines(&example()).unwrap();
 
         assert_eq!(homework.operands.len(), 3);
         for operands in homework.operands.iter() {
@@ -140,19 +182,19 @@ mod tests {
         assert_eq!(homework.operands[2], vec![6, 98, 215, 314]);
 
         assert_eq!(homework.operators.len(), 4);
-        assert_eq!(homework.operators, vec!['*', '+', '*', '+']);
+        assert_eq!(homework.operators, example_operators());
     }
 
     #[test]
     fn test_row_math() {
-        let homework = Homework::from_lines(&example());
+        let homework = Homework::from_lines(&example()).unwrap();
 
         assert_eq!(homework.row_math(), 4277556);
     }
 
     #[test]
     fn test_from_columns() {
-        let homework = Homework::from_columns(&example());
+        let homework = Homework::from_columns(&example()).unwrap();
 
         assert_eq!(homework.operands.len(), 4);
 
@@ -162,15 +204,53 @@ mod tests {
         assert_eq!(homework.operands[3], vec![623, 431, 4]);
 
         assert_eq!(homework.operators.len(), 4);
-        assert_eq!(homework.operators, vec!['*', '+', '*', '+']);
+        assert_eq!(homework.operators, example_operators());
     }
 
     #[test]
     fn test_column_math() {
-        let homework = Homework::from_columns(&example());
+        let homework = Homework::from_columns(&example()).unwrap();
 
         assert_eq!(homework.column_math(), 3263827);
     }
+
+    #[test]
+    fn test_parse_errors() {
+        let lines = |lines: &[&str]| lines.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        assert!(Homework::from_lines(&[]).is_err());
+        assert!(Homework::from_columns(&[]).is_err());
+        assert_eq!(
+            Homework::from_lines(&lines(&["1 2", "+ -"]))
+                .unwrap_err()
+                .to_string(),
+            "2: invalid operator: `-` in `+ -`"
+        );
+        assert_eq!(
+            Homework::from_lines(&lines(&["1 x", "+ *"]))
+                .unwrap_err()
+                .to_string(),
+            "1: invalid digit found in string: `x` in `1 x`"
+        );
+        assert_eq!(
+            Homework::from_lines(&lines(&["1 2", "3", "+ *"]))
+                .unwrap_err()
+                .to_string(),
+            "2: expected 2 operands: `3`"
+        );
+        assert_eq!(
+            Homework::from_columns(&lines(&["1 2", "3 x", "+ *"]))
+                .unwrap_err()
+                .to_string(),
+            "2: not a digit: `x` in `3 x`"
+        );
+    }
+
+    fn example_operators() -> Vec<Operator> {
+        use Operator::*;
+        vec![Multiply, Add, Multiply, Add]
+    }
+
     fn example() -> Vec<String> {
         vec![
             String::from("123 328  51 64 "),
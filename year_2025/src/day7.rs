@@ -1,14 +1,26 @@
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day7.txt");
-    let manifold = Manifold::from_lines(data);
+use aoc_utils::Answer;
+use aoc_utils::solution::Solution;
 
-    let part1 = manifold.count_splits();
-    let part2 = manifold.count_quantum_timelines();
+pub struct Day7;
 
-    format!("{} {}", part1, part2)
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input = Manifold;
+
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        Ok(Manifold::from_lines(lines.to_vec()))
+    }
+
+    fn part1(manifold: &Self::Input) -> Answer {
+        manifold.count_splits().into()
+    }
+
+    fn part2(manifold: &Self::Input) -> Answer {
+        manifold.count_quantum_timelines().into()
+    }
 }
 
-struct Manifold {
+pub struct Manifold {
     start: usize,
     layers: Vec<Vec<usize>>,
 }
@@ -91,7 +103,9 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "1613 48021610271997");
+        let result = aoc_utils::solution::execute::<Day7>().unwrap();
+        assert_eq!(result.part1, 1613);
+        assert_eq!(result.part2, 48021610271997);
     }
 
     #[test]
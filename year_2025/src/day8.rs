@@ -1,147 +1,80 @@
-use std::collections::HashMap;
+use aoc_utils::Answer;
+use aoc_utils::graph::DisjointSet;
+use aoc_utils::solution::Solution;
 
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day8.txt");
+pub struct Day8;
 
-    let mut boxes1 = JunctionBox::from_lines(&data);
-    add_n_connections(&mut boxes1, 1000);
-    let part1 = count_circuits(&boxes1);
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Input = Vec<JunctionBox>;
 
-    let mut boxes2 = JunctionBox::from_lines(&data);
-    let (a, b) = find_closing_connection(&mut boxes2);
-    let part2 = a.x * b.x;
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        JunctionBox::from_lines(lines)
+    }
+
+    fn part1(boxes: &Self::Input) -> Answer {
+        let circuits = add_n_connections(boxes, 1000);
+        count_circuits(&circuits).into()
+    }
 
-    format!("{} {}", part1, part2)
+    fn part2(boxes: &Self::Input) -> Answer {
+        let (a, b) = find_closing_connection(boxes);
+        (a.x * b.x).into()
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
-struct JunctionBox {
+pub struct JunctionBox {
     x: i64,
     y: i64,
     z: i64,
-    circuit: Option<u32>,
 }
 
 impl JunctionBox {
-    fn from_lines(lines: &Vec<String>) -> Vec<Self> {
-        lines.into_iter().map(Self::from_line).collect()
+    fn from_lines(lines: &[String]) -> aoc_utils::Result<Vec<Self>> {
+        aoc_utils::parse::lines(lines, Self::from_line)
     }
 
-    fn from_line(line: &String) -> Self {
-        let coords: Vec<_> = line
+    fn from_line(line: &str) -> aoc_utils::Result<Self> {
+        let coords = line
             .split(',')
-            .map(|coord| coord.parse().unwrap())
-            .collect();
-        Self {
-            x: coords[0],
-            y: coords[1],
-            z: coords[2],
-            circuit: None,
-        }
+            .map(aoc_utils::parse::value)
+            .collect::<aoc_utils::Result<Vec<_>>>()?;
+        let [x, y, z] = coords[..] else {
+            return Err(aoc_utils::Error::parse(line, "expected 3 coordinates"));
+        };
+        Ok(Self { x, y, z })
     }
 }
 
-fn count_circuits(junction_boxes: &Vec<JunctionBox>) -> usize {
+fn count_circuits(circuits: &DisjointSet) -> usize {
     let n_circuits = 3;
 
-    let mut counts = HashMap::new();
-    for abox in junction_boxes {
-        if abox.circuit.is_some() {
-            counts
-                .entry(abox.circuit.unwrap())
-                .and_modify(|count| *count += 1)
-                .or_insert(1usize);
-        }
-    }
-
-    let mut largest = vec![0; n_circuits];
-
-    for count in counts.values() {
-        if *count > largest[0] {
-            largest[0] = *count;
-            largest.sort_unstable();
-        }
-    }
-
-    largest.iter().product()
+    let mut sizes = circuits.set_sizes();
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+    sizes.iter().take(n_circuits).product()
 }
 
-fn add_n_connections(junction_boxes: &mut Vec<JunctionBox>, n: usize) {
-    let connections = shortest_distances(junction_boxes);
-
-    let mut net_id = 0;
-    let mut remaining = n;
-    for (i, j) in connections {
-        if junction_boxes[i].circuit.is_none() && junction_boxes[j].circuit.is_none() {
-            net_id += 1;
-            junction_boxes[i].circuit = Some(net_id);
-            junction_boxes[j].circuit = Some(net_id);
-        } else if junction_boxes[i].circuit.is_none() {
-            junction_boxes[i].circuit = junction_boxes[j].circuit.clone();
-        } else if junction_boxes[j].circuit.is_none() {
-            junction_boxes[j].circuit = junction_boxes[i].circuit.clone();
-        } else if junction_boxes[i].circuit != junction_boxes[j].circuit {
-            let merged_circuit = junction_boxes[j].circuit;
-            let remaining_circuit = junction_boxes[i].circuit;
-            for abox in junction_boxes.iter_mut() {
-                if abox.circuit == merged_circuit {
-                    abox.circuit = remaining_circuit;
-                }
-            }
-        }
-
-        remaining -= 1;
-        if remaining == 0 {
-            break;
-        }
+fn add_n_connections(junction_boxes: &[JunctionBox], n: usize) -> DisjointSet {
+    let mut circuits = DisjointSet::new(junction_boxes.len());
+    for (i, j) in shortest_distances(junction_boxes).into_iter().take(n) {
+        circuits.union(i, j);
     }
+    circuits
 }
 
-fn find_closing_connection(junction_boxes: &mut Vec<JunctionBox>) -> (JunctionBox, JunctionBox) {
-    let connections = shortest_distances(junction_boxes);
-
-    let mut net_id = 0;
-    let mut unconnected = junction_boxes.len();
-    for (i, j) in connections {
-        if junction_boxes[i].circuit.is_none() && junction_boxes[j].circuit.is_none() {
-            net_id += 1;
-            junction_boxes[i].circuit = Some(net_id);
-            junction_boxes[j].circuit = Some(net_id);
-            unconnected -= 2;
-        } else if junction_boxes[i].circuit.is_none() {
-            junction_boxes[i].circuit = junction_boxes[j].circuit.clone();
-            unconnected -= 1;
-        } else if junction_boxes[j].circuit.is_none() {
-            junction_boxes[j].circuit = junction_boxes[i].circuit.clone();
-            unconnected -= 1;
-        } else if junction_boxes[i].circuit != junction_boxes[j].circuit {
-            let merged_circuit = junction_boxes[j].circuit;
-            let remaining_circuit = junction_boxes[i].circuit;
-            for abox in junction_boxes.iter_mut() {
-                if abox.circuit == merged_circuit {
-                    abox.circuit = remaining_circuit;
-                }
-            }
-        } else {
-            continue;
-        }
-
-        if unconnected == 0 {
-            let net_id = junction_boxes.first().unwrap().circuit.unwrap();
-
-            if junction_boxes
-                .iter()
-                .all(|abox| abox.circuit == Some(net_id))
-            {
-                return (junction_boxes[i], junction_boxes[j]);
-            }
+fn find_closing_connection(junction_boxes: &[JunctionBox]) -> (JunctionBox, JunctionBox) {
+    let mut circuits = DisjointSet::new(junction_boxes.len());
+    for (i, j) in shortest_distances(junction_boxes) {
+        if circuits.union(i, j) && circuits.set_count() == 1 {
+            return (junction_boxes[i], junction_boxes[j]);
         }
     }
 
     panic!("No connection found");
 }
 
-fn shortest_distances(junction_boxes: &Vec<JunctionBox>) -> Vec<(usize, usize)> {
+fn shortest_distances(junction_boxes: &[JunctionBox]) -> Vec<(usize, usize)> {
     let mut all_dists = vec![vec![-1; junction_boxes.len()]; junction_boxes.len()];
     for i in 0..junction_boxes.len() - 1 {
         let a = &junction_boxes[i];
@@ -188,32 +121,33 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "117000 8368033065");
+        let result = aoc_utils::solution::execute::<Day8>().unwrap();
+        assert_eq!(result.part1, 117000);
+        assert_eq!(result.part2, 8368033065);
     }
 
     #[test]
     fn test_count_circuits() {
-        let mut boxes = JunctionBox::from_lines(&example());
-        add_n_connections(&mut boxes, 10);
+        let boxes = JunctionBox::from_lines(&example()).unwrap();
+        let circuits = add_n_connections(&boxes, 10);
 
-        assert_eq!(count_circuits(&boxes), 40);
+        assert_eq!(count_circuits(&circuits), 40);
     }
     #[test]
     fn test_add_n_connections() {
-        let mut boxes = JunctionBox::from_lines(&example());
-        add_n_connections(&mut boxes, 10);
-
-        assert_eq!(boxes[0].circuit, Some(1));
-        assert_eq!(boxes[19].circuit, Some(1));
+        let boxes = JunctionBox::from_lines(&example()).unwrap();
+        let mut circuits = add_n_connections(&boxes, 10);
 
-        assert_eq!(boxes[0].circuit, Some(1));
-        assert_eq!(boxes[19].circuit, Some(1));
+        assert!(circuits.same_set(0, 19));
+        assert!(circuits.same_set(0, 7));
+        assert_eq!(circuits.set_size(0), 4);
+        assert_eq!(circuits.set_count(), 11);
     }
 
     #[test]
     fn test_find_closing_connection() {
-        let mut boxes = JunctionBox::from_lines(&example());
-        let (a, b) = find_closing_connection(&mut boxes);
+        let boxes = JunctionBox::from_lines(&example()).unwrap();
+        let (a, b) = find_closing_connection(&boxes);
         assert_eq!(a.x, 216);
         assert_eq!(b.x, 117);
         assert_eq!(a.x * b.x, 25272);
@@ -221,7 +155,7 @@ mod tests {
 
     #[test]
     fn test_shortest_distances() {
-        let boxes = JunctionBox::from_lines(&example());
+        let boxes = JunctionBox::from_lines(&example()).unwrap();
 
         let pairs = shortest_distances(&boxes);
 
@@ -242,10 +176,9 @@ mod tests {
 
     #[test]
     fn test_junctionbox_from_lines() {
-        let boxes = JunctionBox::from_lines(&example());
+        let boxes = JunctionBox::from_lines(&example()).unwrap();
 
         assert_eq!(boxes.len(), 20);
-        assert!(boxes.iter().all(|abox| abox.circuit.is_none()));
 
         assert_eq!(boxes[0].x, 162);
         assert_eq!(boxes[0].y, 817);
@@ -1,31 +1,38 @@
+use aoc_utils::Answer;
+use aoc_utils::parallel;
+use aoc_utils::parse;
+use aoc_utils::solution::Solution;
 use std::cmp::max;
 use std::cmp::min;
 
-pub fn execute() -> String {
-    let data = aoc_utils::read_lines("input/day9.txt");
+pub struct Day9;
 
-    let tiles = from_lines(data);
-    let (a, b) = find_largest_rectangle(&tiles, &vec![]);
-    let part1 = area(a, b);
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    /// The red tiles.
+    type Input = Vec<(i64, i64)>;
 
-    let forbidden = get_forbidden_rectangles(&tiles);
-    let (a, b) = find_largest_rectangle(&tiles, &forbidden);
-    let part2 = area(a, b);
+    fn parse(lines: &[String]) -> aoc_utils::Result<Self::Input> {
+        from_lines(lines.to_vec())
+    }
+
+    fn part1(tiles: &Self::Input) -> Answer {
+        let (a, b) = find_largest_rectangle(tiles, &vec![]);
+        area(a, b).into()
+    }
 
-    format!("{} {}", part1, part2)
+    fn part2(tiles: &Self::Input) -> Answer {
+        let forbidden = get_forbidden_rectangles(tiles);
+        let (a, b) = find_largest_rectangle(tiles, &forbidden);
+        area(a, b).into()
+    }
 }
 
-fn from_lines(lines: Vec<String>) -> Vec<(i64, i64)> {
-    lines
-        .iter()
-        .map(|line| {
-            let coords: Vec<_> = line
-                .split(',')
-                .map(|val| val.parse::<i64>().unwrap())
-                .collect();
-            (coords[0], coords[1])
-        })
-        .collect()
+fn from_lines(lines: Vec<String>) -> aoc_utils::Result<Vec<(i64, i64)>> {
+    parse::lines(&lines, |line| {
+        let (x, y) = parse::split_once_or_err(line, ",")?;
+        Ok((parse::value::<i64>(x)?, parse::value::<i64>(y)?))
+    })
 }
 
 fn get_forbidden_rectangles(tiles: &Vec<(i64, i64)>) -> Vec<((i64, i64), (i64, i64))> {
@@ -123,20 +130,20 @@ fn find_largest_rectangle(
     tiles: &Vec<(i64, i64)>,
     forbidden: &Vec<((i64, i64), (i64, i64))>,
 ) -> ((i64, i64), (i64, i64)) {
-    let mut best: Option<(i64, ((i64, i64), (i64, i64)))> = None;
-    for t1 in tiles.iter() {
-        for t2 in tiles.iter() {
-            if t1 != t2 {
-                if is_compatible((*t1, *t2), forbidden) {
-                    let area = area(*t1, *t2);
-                    if best.is_none() || area > best.unwrap().0 {
-                        best = Some((area, (*t1, *t2)));
-                    }
-                }
-            }
-        }
-    }
-
+    type Candidate = (i64, ((i64, i64), (i64, i64)));
+    let keep_largest = |best: Option<Candidate>, candidate: Candidate| match best {
+        Some(best) if best.0 >= candidate.0 => Some(best),
+        _ => Some(candidate),
+    };
+    let best_by_tile = parallel::map(tiles.iter().collect(), |t1| {
+        tiles
+            .iter()
+            .filter(|&t2| t1 != t2 && is_compatible((*t1, *t2), forbidden))
+            .map(|t2| (area(*t1, *t2), (*t1, *t2)))
+            .fold(None, keep_largest)
+    });
+
+    let best = best_by_tile.into_iter().flatten().fold(None, keep_largest);
     let (a, b) = best.unwrap().1;
 
     if a.0 < b.0 || (a.0 == b.0 && a.1 < b.1) {
@@ -174,12 +181,14 @@ mod tests {
 
     #[test]
     fn test_mine() {
-        assert_eq!(execute(), "4739623064 1654141440");
+        let result = aoc_utils::solution::execute::<Day9>().unwrap();
+        assert_eq!(result.part1, 4739623064);
+        assert_eq!(result.part2, 1654141440);
     }
 
     #[test]
     fn test_find_largest_rectangle() {
-        let tiles = from_lines(example());
+        let tiles = from_lines(example()).unwrap();
 
         let (a, b) = find_largest_rectangle(&tiles, &vec![]);
         assert_eq!(a, (2, 5));
@@ -199,7 +208,7 @@ mod tests {
     }
     #[test]
     fn test_from_lines() {
-        let tiles = from_lines(example());
+        let tiles = from_lines(example()).unwrap();
 
         assert_eq!(tiles.len(), 8);
         assert_eq!(tiles[0], (7, 1));
@@ -1,28 +0,0 @@
-mod day1;
-mod day2;
-mod day3;
-
-mod day10;
-mod day11;
-mod day12;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-
-fn main() {
-    println!("Day  1: {}", day1::execute());
-    println!("Day  2: {}", day2::execute());
-    println!("Day  3: {}", day3::execute());
-    println!("Day  4: {}", day4::execute());
-    println!("Day  5: {}", day5::execute());
-    println!("Day  6: {}", day6::execute());
-    println!("Day  7: {}", day7::execute());
-    println!("Day  8: {}", day8::execute());
-    println!("Day  9: {}", day9::execute());
-    println!("Day 10: {}", day10::execute());
-    println!("Day 11: {}", day11::execute());
-    println!("Day 12: {}", day12::execute());
-}